@@ -0,0 +1,416 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use diesel::PgConnection;
+use shared::account::Account;
+use shared::balance::{Balances, TokenSupply};
+use shared::block::{Block, Epoch};
+use shared::crawler_state::ChainCrawlerState;
+use shared::id::Id;
+use shared::masp::MaspRewardData;
+use shared::pos::{Bond, Redelegation, Slashes, UnbondAddresses, Unbonds};
+use shared::proposal::{GovernanceProposal, TallyType};
+use shared::public_key::PublicKey;
+use shared::token::{IbcRateLimit, Token};
+use shared::validator::{
+    ValidatorMetadataChange, ValidatorSet, ValidatorStateChange,
+};
+use shared::vote::GovernanceVote;
+use tendermint_rpc::endpoint::block::Response as TendermintBlockResponse;
+
+use crate::repository;
+
+/// Owner, validator and transaction of a rewards claim
+type RewardClaimer = (Id, Id, Id);
+
+/// Everything the chain crawler extracts from a single block, ready to be
+/// written to the database. Several [`BlockData`] can be folded together with
+/// [`BlockData::merge`] so that a batch of blocks is committed in a single db
+/// transaction.
+pub struct BlockData {
+    pub blocks: Vec<(Block, TendermintBlockResponse)>,
+    pub ibc_tokens: Vec<Token>,
+    pub token_supplies: Vec<TokenSupply>,
+    pub rate_limits: Vec<IbcRateLimit>,
+    pub balances: Balances,
+    pub proposals: Vec<(GovernanceProposal, TallyType)>,
+    pub votes: HashSet<GovernanceVote>,
+    pub validator_set: ValidatorSet,
+    pub validators_state_change: HashSet<ValidatorStateChange>,
+    pub bonds: Vec<(Id, Id, Option<Bond>)>,
+    pub unbonds: Unbonds,
+    pub redelegations: Vec<Redelegation>,
+    pub slashes: Slashes,
+    pub withdraws: Vec<(Epoch, HashSet<UnbondAddresses>)>,
+    pub reward_claimers: Vec<(Epoch, HashSet<RewardClaimer>)>,
+    pub metadata_change: Vec<ValidatorMetadataChange>,
+    pub revealed_pks: Vec<(PublicKey, Id)>,
+    pub accounts: Vec<Account>,
+    pub masp_reward_rates: Vec<MaspRewardData>,
+    pub crawler_state: ChainCrawlerState,
+}
+
+impl BlockData {
+    /// Fold the data of a later block into this one. Values that are upserted
+    /// by key keep only the most recent entry, so that a single insert
+    /// statement never touches the same row twice.
+    pub fn merge(self, next: BlockData) -> Self {
+        Self {
+            blocks: concat(self.blocks, next.blocks),
+            ibc_tokens: concat(self.ibc_tokens, next.ibc_tokens),
+            token_supplies: concat(self.token_supplies, next.token_supplies),
+            rate_limits: concat(self.rate_limits, next.rate_limits),
+            balances: last_by_key(
+                concat(self.balances, next.balances),
+                |balance| {
+                    (
+                        balance.owner.clone(),
+                        balance.token.clone(),
+                        balance.height,
+                    )
+                },
+            ),
+            proposals: last_by_key(
+                concat(self.proposals, next.proposals),
+                |(proposal, _)| proposal.id,
+            ),
+            votes: last_by_key(
+                self.votes.into_iter().chain(next.votes).collect(),
+                |vote| (vote.proposal_id, vote.address.clone()),
+            )
+            .into_iter()
+            .collect(),
+            validator_set: ValidatorSet {
+                validators: last_by_key(
+                    self.validator_set
+                        .validators
+                        .into_iter()
+                        .chain(next.validator_set.validators)
+                        .collect(),
+                    |validator| validator.address.clone(),
+                )
+                .into_iter()
+                .collect(),
+                epoch: next.validator_set.epoch,
+            },
+            validators_state_change: last_by_key(
+                self.validators_state_change
+                    .into_iter()
+                    .chain(next.validators_state_change)
+                    .collect(),
+                |change| change.address.clone(),
+            )
+            .into_iter()
+            .collect(),
+            bonds: merge_bonds(self.bonds, next.bonds),
+            unbonds: last_by_key(
+                concat(self.unbonds, next.unbonds),
+                |unbond| {
+                    (
+                        unbond.source.clone(),
+                        unbond.target.clone(),
+                        unbond.withdraw_at,
+                    )
+                },
+            ),
+            redelegations: last_by_key(
+                concat(self.redelegations, next.redelegations),
+                |redelegation| {
+                    (
+                        redelegation.delegator.clone(),
+                        redelegation.validator.clone(),
+                    )
+                },
+            ),
+            slashes: last_by_key(concat(self.slashes, next.slashes), |slash| {
+                (
+                    slash.validator.clone(),
+                    slash.block_height,
+                    slash.kind.clone(),
+                )
+            }),
+            withdraws: concat(self.withdraws, next.withdraws),
+            reward_claimers: concat(self.reward_claimers, next.reward_claimers),
+            metadata_change: concat(self.metadata_change, next.metadata_change),
+            revealed_pks: concat(self.revealed_pks, next.revealed_pks),
+            accounts: merge_accounts(self.accounts, next.accounts),
+            masp_reward_rates: last_by_key(
+                concat(self.masp_reward_rates, next.masp_reward_rates),
+                |rate| rate.address.clone(),
+            ),
+            crawler_state: next.crawler_state,
+        }
+    }
+
+    pub fn persist(
+        self,
+        transaction_conn: &mut PgConnection,
+        should_update_crawler_state: bool,
+    ) -> anyhow::Result<()> {
+        repository::balance::insert_tokens(transaction_conn, self.ibc_tokens)?;
+
+        repository::balance::insert_token_supplies(
+            transaction_conn,
+            self.token_supplies,
+        )?;
+
+        repository::balance::insert_ibc_rate_limits(
+            transaction_conn,
+            self.rate_limits,
+        )?;
+
+        for (block, tm_block_response) in self.blocks {
+            repository::block::upsert_block(
+                transaction_conn,
+                block,
+                tm_block_response,
+            )?;
+        }
+
+        repository::balance::insert_balances(transaction_conn, self.balances)?;
+
+        let updated_proposals = self
+            .proposals
+            .iter()
+            .map(|(proposal, _)| proposal.id)
+            .chain(self.votes.iter().map(|vote| vote.proposal_id))
+            .collect::<HashSet<_>>();
+
+        repository::gov::insert_proposals(transaction_conn, self.proposals)?;
+        repository::gov::insert_votes(transaction_conn, self.votes)?;
+        repository::gov::notify_proposals(transaction_conn, updated_proposals)?;
+
+        repository::pos::upsert_validators(
+            transaction_conn,
+            self.validator_set,
+        )?;
+
+        repository::pos::upsert_validator_state(
+            transaction_conn,
+            self.validators_state_change,
+        )?;
+
+        let removed_bonds_addresses = self
+            .bonds
+            .iter()
+            .map(|(source, validator, _)| (source.clone(), validator.clone()))
+            .collect::<Vec<(Id, Id)>>();
+        let bonds_updates = self
+            .bonds
+            .into_iter()
+            .filter_map(|(_, _, bond)| bond)
+            .collect::<Vec<_>>();
+
+        // We first remove all the bonds and then insert the new ones
+        repository::pos::clear_bonds(
+            transaction_conn,
+            removed_bonds_addresses,
+        )?;
+        repository::pos::insert_bonds(transaction_conn, bonds_updates)?;
+
+        repository::pos::insert_unbonds(transaction_conn, self.unbonds)?;
+        repository::pos::insert_redelegations(
+            transaction_conn,
+            self.redelegations,
+        )?;
+        repository::pos::insert_slashes(transaction_conn, self.slashes)?;
+        for (epoch, withdraw_addresses) in self.withdraws {
+            repository::pos::remove_withdraws(
+                transaction_conn,
+                epoch,
+                withdraw_addresses,
+            )?;
+        }
+
+        for (epoch, reward_claimers) in self.reward_claimers {
+            repository::pos::mark_reward_accruals_claimed(
+                transaction_conn,
+                &reward_claimers,
+                epoch,
+            )?;
+            repository::pos::delete_claimed_rewards(
+                transaction_conn,
+                reward_claimers,
+            )?;
+        }
+
+        repository::pos::update_validator_metadata(
+            transaction_conn,
+            self.metadata_change,
+        )?;
+
+        repository::revealed_pk::insert_revealed_pks(
+            transaction_conn,
+            self.revealed_pks,
+        )?;
+
+        repository::account::upsert_accounts(transaction_conn, self.accounts)?;
+
+        repository::masp::insert_masp_rates(
+            transaction_conn,
+            self.masp_reward_rates,
+        )?;
+
+        if should_update_crawler_state {
+            repository::crawler_state::upsert_crawler_state(
+                transaction_conn,
+                self.crawler_state,
+            )?;
+        }
+
+        anyhow::Ok(())
+    }
+}
+
+fn concat<T>(mut first: Vec<T>, second: Vec<T>) -> Vec<T> {
+    first.extend(second);
+    first
+}
+
+/// Deduplicate `items` by `key`, keeping the last occurrence of each key at
+/// the position of its first occurrence.
+fn last_by_key<T, K, F>(items: Vec<T>, key: F) -> Vec<T>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    let mut positions = HashMap::new();
+    let mut deduplicated: Vec<T> = Vec::with_capacity(items.len());
+
+    for item in items {
+        match positions.get(&key(&item)) {
+            Some(&position) => deduplicated[position] = item,
+            None => {
+                positions.insert(key(&item), deduplicated.len());
+                deduplicated.push(item);
+            }
+        }
+    }
+
+    deduplicated
+}
+
+/// Bonds are queried per (source, validator) pair, so the bonds of a pair in a
+/// later block fully replace the ones of an earlier block.
+fn merge_bonds(
+    bonds: Vec<(Id, Id, Option<Bond>)>,
+    next: Vec<(Id, Id, Option<Bond>)>,
+) -> Vec<(Id, Id, Option<Bond>)> {
+    let replaced = next
+        .iter()
+        .map(|(source, validator, _)| (source.clone(), validator.clone()))
+        .collect::<HashSet<_>>();
+
+    bonds
+        .into_iter()
+        .filter(|(source, validator, _)| {
+            !replaced.contains(&(source.clone(), validator.clone()))
+        })
+        .chain(next)
+        .collect()
+}
+
+/// Accounts are queried again on every change, so a later entry replaces an
+/// earlier one but keeps the creation height and validity predicate that only
+/// the earlier transaction carried.
+fn merge_accounts(accounts: Vec<Account>, next: Vec<Account>) -> Vec<Account> {
+    let mut merged: Vec<Account> = Vec::with_capacity(accounts.len());
+
+    for account in accounts.into_iter().chain(next) {
+        match merged
+            .iter_mut()
+            .find(|merged| merged.address == account.address)
+        {
+            Some(merged) => {
+                let previous = std::mem::replace(merged, account);
+                merged.creation_height =
+                    merged.creation_height.or(previous.creation_height);
+                merged.vp_code_hash =
+                    merged.vp_code_hash.take().or(previous.vp_code_hash);
+            }
+            None => merged.push(account),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::validator::Validator;
+
+    use super::*;
+
+    #[test]
+    fn test_last_by_key_keeps_last_value_at_first_position() {
+        let items = vec![(1, "a"), (2, "b"), (1, "c"), (3, "d"), (2, "e")];
+
+        let deduplicated = last_by_key(items, |(key, _)| *key);
+
+        assert_eq!(deduplicated, vec![(1, "c"), (2, "e"), (3, "d")]);
+    }
+
+    #[test]
+    fn test_merge_bonds_replaces_pairs_of_later_block() {
+        let validator = Validator::fake().address;
+        let replaced_bond = Bond::fake(validator.clone());
+        let kept_bond = Bond::fake(validator.clone());
+
+        let bonds = vec![
+            (
+                replaced_bond.source.clone(),
+                validator.clone(),
+                Some(replaced_bond.clone()),
+            ),
+            (
+                kept_bond.source.clone(),
+                validator.clone(),
+                Some(kept_bond.clone()),
+            ),
+        ];
+        let next =
+            vec![(replaced_bond.source.clone(), validator.clone(), None)];
+
+        let merged = merge_bonds(bonds, next);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].0, kept_bond.source);
+        assert_eq!(
+            merged[0].2.as_ref().map(|bond| bond.start),
+            Some(kept_bond.start)
+        );
+        assert_eq!(merged[1].0, replaced_bond.source);
+        assert!(merged[1].2.is_none());
+    }
+
+    #[test]
+    fn test_merge_accounts_keeps_creation_of_earlier_block() {
+        let created = Account {
+            address: Id::Account("tnam1account".to_string()),
+            public_keys: vec!["key1".to_string()],
+            threshold: 1,
+            vp_code_hash: Some(Id::Hash("vp".to_string())),
+            creation_height: Some(10),
+            last_update_height: 10,
+        };
+        let updated = Account {
+            public_keys: vec!["key1".to_string(), "key2".to_string()],
+            threshold: 2,
+            vp_code_hash: None,
+            creation_height: None,
+            last_update_height: 12,
+            ..created.clone()
+        };
+
+        let merged = merge_accounts(vec![created], vec![updated.clone()]);
+
+        assert_eq!(
+            merged,
+            vec![Account {
+                vp_code_hash: Some(Id::Hash("vp".to_string())),
+                creation_height: Some(10),
+                ..updated
+            }]
+        );
+    }
+}
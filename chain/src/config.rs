@@ -33,6 +33,16 @@ pub struct AppConfig {
     )]
     pub storage_read_past_height_limit: u32,
 
+    #[clap(
+        long,
+        env,
+        default_value = "1",
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Number of blocks to query concurrently and commit in a single \
+                db transaction"
+    )]
+    pub batch_size: u32,
+
     #[clap(flatten)]
     pub log: LogConfig,
 
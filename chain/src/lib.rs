@@ -1,4 +1,5 @@
 pub mod app_state;
+pub mod batch;
 pub mod config;
 pub mod repository;
 pub mod services;
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use anyhow::Context;
 use chain::app_state::AppState;
+use chain::batch::BlockData;
 use chain::config::AppConfig;
 use chain::repository;
 use chain::services::namada::{
@@ -19,18 +20,20 @@ use chrono::{NaiveDateTime, Utc};
 use clap::Parser;
 use deadpool_diesel::postgres::Object;
 use diesel::RunQueryDsl;
-use futures::stream::StreamExt;
+use futures::stream::{StreamExt, TryStreamExt};
+use namada_sdk::address::Address as NamadaSdkAddress;
 use namada_sdk::time::DateTimeUtc;
 use orm::migrations::CustomMigrationSource;
 use orm::schema::{bonds, unbonds};
 use repository::pgf as namada_pgf_repository;
-use shared::balance::TokenSupply;
-use shared::block::Block;
+use shared::account::AccountChange;
+use shared::balance::{Balances, TokenSupply};
+use shared::block::{Block, BlockHeight};
 use shared::block_result::BlockResult;
 use shared::checksums::Checksums;
 use shared::client::Client;
 use shared::cometbft::CometbftBlock;
-use shared::crawler::crawl;
+use shared::crawler::{crawl, crawl_batch};
 use shared::crawler_state::ChainCrawlerState;
 use shared::error::{
     AsDbError, AsRpcError, AsTaskJoinError, ContextDbInteractError, MainError,
@@ -218,20 +221,39 @@ async fn main() -> Result<(), MainError> {
         }
     };
 
-    crawl(
-        move |block_height| {
-            crawling_fn(
-                block_height,
-                Arc::new(client.get()),
-                conn.clone(),
-                checksums.clone(),
-                config.backfill_from.is_none(),
-            )
-        },
-        crawler_state.last_processed_block,
-        Some(1000),
-    )
-    .await
+    if config.batch_size > 1 {
+        crawl_batch(
+            move |from, batch_size| {
+                crawling_batch_fn(
+                    from,
+                    batch_size,
+                    Arc::new(client.get()),
+                    conn.clone(),
+                    checksums.clone(),
+                    config.backfill_from.is_none(),
+                )
+            },
+            crawler_state.last_processed_block,
+            config.batch_size,
+            Some(1000),
+        )
+        .await
+    } else {
+        crawl(
+            move |block_height| {
+                crawling_fn(
+                    block_height,
+                    Arc::new(client.get()),
+                    conn.clone(),
+                    checksums.clone(),
+                    config.backfill_from.is_none(),
+                )
+            },
+            crawler_state.last_processed_block,
+            Some(1000),
+        )
+        .await
+    }
 }
 
 async fn crawling_fn(
@@ -255,8 +277,6 @@ async fn crawling_fn(
         return Err(MainError::NoAction);
     }
 
-    let start = Instant::now();
-
     tracing::debug!(block = block_height, "Query first block in epoch...");
     let first_block_in_epoch =
         namada_service::get_first_block_in_epoch(&client)
@@ -272,18 +292,171 @@ async fn crawling_fn(
         *checksums = namada_service::query_checksums(&client).await;
     }
 
+    let cometbft_block =
+        get_cometbft_block_with_fallback(&conn, &client, block_height)
+            .await
+            .into_db_error()?;
+
+    let block_data = query_block_data(
+        cometbft_block,
+        first_block_in_epoch,
+        client,
+        &conn,
+        &checksums,
+    )
+    .await?;
+
+    let start = Instant::now();
+
+    commit_block_data(&conn, block_data, should_update_crawler_state).await?;
+
+    tracing::info!(
+        block = block_height,
+        time_taken = start.elapsed().as_secs_f64(),
+        "Inserted block into database"
+    );
+
+    Ok(())
+}
+
+/// Crawls up to `batch_size` blocks starting at `from`. The blocks are queried
+/// concurrently, folded in memory and committed in a single db transaction
+/// together with the crawler state, so a failure anywhere in the batch leaves
+/// the database untouched and the whole batch is retried. Returns the height
+/// of the last processed block.
+async fn crawling_batch_fn(
+    from: u32,
+    batch_size: u32,
+    client: Arc<HttpClient>,
+    conn: Arc<Object>,
+    checksums: Arc<Mutex<Checksums>>,
+    should_update_crawler_state: bool,
+) -> Result<u32, MainError> {
+    let last_block_height = namada_service::query_last_block_height(&client)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to query Namada's last committed block: {}",
+                e
+            );
+            MainError::RpcError
+        })?;
+
+    if last_block_height < from {
+        let timestamp = Utc::now().naive_utc();
+        update_crawler_timestamp(&conn, timestamp).await?;
+
+        tracing::trace!(block = from, "Block does not exist yet, waiting...");
+
+        return Err(MainError::NoAction);
+    }
+
+    let to = from.saturating_add(batch_size - 1).min(last_block_height);
+
+    tracing::info!(from = from, to = to, "Crawling batch of blocks...");
+
+    let start = Instant::now();
+
+    let cometbft_blocks = futures::stream::iter(from..=to)
+        .map(|block_height| {
+            get_cometbft_block_with_fallback(&conn, &client, block_height)
+        })
+        .buffered(batch_size as usize)
+        .try_collect::<Vec<_>>()
+        .await
+        .into_db_error()?;
+
+    let previous_epoch = match from.checked_sub(1) {
+        Some(previous_height) if previous_height > 0 => Some(
+            namada_service::get_epoch_at_block_height(&client, previous_height)
+                .await
+                .into_rpc_error()?,
+        ),
+        _ => None,
+    };
+
+    // The first block in epoch of the node is only accurate for the blocks
+    // of the current epoch, so we detect epoch changes within the batch by
+    // comparing the epochs of consecutive blocks
+    let mut first_block_in_epoch =
+        namada_service::get_first_block_in_epoch(&client)
+            .await
+            .into_rpc_error()?;
+    let mut epoch = previous_epoch;
+    let mut blocks = Vec::with_capacity(cometbft_blocks.len());
+    for cometbft_block in cometbft_blocks {
+        if epoch.is_some_and(|epoch| epoch != cometbft_block.epoch) {
+            first_block_in_epoch = cometbft_block.block_height;
+        }
+        epoch = Some(cometbft_block.epoch);
+        blocks.push((cometbft_block, first_block_in_epoch));
+    }
+
+    let mut checksums = checksums.lock().await;
+    let new_epoch = blocks.iter().any(|(cometbft_block, first_block)| {
+        cometbft_block.block_height.eq(first_block)
+    });
+    // For new epochs, we need to query checksums in case they were changed due
+    // to proposal
+    if new_epoch {
+        *checksums = namada_service::query_checksums(&client).await;
+    }
+
+    let block_data = futures::stream::iter(blocks)
+        .map(|(cometbft_block, first_block_in_epoch)| {
+            query_block_data(
+                cometbft_block,
+                first_block_in_epoch,
+                client.clone(),
+                &conn,
+                &checksums,
+            )
+        })
+        .buffered(batch_size as usize)
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .reduce(BlockData::merge)
+        .expect("Batch should contain at least one block");
+
+    let first_checkpoint = Instant::now();
+
+    commit_block_data(&conn, block_data, should_update_crawler_state).await?;
+
+    tracing::info!(
+        from = from,
+        to = to,
+        query_time = first_checkpoint.duration_since(start).as_secs_f64(),
+        commit_time = first_checkpoint.elapsed().as_secs_f64(),
+        "Inserted batch of blocks into database"
+    );
+
+    Ok(to)
+}
+
+async fn query_block_data(
+    cometbft_block: CometbftBlock,
+    first_block_in_epoch: u32,
+    client: Arc<HttpClient>,
+    conn: &Object,
+    checksums: &Checksums,
+) -> Result<BlockData, MainError> {
+    let start = Instant::now();
+
+    let block_height = cometbft_block.block_height;
+    let new_epoch = first_block_in_epoch.eq(&block_height);
+
     let native_token = namada_service::get_native_token(&client)
         .await
         .into_rpc_error()?;
     let native_token_address: namada_sdk::address::Address =
         native_token.clone().into();
 
-    let cometbft_block =
-        get_cometbft_block_with_fallback(&conn, &client, block_height)
-            .await
-            .into_db_error()?;
+    let slashed_validators =
+        BlockResult::from(&cometbft_block.events).slashed_validators();
+
     let (block, tm_block_response, epoch) =
-        get_block(cometbft_block, &client, &checksums, &native_token_address)
+        get_block(cometbft_block, &client, checksums, &native_token_address)
             .await?;
 
     let rate_limits = new_epoch.then(|| {
@@ -338,7 +511,7 @@ async fn crawling_fn(
         query_token_supplies(&client, &all_changed_tokens_supply, epoch)
             .await?;
 
-    let validators_addresses = if first_block_in_epoch.eq(&block_height) {
+    let validators_addresses = if new_epoch {
         let previous_epoch = epoch.saturating_sub(1);
         namada_service::get_all_consensus_validators_addresses_at(
             &client,
@@ -360,7 +533,7 @@ async fn crawling_fn(
             token: Token::Native(native_token.clone()),
         });
 
-    let pgf_receipient_addresses = if first_block_in_epoch.eq(&block_height) {
+    let pgf_receipient_addresses = if new_epoch {
         conn.interact(move |conn| {
             namada_pgf_repository::get_pgf_receipients_balance_changes(
                 conn,
@@ -435,7 +608,40 @@ async fn crawling_fn(
         validators_state_change.len()
     );
 
-    let addresses = block.bond_addresses();
+    // Slashes change the amounts of every bond and unbond with the slashed
+    // validators, so those are queried again as well
+    let (slashes, slashed_bond_addresses, slashed_unbond_addresses) =
+        if slashed_validators.is_empty() {
+            (vec![], HashSet::default(), HashSet::default())
+        } else {
+            let slashes = namada_service::query_validators_slashes(
+                &client,
+                &slashed_validators,
+            )
+            .await
+            .into_rpc_error()?;
+
+            let (bond_addresses, unbond_addresses) = conn
+                .interact(move |conn| {
+                    repository::pos::get_delegations_to_validators(
+                        conn,
+                        slashed_validators,
+                    )
+                })
+                .await
+                .context_db_interact_error()
+                .and_then(identity)
+                .into_db_error()?;
+
+            (slashes, bond_addresses, unbond_addresses)
+        };
+    tracing::debug!(block = block_height, "Updating {} slashes", slashes.len());
+
+    let addresses = block
+        .bond_addresses()
+        .into_iter()
+        .chain(slashed_bond_addresses)
+        .collect();
     let bonds = query_bonds(&client, &addresses).await.into_rpc_error()?;
     tracing::debug!(
         block = block_height,
@@ -447,19 +653,11 @@ async fn crawling_fn(
         .into_rpc_error()?;
     tracing::debug!("Updating redelegations for {} addresses", bonds.len());
 
-    let bonds_updates = bonds
-        .iter()
-        .cloned()
-        .filter_map(|(_, _, bond)| bond)
-        .collect::<Vec<_>>();
-
-    let removed_bonds_addresses = bonds
-        .iter()
-        .cloned()
-        .map(|(source, validator, _)| (source, validator))
-        .collect::<Vec<(Id, Id)>>();
-
-    let addresses = block.unbond_addresses();
+    let addresses = block
+        .unbond_addresses()
+        .into_iter()
+        .chain(slashed_unbond_addresses)
+        .collect();
     let unbonds = namada_service::query_unbonds(&client, addresses)
         .await
         .into_rpc_error()?;
@@ -478,6 +676,16 @@ async fn crawling_fn(
         revealed_pks.len()
     );
 
+    let accounts =
+        namada_service::query_accounts(&client, block.account_changes())
+            .await
+            .into_rpc_error()?;
+    tracing::debug!(
+        block = block_height,
+        "Updating {} accounts",
+        accounts.len()
+    );
+
     let metadata_change = block.validator_metadata();
 
     let reward_claimers = block.pos_rewards();
@@ -502,8 +710,6 @@ async fn crawling_fn(
                     .into_rpc_error()
             })?;
 
-    let first_checkpoint = Instant::now();
-
     tracing::info!(
         txs = block.transactions.len(),
         ibc_tokens = ibc_tokens.len(),
@@ -511,133 +717,63 @@ async fn crawling_fn(
         proposals = proposals_with_tally.len(),
         votes = proposals_votes.len(),
         validators = validators.len(),
-        bonds = bonds_updates.len(),
+        bonds = bonds.len(),
         unbonds = unbonds.len(),
+        slashes = slashes.len(),
         withdraws = withdraw_addreses.len(),
         claimed_rewards = reward_claimers.len(),
         revealed_pks = revealed_pks.len(),
+        accounts = accounts.len(),
         validator_state = validators_state_change.len(),
         epoch = epoch,
         first_block_in_epoch = first_block_in_epoch,
         block = block_height,
-        time_taken = first_checkpoint.duration_since(start).as_secs_f64(),
+        time_taken = start.elapsed().as_secs_f64(),
         "Queried block successfully",
     );
 
+    Ok(BlockData {
+        blocks: vec![(block, tm_block_response)],
+        ibc_tokens,
+        token_supplies,
+        rate_limits,
+        balances,
+        proposals: proposals_with_tally,
+        votes: proposals_votes,
+        validator_set,
+        validators_state_change,
+        bonds,
+        unbonds,
+        redelegations,
+        slashes,
+        withdraws: vec![(epoch, withdraw_addreses)],
+        reward_claimers: vec![(epoch, reward_claimers)],
+        metadata_change,
+        revealed_pks,
+        accounts,
+        masp_reward_rates,
+        crawler_state,
+    })
+}
+
+async fn commit_block_data(
+    conn: &Object,
+    block_data: BlockData,
+    should_update_crawler_state: bool,
+) -> Result<(), MainError> {
     conn.interact(move |conn| {
         conn.build_transaction()
             .read_write()
             .run(|transaction_conn| {
-                repository::balance::insert_tokens(
-                    transaction_conn,
-                    ibc_tokens,
-                )?;
-
-                repository::balance::insert_token_supplies(
-                    transaction_conn,
-                    token_supplies,
-                )?;
-
-                repository::balance::insert_ibc_rate_limits(
-                    transaction_conn,
-                    rate_limits,
-                )?;
-
-                repository::block::upsert_block(
-                    transaction_conn,
-                    block,
-                    tm_block_response,
-                )?;
-
-                repository::balance::insert_balances(
-                    transaction_conn,
-                    balances,
-                )?;
-
-                repository::gov::insert_proposals(
-                    transaction_conn,
-                    proposals_with_tally,
-                )?;
-                repository::gov::insert_votes(
-                    transaction_conn,
-                    proposals_votes,
-                )?;
-
-                repository::pos::upsert_validators(
-                    transaction_conn,
-                    validator_set,
-                )?;
-
-                repository::pos::upsert_validator_state(
-                    transaction_conn,
-                    validators_state_change,
-                )?;
-
-                // We first remove all the bonds and then insert the new ones
-                repository::pos::clear_bonds(
-                    transaction_conn,
-                    removed_bonds_addresses,
-                )?;
-                repository::pos::insert_bonds(transaction_conn, bonds_updates)?;
-
-                repository::pos::insert_unbonds(transaction_conn, unbonds)?;
-                repository::pos::insert_redelegations(
-                    transaction_conn,
-                    redelegations,
-                )?;
-                repository::pos::remove_withdraws(
-                    transaction_conn,
-                    epoch,
-                    withdraw_addreses,
-                )?;
-
-                repository::pos::delete_claimed_rewards(
-                    transaction_conn,
-                    reward_claimers,
-                )?;
-
-                repository::pos::update_validator_metadata(
-                    transaction_conn,
-                    metadata_change,
-                )?;
-
-                repository::revealed_pk::insert_revealed_pks(
-                    transaction_conn,
-                    revealed_pks,
-                )?;
-
-                repository::masp::insert_masp_rates(
-                    transaction_conn,
-                    masp_reward_rates,
-                )?;
-
-                if should_update_crawler_state {
-                    repository::crawler_state::upsert_crawler_state(
-                        transaction_conn,
-                        crawler_state,
-                    )?;
-                }
-
-                anyhow::Ok(())
+                block_data
+                    .persist(transaction_conn, should_update_crawler_state)
             })
     })
     .await
     .context_db_interact_error()
     .into_db_error()?
     .context("Commit block db transaction error")
-    .into_db_error()?;
-
-    let second_checkpoint = Instant::now();
-
-    tracing::info!(
-        block = block_height,
-        time_taken = second_checkpoint
-            .duration_since(first_checkpoint)
-            .as_secs_f64(),
-        "Inserted block into database"
-    );
-
-    Ok(())
+    .into_db_error()
 }
 
 async fn initial_query(
@@ -731,6 +867,14 @@ async fn try_initial_query(
             .await
             .into_rpc_error()?;
 
+    tracing::debug!(block = block_height, "Querying accounts...");
+    let accounts = namada_service::query_accounts(
+        client,
+        known_accounts(&balances, &validator_set, block_height),
+    )
+    .await
+    .into_rpc_error()?;
+
     tracing::debug!("Querying redelegations...");
     let redelegations =
         namada_service::query_all_redelegations(client, validators_set)
@@ -742,6 +886,11 @@ async fn try_initial_query(
         .await
         .into_rpc_error()?;
 
+    tracing::debug!("Querying slashes...");
+    let slashes = namada_service::query_all_slashes(client)
+        .await
+        .into_rpc_error()?;
+
     tracing::debug!(block = block_height, "Querying proposals...");
     let proposals = query_all_proposals(client).await.into_rpc_error()?;
     let proposals_with_tally =
@@ -818,12 +967,23 @@ async fn try_initial_query(
                     validator_set,
                 )?;
 
+                tracing::debug!(
+                    block = block_height,
+                    "Inserting {} accounts...",
+                    accounts.len()
+                );
+                repository::account::upsert_accounts(
+                    transaction_conn,
+                    accounts,
+                )?;
+
                 repository::pos::insert_bonds(transaction_conn, bonds)?;
                 repository::pos::insert_unbonds(transaction_conn, unbonds)?;
                 repository::pos::insert_redelegations(
                     transaction_conn,
                     redelegations,
                 )?;
+                repository::pos::insert_slashes(transaction_conn, slashes)?;
 
                 repository::masp::insert_masp_rates(
                     transaction_conn,
@@ -844,6 +1004,35 @@ async fn try_initial_query(
     .into_db_error()
 }
 
+/// Established accounts existing before the first indexed block. There is no
+/// way to list every account, so these are the validators and the owners of a
+/// balance, which any account that ever paid fees is.
+fn known_accounts(
+    balances: &Balances,
+    validator_set: &ValidatorSet,
+    block_height: BlockHeight,
+) -> Vec<AccountChange> {
+    balances
+        .iter()
+        .map(|balance| &balance.owner)
+        .chain(validator_set.validators.iter().map(|v| &v.address))
+        .filter(|address| {
+            matches!(
+                NamadaSdkAddress::from((*address).clone()),
+                NamadaSdkAddress::Established(_)
+            )
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|address| AccountChange {
+            address: address.clone(),
+            vp_code_hash: None,
+            creation_height: None,
+            height: block_height,
+        })
+        .collect()
+}
+
 async fn can_process(
     block_height: u32,
     client: Arc<HttpClient>,
@@ -0,0 +1,39 @@
+use anyhow::Context;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
+use orm::accounts::{
+    AccountInsertDb, AccountPublicKeyInsertDb, AccountUpdateDb,
+};
+use orm::schema::{account_public_keys, accounts};
+use shared::account::Account;
+
+pub fn upsert_accounts(
+    transaction_conn: &mut PgConnection,
+    accounts: Vec<Account>,
+) -> anyhow::Result<()> {
+    for account in accounts {
+        let account_db = AccountInsertDb::from(&account);
+
+        diesel::insert_into(accounts::table)
+            .values(&account_db)
+            .on_conflict(accounts::address)
+            .do_update()
+            .set(AccountUpdateDb::from(&account_db))
+            .execute(transaction_conn)
+            .context("Failed to upsert account in db")?;
+
+        // Keys are replaced as a whole, as indexes can be reassigned
+        diesel::delete(
+            account_public_keys::table
+                .filter(account_public_keys::address.eq(&account_db.address)),
+        )
+        .execute(transaction_conn)
+        .context("Failed to remove account public keys from db")?;
+
+        diesel::insert_into(account_public_keys::table)
+            .values(AccountPublicKeyInsertDb::from_account(&account))
+            .execute(transaction_conn)
+            .context("Failed to insert account public keys in db")?;
+    }
+
+    anyhow::Ok(())
+}
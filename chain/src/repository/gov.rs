@@ -5,6 +5,7 @@ use diesel::upsert::excluded;
 use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
 use orm::governance_proposal::GovernanceProposalInsertDb;
 use orm::governance_votes::GovernanceProposalVoteInsertDb;
+use orm::notifications::IndexerNotification;
 use orm::schema::{governance_proposals, governance_votes};
 use shared::proposal::{GovernanceProposal, TallyType};
 use shared::tuple_len::TupleLen;
@@ -86,3 +87,16 @@ fn insert_votes_chunk(
 
     anyhow::Ok(())
 }
+
+pub fn notify_proposals(
+    transaction_conn: &mut PgConnection,
+    proposal_ids: HashSet<u64>,
+) -> anyhow::Result<()> {
+    for id in proposal_ids {
+        IndexerNotification::Proposal { id: id as i32 }
+            .notify(transaction_conn)
+            .context("Failed to notify governance proposal")?;
+    }
+
+    anyhow::Ok(())
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Context;
 use diesel::upsert::excluded;
@@ -8,7 +8,11 @@ use diesel::{
 };
 use orm::bond::BondInsertDb;
 use orm::redelegation::RedelegationInsertDb;
-use orm::schema::{bonds, pos_rewards, redelegation, unbonds, validators};
+use orm::schema::{
+    bonds, pos_reward_accruals, pos_rewards, redelegation, slashes, unbonds,
+    validators,
+};
+use orm::slashes::SlashInsertDb;
 use orm::unbond::UnbondInsertDb;
 use orm::validators::{
     ValidatorDb, ValidatorStateDb, ValidatorUpdateMetadataDb,
@@ -16,7 +20,9 @@ use orm::validators::{
 };
 use shared::block::Epoch;
 use shared::id::Id;
-use shared::pos::{Bonds, Redelegations, UnbondAddresses, Unbonds};
+use shared::pos::{
+    BondAddresses, Bonds, Redelegations, Slashes, UnbondAddresses, Unbonds,
+};
 use shared::tuple_len::TupleLen;
 use shared::validator::{
     ValidatorMetadataChange, ValidatorSet, ValidatorStateChange,
@@ -240,7 +246,7 @@ pub fn remove_withdraws(
 
 pub fn delete_claimed_rewards(
     transaction_conn: &mut PgConnection,
-    reward_claimers: HashSet<(Id, Id)>,
+    reward_claimers: HashSet<(Id, Id, Id)>,
 ) -> anyhow::Result<()> {
     // If there are no rewards to claimm return early, to not clear the whole
     // table
@@ -248,7 +254,7 @@ pub fn delete_claimed_rewards(
         return Ok(());
     }
 
-    for (owner, validator_id) in reward_claimers {
+    for (owner, validator_id, _) in reward_claimers {
         let target = pos_rewards::table.filter(
             pos_rewards::owner.eq(owner.to_string()).and(
                 pos_rewards::validator_id.eq_any(
@@ -269,6 +275,40 @@ pub fn delete_claimed_rewards(
     anyhow::Ok(())
 }
 
+/// Accruals booked after the epoch of the claim were earned after it, and are
+/// left unclaimed
+pub fn mark_reward_accruals_claimed(
+    transaction_conn: &mut PgConnection,
+    reward_claimers: &HashSet<(Id, Id, Id)>,
+    epoch: Epoch,
+) -> anyhow::Result<()> {
+    for (owner, validator_id, tx_id) in reward_claimers {
+        let target = pos_reward_accruals::table.filter(
+            pos_reward_accruals::owner
+                .eq(owner.to_string())
+                .and(pos_reward_accruals::claimed_in_tx.is_null())
+                .and(pos_reward_accruals::epoch.le(epoch as i32))
+                .and(
+                    pos_reward_accruals::validator_id.eq_any(
+                        validators::table
+                            .select(validators::columns::id)
+                            .filter(
+                                validators::columns::namada_address
+                                    .eq(validator_id.to_string()),
+                            ),
+                    ),
+                ),
+        );
+
+        diesel::update(target)
+            .set(pos_reward_accruals::claimed_in_tx.eq(tx_id.to_string()))
+            .execute(transaction_conn)
+            .context("Failed to update pos reward accruals in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
 pub fn update_validator_metadata(
     transaction_conn: &mut PgConnection,
     metadata_change: Vec<ValidatorMetadataChange>,
@@ -356,14 +396,107 @@ pub fn upsert_validators(
     Ok(())
 }
 
+pub fn insert_slashes(
+    transaction_conn: &mut PgConnection,
+    slashes: Slashes,
+) -> anyhow::Result<()> {
+    if slashes.is_empty() {
+        return Ok(());
+    }
+
+    let validator_ids: HashMap<String, i32> = validators::table
+        .filter(
+            validators::columns::namada_address.eq_any(
+                slashes
+                    .iter()
+                    .map(|slash| slash.validator.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .select((validators::columns::namada_address, validators::columns::id))
+        .load::<(String, i32)>(transaction_conn)
+        .context("Failed to read slashed validators from db")?
+        .into_iter()
+        .collect();
+
+    let slashes_db = slashes
+        .into_iter()
+        .filter_map(|slash| {
+            let validator_id =
+                *validator_ids.get(&slash.validator.to_string())?;
+            Some(SlashInsertDb::from_slash(slash, validator_id))
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(slashes::table)
+        .values::<&Vec<SlashInsertDb>>(&slashes_db)
+        .on_conflict((
+            slashes::columns::validator_id,
+            slashes::columns::block_height,
+            slashes::columns::slash_type,
+        ))
+        .do_update()
+        .set(slashes::columns::rate.eq(excluded(slashes::columns::rate)))
+        .execute(transaction_conn)
+        .context("Failed to insert slashes in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Bonds and unbonds held with `validators`, whose amounts change when the
+/// validators are slashed
+pub fn get_delegations_to_validators(
+    transaction_conn: &mut PgConnection,
+    validators: HashSet<Id>,
+) -> anyhow::Result<(HashSet<BondAddresses>, HashSet<UnbondAddresses>)> {
+    let validators = validators
+        .into_iter()
+        .map(|validator| validator.to_string())
+        .collect::<Vec<_>>();
+
+    let bond_addresses = bonds::table
+        .inner_join(validators::table)
+        .filter(validators::columns::namada_address.eq_any(&validators))
+        .select((bonds::columns::address, validators::columns::namada_address))
+        .distinct()
+        .load::<(String, String)>(transaction_conn)
+        .context("Failed to read bonds of slashed validators from db")?
+        .into_iter()
+        .map(|(source, validator)| BondAddresses {
+            source: Id::Account(source),
+            target: Id::Account(validator),
+        })
+        .collect();
+
+    let unbond_addresses = unbonds::table
+        .inner_join(validators::table)
+        .filter(validators::columns::namada_address.eq_any(&validators))
+        .select((
+            unbonds::columns::address,
+            validators::columns::namada_address,
+        ))
+        .distinct()
+        .load::<(String, String)>(transaction_conn)
+        .context("Failed to read unbonds of slashed validators from db")?
+        .into_iter()
+        .map(|(source, validator)| UnbondAddresses {
+            source: Id::Account(source),
+            validator: Id::Account(validator),
+        })
+        .collect();
+
+    anyhow::Ok((bond_addresses, unbond_addresses))
+}
+
 #[cfg(test)]
 mod tests {
     use orm::bond::BondDb;
     use orm::redelegation::RedelegationDb;
+    use orm::slashes::SlashDb;
     use orm::unbond::UnbondDb;
     use orm::validators::ValidatorInsertDb;
     use shared::balance::Amount;
-    use shared::pos::{Bond, Redelegation, Unbond};
+    use shared::pos::{Bond, Redelegation, Slash, SlashType, Unbond};
     use shared::validator::Validator;
     use test_helpers::db::TestDb;
 
@@ -883,6 +1016,90 @@ mod tests {
         .expect("Failed to run test");
     }
 
+    /// Test that inserting the same slash twice keeps a single row with the
+    /// latest rate, and that slashes of unknown validators are skipped.
+    #[tokio::test]
+    async fn test_insert_slashes_with_conflict() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let validator = Validator::fake();
+            seed_validator(conn, validator.clone())?;
+
+            let slash = Slash {
+                validator: validator.address.clone(),
+                infraction_epoch: 10,
+                processing_epoch: 13,
+                block_height: 1000,
+                rate: "0.001".to_string(),
+                kind: SlashType::DuplicateVote,
+            };
+            let unknown_validator_slash = Slash {
+                validator: Validator::fake().address,
+                ..slash.clone()
+            };
+
+            insert_slashes(conn, vec![slash.clone(), unknown_validator_slash])?;
+            insert_slashes(
+                conn,
+                vec![Slash {
+                    rate: "0.05".to_string(),
+                    ..slash
+                }],
+            )?;
+
+            let queried_slashes = slashes::table
+                .select(SlashDb::as_select())
+                .load::<SlashDb>(conn)?;
+
+            assert_eq!(queried_slashes.len(), 1);
+            assert_eq!(queried_slashes[0].rate.to_string(), "0.05");
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
+    /// Test that only the delegations to the slashed validators are returned.
+    #[tokio::test]
+    async fn test_get_delegations_to_validators() {
+        let db = TestDb::new();
+
+        db.run_test(|conn| {
+            let slashed_validator = Validator::fake();
+            let other_validator = Validator::fake();
+            let slashed_bond = Bond::fake(slashed_validator.address.clone());
+            let other_bond = Bond::fake(other_validator.address.clone());
+
+            seed_bonds(
+                conn,
+                slashed_validator.clone(),
+                vec![slashed_bond.clone()],
+            )?;
+            seed_bonds(conn, other_validator, vec![other_bond])?;
+
+            let (bond_addresses, unbond_addresses) =
+                get_delegations_to_validators(
+                    conn,
+                    HashSet::from([slashed_validator.address.clone()]),
+                )?;
+
+            assert_eq!(
+                bond_addresses,
+                HashSet::from([BondAddresses {
+                    source: slashed_bond.source,
+                    target: slashed_validator.address,
+                }])
+            );
+            assert!(unbond_addresses.is_empty());
+
+            anyhow::Ok(())
+        })
+        .await
+        .expect("Failed to run test");
+    }
+
     fn seed_bonds(
         conn: &mut PgConnection,
         validator: Validator,
@@ -20,13 +20,15 @@ use namada_sdk::state::Key;
 use namada_sdk::storage::DbKeySeg;
 use namada_sdk::token::Amount as NamadaSdkAmount;
 use namada_sdk::{rpc, token};
+use shared::account::{Account, AccountChange};
 use shared::balance::{Amount, Balance, Balances, TokenSupply};
 use shared::block::{BlockHeight, Epoch};
 use shared::checksums::Checksums;
 use shared::id::Id;
 use shared::masp::MaspRewardData;
 use shared::pos::{
-    Bond, BondAddresses, Bonds, Redelegation, Unbond, UnbondAddresses, Unbonds,
+    Bond, BondAddresses, Bonds, Redelegation, Slash, Slashes, Unbond,
+    UnbondAddresses, Unbonds,
 };
 use shared::proposal::{GovernanceProposal, TallyType};
 use shared::token::{IbcRateLimit, IbcToken, Token};
@@ -512,6 +514,45 @@ pub async fn query_bonds(
     anyhow::Ok(bonds)
 }
 
+pub async fn query_accounts(
+    client: &HttpClient,
+    changes: Vec<AccountChange>,
+) -> anyhow::Result<Vec<Account>> {
+    let accounts = futures::stream::iter(changes)
+        .map(|change| async move {
+            let address = NamadaSdkAddress::from(change.address.clone());
+            let operation = || async {
+                rpc::get_account_info(client, &address).await.with_context(
+                    || format!("Failed to query account {}", change.address),
+                )
+            };
+            let account = default_retry(operation).await?;
+
+            anyhow::Ok(account.map(|account| {
+                let mut public_keys = account
+                    .public_keys_map
+                    .idx_to_pk
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                public_keys.sort_by_key(|(index, _)| *index);
+
+                Account::from(
+                    change,
+                    public_keys
+                        .into_iter()
+                        .map(|(_, public_key)| public_key.to_string())
+                        .collect(),
+                    account.threshold,
+                )
+            }))
+        })
+        .buffer_unordered(32)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    anyhow::Ok(accounts.into_iter().flatten().collect())
+}
+
 pub async fn query_unbonds(
     client: &HttpClient,
     addresses: HashSet<UnbondAddresses>,
@@ -586,6 +627,92 @@ pub async fn query_unbonds(
     anyhow::Ok(unbonds)
 }
 
+async fn query_slash_processing_epoch_offset(
+    client: &HttpClient,
+) -> anyhow::Result<u64> {
+    let operation = || async {
+        rpc::get_pos_params(client)
+            .await
+            .with_context(|| "Failed to query pos parameters".to_string())
+            .map(|parameters| parameters.slash_processing_epoch_offset())
+    };
+
+    default_retry(operation).await
+}
+
+/// Processed slashes of `validators`
+pub async fn query_validators_slashes(
+    client: &HttpClient,
+    validators: &HashSet<Id>,
+) -> anyhow::Result<Slashes> {
+    let slash_processing_epoch_offset =
+        query_slash_processing_epoch_offset(client).await?;
+
+    let nested_slashes = futures::stream::iter(validators)
+        .map(|validator| async move {
+            let address = NamadaSdkAddress::from(validator.clone());
+            let operation = || async {
+                RPC.vp()
+                    .pos()
+                    .validator_slashes(client, &address)
+                    .await
+                    .context("Failed to query validator slashes")
+            };
+
+            let slashes = default_retry(operation).await?;
+
+            anyhow::Ok(
+                slashes
+                    .into_iter()
+                    .map(|slash| {
+                        Slash::from(
+                            validator.clone(),
+                            slash,
+                            slash_processing_epoch_offset,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .buffer_unordered(32)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    anyhow::Ok(nested_slashes.into_iter().flatten().collect())
+}
+
+/// Processed slashes of every validator
+pub async fn query_all_slashes(client: &HttpClient) -> anyhow::Result<Slashes> {
+    let slash_processing_epoch_offset =
+        query_slash_processing_epoch_offset(client).await?;
+
+    let operation = || async {
+        RPC.vp()
+            .pos()
+            .slashes(client)
+            .await
+            .context("Failed to query slashes")
+    };
+
+    let slashes = default_retry(operation).await?;
+
+    anyhow::Ok(
+        slashes
+            .into_iter()
+            .flat_map(|(validator, slashes)| {
+                let validator = Id::from(validator);
+                slashes.into_iter().map(move |slash| {
+                    Slash::from(
+                        validator.clone(),
+                        slash,
+                        slash_processing_epoch_offset,
+                    )
+                })
+            })
+            .collect(),
+    )
+}
+
 pub async fn query_redelegations(
     client: &HttpClient,
     addresses: &HashSet<BondAddresses>,
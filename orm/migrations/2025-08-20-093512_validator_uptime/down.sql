@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS validator_uptime;
+DROP TABLE IF EXISTS validator_consensus_addresses;
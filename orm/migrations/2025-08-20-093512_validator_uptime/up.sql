@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE validator_consensus_addresses (
+  consensus_address VARCHAR PRIMARY KEY NOT NULL,
+  validator_id INT NOT NULL,
+  CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+CREATE INDEX index_validator_consensus_addresses_validator_id ON validator_consensus_addresses (validator_id);
+
+CREATE TABLE validator_uptime (
+  id SERIAL PRIMARY KEY,
+  validator_id INT NOT NULL,
+  epoch INT NOT NULL,
+  signed_blocks INT NOT NULL,
+  missed_blocks INT NOT NULL,
+  CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+ALTER TABLE validator_uptime ADD UNIQUE (validator_id, epoch);
+
+CREATE INDEX index_validator_uptime_epoch ON validator_uptime (epoch);
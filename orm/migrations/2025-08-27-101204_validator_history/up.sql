@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE validator_history (
+  id SERIAL PRIMARY KEY,
+  validator_id INT NOT NULL,
+  epoch INT NOT NULL,
+  voting_power INT NOT NULL,
+  max_commission VARCHAR NOT NULL,
+  commission VARCHAR NOT NULL,
+  state VALIDATOR_STATE NOT NULL,
+  CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+ALTER TABLE validator_history ADD UNIQUE (validator_id, epoch);
+
+CREATE INDEX index_validator_history_epoch ON validator_history (epoch);
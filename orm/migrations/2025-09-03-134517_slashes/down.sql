@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS slashes;
+
+DROP TYPE IF EXISTS SLASH_TYPE;
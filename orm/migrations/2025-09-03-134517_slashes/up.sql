@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TYPE SLASH_TYPE AS ENUM ('duplicate_vote', 'light_client_attack');
+
+CREATE TABLE slashes (
+  id SERIAL PRIMARY KEY,
+  validator_id INT NOT NULL,
+  infraction_epoch INT NOT NULL,
+  processing_epoch INT NOT NULL,
+  block_height INT NOT NULL,
+  rate NUMERIC NOT NULL,
+  slash_type SLASH_TYPE NOT NULL,
+  CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+ALTER TABLE slashes ADD UNIQUE (validator_id, block_height, slash_type);
+
+CREATE INDEX index_slashes_processing_epoch ON slashes (processing_epoch);
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE pos_reward_accruals (
+  id SERIAL PRIMARY KEY,
+  owner VARCHAR NOT NULL,
+  validator_id INT NOT NULL,
+  epoch INT NOT NULL,
+  raw_amount NUMERIC(78, 0) NOT NULL,
+  claimed_in_tx VARCHAR(64),
+  CONSTRAINT fk_validator_id FOREIGN KEY(validator_id) REFERENCES validators(id) ON DELETE CASCADE
+);
+
+ALTER TABLE pos_reward_accruals ADD UNIQUE (owner, validator_id, epoch);
+
+CREATE INDEX index_pos_reward_accruals_owner ON pos_reward_accruals USING HASH (owner);
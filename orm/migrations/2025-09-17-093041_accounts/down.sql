@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS account_public_keys;
+
+DROP TABLE IF EXISTS accounts;
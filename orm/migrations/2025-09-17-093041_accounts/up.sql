@@ -0,0 +1,22 @@
+-- Your SQL goes here
+ALTER TYPE TRANSACTION_KIND ADD VALUE 'update_account';
+
+CREATE TABLE accounts (
+  address VARCHAR(45) PRIMARY KEY,
+  threshold INT NOT NULL,
+  vp_code_hash VARCHAR(64),
+  creation_height INT,
+  last_update_height INT NOT NULL
+);
+
+CREATE TABLE account_public_keys (
+  id SERIAL PRIMARY KEY,
+  address VARCHAR(45) NOT NULL,
+  key_index INT NOT NULL,
+  public_key VARCHAR NOT NULL,
+  CONSTRAINT fk_address FOREIGN KEY(address) REFERENCES accounts(address) ON DELETE CASCADE
+);
+
+ALTER TABLE account_public_keys ADD UNIQUE (address, key_index);
+
+CREATE INDEX index_account_public_keys_public_key ON account_public_keys (public_key);
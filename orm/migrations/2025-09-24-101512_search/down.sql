@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS inner_transactions_memo_trgm_idx;
+DROP INDEX IF EXISTS governance_proposals_content_trgm_idx;
+DROP INDEX IF EXISTS validators_website_trgm_idx;
+DROP INDEX IF EXISTS validators_email_trgm_idx;
+DROP INDEX IF EXISTS validators_name_trgm_idx;
+
+DROP EXTENSION IF EXISTS pg_trgm;
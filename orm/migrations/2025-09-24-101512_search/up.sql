@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX validators_name_trgm_idx ON validators USING gin (name gin_trgm_ops);
+CREATE INDEX validators_email_trgm_idx ON validators USING gin (email gin_trgm_ops);
+CREATE INDEX validators_website_trgm_idx ON validators USING gin (website gin_trgm_ops);
+CREATE INDEX governance_proposals_content_trgm_idx ON governance_proposals USING gin (content gin_trgm_ops);
+CREATE INDEX inner_transactions_memo_trgm_idx ON inner_transactions USING gin (memo gin_trgm_ops);
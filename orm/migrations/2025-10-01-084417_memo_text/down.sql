@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS inner_transactions_memo_text_trgm_idx;
+CREATE INDEX inner_transactions_memo_trgm_idx ON inner_transactions USING gin (memo gin_trgm_ops);
+
+DROP INDEX IF EXISTS inner_transactions_memo_text_idx;
+
+ALTER TABLE inner_transactions DROP COLUMN memo_text;
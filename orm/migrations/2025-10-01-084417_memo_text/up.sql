@@ -0,0 +1,22 @@
+-- Your SQL goes here
+ALTER TABLE inner_transactions ADD COLUMN memo_text VARCHAR;
+
+-- Memos that are not valid UTF-8 (or contain nul bytes) are left undecoded
+CREATE FUNCTION pg_temp.decode_memo(memo VARCHAR) RETURNS VARCHAR AS $$
+BEGIN
+  RETURN convert_from(decode(memo, 'hex'), 'UTF8');
+EXCEPTION WHEN others THEN
+  RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+UPDATE inner_transactions
+SET memo_text = pg_temp.decode_memo(memo)
+WHERE memo IS NOT NULL;
+
+-- Supports both exact and prefix (LIKE 'value%') matches
+CREATE INDEX inner_transactions_memo_text_idx ON inner_transactions (memo_text varchar_pattern_ops);
+
+-- Search matches memos on their text rather than their hex encoding
+DROP INDEX IF EXISTS inner_transactions_memo_trgm_idx;
+CREATE INDEX inner_transactions_memo_text_trgm_idx ON inner_transactions USING gin (memo_text gin_trgm_ops);
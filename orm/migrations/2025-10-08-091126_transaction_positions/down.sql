@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_inner_transactions_wrapper_id;
+
+ALTER TABLE inner_transactions DROP COLUMN batch_index;
+ALTER TABLE wrapper_transactions DROP COLUMN block_index;
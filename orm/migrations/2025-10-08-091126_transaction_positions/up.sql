@@ -0,0 +1,7 @@
+-- Your SQL goes here
+-- Position of the transactions in their block and batch, unknown for
+-- transactions indexed before these columns were added
+ALTER TABLE wrapper_transactions ADD COLUMN block_index INT;
+ALTER TABLE inner_transactions ADD COLUMN batch_index INT;
+
+CREATE INDEX IF NOT EXISTS index_inner_transactions_wrapper_id ON inner_transactions (wrapper_id);
@@ -0,0 +1,36 @@
+-- Your SQL goes here
+CREATE TYPE IBC_CHANNEL_STATE AS ENUM ('init', 'try_open', 'open', 'closed');
+
+CREATE TYPE IBC_CONNECTION_STATE AS ENUM ('init', 'try_open', 'open');
+
+CREATE TABLE ibc_clients (
+  client_id VARCHAR PRIMARY KEY,
+  client_type VARCHAR NOT NULL,
+  counterparty_chain_id VARCHAR,
+  consensus_height VARCHAR NOT NULL,
+  creation_height INT,
+  last_update_height INT NOT NULL
+);
+
+CREATE TABLE ibc_connections (
+  connection_id VARCHAR PRIMARY KEY,
+  client_id VARCHAR NOT NULL,
+  counterparty_connection_id VARCHAR,
+  counterparty_client_id VARCHAR NOT NULL,
+  state IBC_CONNECTION_STATE NOT NULL,
+  last_update_height INT NOT NULL
+);
+
+CREATE INDEX index_ibc_connections_client_id ON ibc_connections (client_id);
+
+CREATE TABLE ibc_channels (
+  channel_id VARCHAR PRIMARY KEY,
+  port_id VARCHAR NOT NULL,
+  counterparty_port_id VARCHAR NOT NULL,
+  counterparty_channel_id VARCHAR,
+  connection_id VARCHAR NOT NULL,
+  state IBC_CHANNEL_STATE NOT NULL,
+  last_update_height INT NOT NULL
+);
+
+CREATE INDEX index_ibc_channels_connection_id ON ibc_channels (connection_id);
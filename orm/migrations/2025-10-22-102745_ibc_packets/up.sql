@@ -0,0 +1,37 @@
+-- Your SQL goes here
+CREATE TYPE IBC_PACKET_DIRECTION AS ENUM ('sent', 'received');
+
+CREATE TABLE ibc_packets (
+  id VARCHAR PRIMARY KEY,
+  direction IBC_PACKET_DIRECTION NOT NULL,
+  source_port VARCHAR NOT NULL,
+  source_channel VARCHAR NOT NULL,
+  dest_port VARCHAR NOT NULL,
+  dest_channel VARCHAR NOT NULL,
+  sequence BIGINT NOT NULL,
+  denom VARCHAR,
+  amount NUMERIC,
+  sender VARCHAR,
+  receiver VARCHAR,
+  memo VARCHAR,
+  timeout_timestamp BIGINT NOT NULL,
+  timeout_height VARCHAR NOT NULL,
+  status IBC_STATUS NOT NULL,
+  refunded BOOLEAN NOT NULL DEFAULT false,
+  send_tx_hash VARCHAR,
+  send_block_height INT,
+  recv_tx_hash VARCHAR,
+  recv_block_height INT,
+  ack_tx_hash VARCHAR,
+  ack_block_height INT,
+  timeout_tx_hash VARCHAR,
+  timeout_block_height INT
+);
+
+CREATE INDEX index_ibc_packets_source_channel_sequence ON ibc_packets (source_channel, sequence);
+
+CREATE INDEX index_ibc_packets_dest_channel_sequence ON ibc_packets (dest_channel, sequence);
+
+CREATE INDEX index_ibc_packets_sender ON ibc_packets (sender);
+
+CREATE INDEX index_ibc_packets_receiver ON ibc_packets (receiver);
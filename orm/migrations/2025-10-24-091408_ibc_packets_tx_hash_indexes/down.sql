@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_ibc_packets_recv_tx_hash;
+
+DROP INDEX IF EXISTS index_ibc_packets_send_tx_hash;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX index_ibc_packets_send_tx_hash ON ibc_packets (send_tx_hash);
+
+CREATE INDEX index_ibc_packets_recv_tx_hash ON ibc_packets (recv_tx_hash);
@@ -0,0 +1,19 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE webhook_validator_snapshots;
+DROP TABLE webhook_proposal_snapshots;
+DROP TABLE webhook_dead_letters;
+DROP TABLE webhook_deliveries;
+DROP TABLE webhooks;
+
+DROP TYPE WEBHOOK_TOPIC;
+
+DELETE FROM crawler_state WHERE name = 'webhooks';
+
+ALTER TYPE CRAWLER_NAME RENAME TO CRAWLER_NAME_OLD;
+
+CREATE TYPE CRAWLER_NAME AS ENUM ('chain', 'governance', 'parameters', 'pos', 'rewards', 'transactions', 'cometbft');
+
+ALTER TABLE crawler_state ALTER COLUMN name TYPE CRAWLER_NAME
+USING name::text::CRAWLER_NAME;
+
+DROP TYPE CRAWLER_NAME_OLD;
@@ -0,0 +1,56 @@
+-- Your SQL goes here
+ALTER TYPE CRAWLER_NAME ADD VALUE 'webhooks';
+
+CREATE TYPE WEBHOOK_TOPIC AS ENUM ('address_activity', 'tx_kind', 'proposal_status', 'validator_state');
+
+CREATE TABLE webhooks (
+    id SERIAL PRIMARY KEY,
+    url VARCHAR NOT NULL,
+    secret VARCHAR NOT NULL,
+    topic WEBHOOK_TOPIC NOT NULL,
+    filter VARCHAR,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX index_webhooks_topic ON webhooks (topic);
+
+CREATE TABLE webhook_deliveries (
+    id SERIAL PRIMARY KEY,
+    webhook_id INT NOT NULL,
+    event_id VARCHAR NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INT NOT NULL DEFAULT 0,
+    last_error VARCHAR,
+    next_attempt_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    CONSTRAINT fk_webhook_deliveries_webhook_id FOREIGN KEY(webhook_id) REFERENCES webhooks(id) ON DELETE CASCADE
+);
+
+ALTER TABLE webhook_deliveries ADD UNIQUE (webhook_id, event_id);
+
+CREATE INDEX index_webhook_deliveries_next_attempt_at ON webhook_deliveries (next_attempt_at);
+
+CREATE TABLE webhook_dead_letters (
+    id SERIAL PRIMARY KEY,
+    webhook_id INT NOT NULL,
+    event_id VARCHAR NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INT NOT NULL,
+    last_error VARCHAR,
+    created_at TIMESTAMP NOT NULL,
+    failed_at TIMESTAMP NOT NULL,
+    CONSTRAINT fk_webhook_dead_letters_webhook_id FOREIGN KEY(webhook_id) REFERENCES webhooks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX index_webhook_dead_letters_webhook_id ON webhook_dead_letters (webhook_id);
+
+-- Last seen proposal results and validator states, to tell when they change
+CREATE TABLE webhook_proposal_snapshots (
+    proposal_id INT PRIMARY KEY,
+    result GOVERNANCE_RESULT NOT NULL
+);
+
+CREATE TABLE webhook_validator_snapshots (
+    validator_id INT PRIMARY KEY,
+    state VALIDATOR_STATE NOT NULL
+);
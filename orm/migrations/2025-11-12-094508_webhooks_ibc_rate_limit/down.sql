@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE webhook_ibc_rate_limit_snapshots;
+
+DELETE FROM webhooks WHERE topic = 'ibc_rate_limit';
+
+ALTER TYPE WEBHOOK_TOPIC RENAME TO WEBHOOK_TOPIC_OLD;
+
+CREATE TYPE WEBHOOK_TOPIC AS ENUM ('address_activity', 'tx_kind', 'proposal_status', 'validator_state');
+
+ALTER TABLE webhooks ALTER COLUMN topic TYPE WEBHOOK_TOPIC
+USING topic::text::WEBHOOK_TOPIC;
+
+DROP TYPE WEBHOOK_TOPIC_OLD;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE WEBHOOK_TOPIC ADD VALUE 'ibc_rate_limit';
+
+-- Last epoch in which each token was reported over the rate limit threshold
+CREATE TABLE webhook_ibc_rate_limit_snapshots (
+    address VARCHAR(45) PRIMARY KEY,
+    epoch INT NOT NULL
+);
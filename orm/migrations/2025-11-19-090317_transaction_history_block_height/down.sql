@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS index_transaction_history_target_block_height;
+
+ALTER TABLE transaction_history DROP COLUMN block_height;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+-- Height of the block of the transaction, so that the history of an address
+-- can be read in block order from an index
+ALTER TABLE transaction_history ADD COLUMN block_height INT;
+
+UPDATE transaction_history
+SET block_height = wrapper_transactions.block_height
+FROM inner_transactions
+JOIN wrapper_transactions ON wrapper_transactions.id = inner_transactions.wrapper_id
+WHERE inner_transactions.id = transaction_history.inner_tx_id;
+
+ALTER TABLE transaction_history ALTER COLUMN block_height SET NOT NULL;
+
+CREATE INDEX index_transaction_history_target_block_height ON transaction_history (target, block_height DESC, inner_tx_id DESC, id DESC);
@@ -0,0 +1,88 @@
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use shared::account::Account;
+use shared::id::Id;
+
+use crate::schema::{account_public_keys, accounts};
+
+#[derive(Insertable, Clone, Queryable, Selectable)]
+#[diesel(table_name = accounts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AccountInsertDb {
+    pub address: String,
+    pub threshold: i32,
+    pub vp_code_hash: Option<String>,
+    pub creation_height: Option<i32>,
+    pub last_update_height: i32,
+}
+
+pub type AccountDb = AccountInsertDb;
+
+/// Changes applied to an account that is already indexed, fields left to
+/// `None` keep their current value
+#[derive(AsChangeset, Clone)]
+#[diesel(table_name = accounts)]
+pub struct AccountUpdateDb {
+    pub threshold: i32,
+    pub vp_code_hash: Option<String>,
+    pub creation_height: Option<i32>,
+    pub last_update_height: i32,
+}
+
+#[derive(Insertable, Clone)]
+#[diesel(table_name = account_public_keys)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AccountPublicKeyInsertDb {
+    pub address: String,
+    pub key_index: i32,
+    pub public_key: String,
+}
+
+#[derive(Queryable, Selectable, Clone)]
+#[diesel(table_name = account_public_keys)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct AccountPublicKeyDb {
+    pub id: i32,
+    pub address: String,
+    pub key_index: i32,
+    pub public_key: String,
+}
+
+impl AccountInsertDb {
+    pub fn from(account: &Account) -> Self {
+        Self {
+            address: account.address.to_string(),
+            threshold: i32::from(account.threshold),
+            vp_code_hash: account.vp_code_hash.as_ref().map(Id::to_string),
+            creation_height: account
+                .creation_height
+                .map(|height| height as i32),
+            last_update_height: account.last_update_height as i32,
+        }
+    }
+}
+
+impl From<&AccountInsertDb> for AccountUpdateDb {
+    fn from(account: &AccountInsertDb) -> Self {
+        Self {
+            threshold: account.threshold,
+            vp_code_hash: account.vp_code_hash.clone(),
+            creation_height: account.creation_height,
+            last_update_height: account.last_update_height,
+        }
+    }
+}
+
+impl AccountPublicKeyInsertDb {
+    pub fn from_account(account: &Account) -> Vec<Self> {
+        account
+            .public_keys
+            .iter()
+            .enumerate()
+            .map(|(index, public_key)| Self {
+                address: account.address.to_string(),
+                key_index: index as i32,
+                public_key: public_key.clone(),
+            })
+            .collect()
+    }
+}
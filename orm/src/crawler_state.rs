@@ -21,6 +21,7 @@ pub enum CrawlerNameDb {
     Rewards,
     Transactions,
     Cometbft,
+    Webhooks,
 }
 
 impl Display for CrawlerNameDb {
@@ -33,6 +34,7 @@ impl Display for CrawlerNameDb {
             Self::Rewards => f.write_str("rewards"),
             Self::Transactions => f.write_str("transactions"),
             Self::Cometbft => f.write_str("cometbft"),
+            Self::Webhooks => f.write_str("webhooks"),
         }
     }
 }
@@ -47,6 +49,7 @@ impl From<CrawlerName> for CrawlerNameDb {
             CrawlerName::Rewards => Self::Rewards,
             CrawlerName::Transactions => Self::Transactions,
             CrawlerName::Cometbft => Self::Cometbft,
+            CrawlerName::Webhooks => Self::Webhooks,
         }
     }
 }
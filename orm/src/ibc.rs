@@ -2,10 +2,17 @@ use bigdecimal::BigDecimal;
 use diesel::prelude::Queryable;
 use diesel::{AsChangeset, Insertable, Selectable};
 use serde::{Deserialize, Serialize};
+use shared::ibc::{
+    IbcChannel, IbcChannelState, IbcClient, IbcConnection, IbcConnectionState,
+    IbcPacketDirection, IbcPacketOutcome, IbcPacketRecord,
+};
 use shared::token::IbcRateLimit;
 use shared::transaction::{IbcAckStatus, IbcSequence};
 
-use crate::schema::{ibc_ack, ibc_rate_limits, ibc_token_flows};
+use crate::schema::{
+    ibc_ack, ibc_channels, ibc_clients, ibc_connections, ibc_packets,
+    ibc_rate_limits, ibc_token_flows,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
 #[ExistingTypePath = "crate::schema::sql_types::IbcStatus"]
@@ -106,3 +113,271 @@ pub struct IbcTokenFlowsInsertDb {
     pub deposit: BigDecimal,
     pub withdraw: BigDecimal,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcChannelState"]
+pub enum IbcChannelStateDb {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelState> for IbcChannelStateDb {
+    fn from(value: IbcChannelState) -> Self {
+        match value {
+            IbcChannelState::Init => Self::Init,
+            IbcChannelState::TryOpen => Self::TryOpen,
+            IbcChannelState::Open => Self::Open,
+            IbcChannelState::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcConnectionState"]
+pub enum IbcConnectionStateDb {
+    Init,
+    TryOpen,
+    Open,
+}
+
+impl From<IbcConnectionState> for IbcConnectionStateDb {
+    fn from(value: IbcConnectionState) -> Self {
+        match value {
+            IbcConnectionState::Init => Self::Init,
+            IbcConnectionState::TryOpen => Self::TryOpen,
+            IbcConnectionState::Open => Self::Open,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_channels)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcChannelDb {
+    pub channel_id: String,
+    pub port_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: Option<String>,
+    pub connection_id: String,
+    pub state: IbcChannelStateDb,
+    pub last_update_height: i32,
+}
+
+pub type IbcChannelInsertDb = IbcChannelDb;
+
+impl IbcChannelInsertDb {
+    pub fn from_channel(channel: IbcChannel, height: u64) -> Self {
+        Self {
+            channel_id: channel.channel_id,
+            port_id: channel.port_id,
+            counterparty_port_id: channel.counterparty_port_id,
+            counterparty_channel_id: channel.counterparty_channel_id,
+            connection_id: channel.connection_id,
+            state: channel.state.into(),
+            last_update_height: height as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_connections)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcConnectionDb {
+    pub connection_id: String,
+    pub client_id: String,
+    pub counterparty_connection_id: Option<String>,
+    pub counterparty_client_id: String,
+    pub state: IbcConnectionStateDb,
+    pub last_update_height: i32,
+}
+
+pub type IbcConnectionInsertDb = IbcConnectionDb;
+
+impl IbcConnectionInsertDb {
+    pub fn from_connection(connection: IbcConnection, height: u64) -> Self {
+        Self {
+            connection_id: connection.connection_id,
+            client_id: connection.client_id,
+            counterparty_connection_id: connection.counterparty_connection_id,
+            counterparty_client_id: connection.counterparty_client_id,
+            state: connection.state.into(),
+            last_update_height: height as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = ibc_clients)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcClientDb {
+    pub client_id: String,
+    pub client_type: String,
+    pub counterparty_chain_id: Option<String>,
+    pub consensus_height: String,
+    pub creation_height: Option<i32>,
+    pub last_update_height: i32,
+}
+
+pub type IbcClientInsertDb = IbcClientDb;
+
+impl IbcClientInsertDb {
+    pub fn from_client(client: IbcClient, height: u64) -> Self {
+        Self {
+            client_id: client.client_id,
+            client_type: client.client_type,
+            counterparty_chain_id: client.counterparty_chain_id,
+            consensus_height: client.consensus_height,
+            creation_height: client.created.then_some(height as i32),
+            last_update_height: height as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::IbcPacketDirection"]
+pub enum IbcPacketDirectionDb {
+    Sent,
+    Received,
+}
+
+impl From<IbcPacketDirection> for IbcPacketDirectionDb {
+    fn from(value: IbcPacketDirection) -> Self {
+        match value {
+            IbcPacketDirection::Sent => Self::Sent,
+            IbcPacketDirection::Received => Self::Received,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = ibc_packets)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcPacketDb {
+    pub id: String,
+    pub direction: IbcPacketDirectionDb,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: i64,
+    pub denom: Option<String>,
+    pub amount: Option<BigDecimal>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_timestamp: i64,
+    pub timeout_height: String,
+    pub status: IbcAckStatusDb,
+    pub refunded: bool,
+    pub send_tx_hash: Option<String>,
+    pub send_block_height: Option<i32>,
+    pub recv_tx_hash: Option<String>,
+    pub recv_block_height: Option<i32>,
+    pub ack_tx_hash: Option<String>,
+    pub ack_block_height: Option<i32>,
+    pub timeout_tx_hash: Option<String>,
+    pub timeout_block_height: Option<i32>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = ibc_packets)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcPacketInsertDb {
+    pub id: String,
+    pub direction: IbcPacketDirectionDb,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: i64,
+    pub denom: Option<String>,
+    pub amount: Option<BigDecimal>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_timestamp: i64,
+    pub timeout_height: String,
+    pub status: IbcAckStatusDb,
+    pub send_tx_hash: Option<String>,
+    pub send_block_height: Option<i32>,
+    pub recv_tx_hash: Option<String>,
+    pub recv_block_height: Option<i32>,
+}
+
+impl IbcPacketInsertDb {
+    pub fn from_record(record: IbcPacketRecord, height: u64) -> Self {
+        let IbcPacketRecord {
+            direction,
+            packet,
+            transfer,
+            tx_id,
+            status,
+        } = record;
+        let tx_hash = tx_id.map(|id| id.to_string());
+        let (send_tx_hash, recv_tx_hash) = match direction {
+            IbcPacketDirection::Sent => (tx_hash, None),
+            IbcPacketDirection::Received => (None, tx_hash),
+        };
+        let (send_block_height, recv_block_height) = match direction {
+            IbcPacketDirection::Sent => (Some(height as i32), None),
+            IbcPacketDirection::Received => (None, Some(height as i32)),
+        };
+
+        Self {
+            id: packet.id(),
+            direction: direction.into(),
+            source_port: packet.source_port,
+            source_channel: packet.source_channel,
+            dest_port: packet.dest_port,
+            dest_channel: packet.dest_channel,
+            sequence: packet
+                .sequence
+                .parse()
+                .expect("IBC packet sequence should be a number"),
+            denom: transfer.as_ref().map(|transfer| transfer.denom.clone()),
+            amount: transfer.as_ref().map(|transfer| transfer.amount.clone()),
+            sender: transfer.as_ref().map(|transfer| transfer.sender.clone()),
+            receiver: transfer
+                .as_ref()
+                .map(|transfer| transfer.receiver.clone()),
+            memo: transfer.map(|transfer| transfer.memo),
+            timeout_timestamp: packet.timeout_timestamp as i64,
+            timeout_height: packet.timeout_height,
+            status: status.into(),
+            send_tx_hash,
+            send_block_height,
+            recv_tx_hash,
+            recv_block_height,
+        }
+    }
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = ibc_packets)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct IbcPacketOutcomeUpdateDb {
+    pub status: IbcAckStatusDb,
+    pub refunded: bool,
+    pub ack_tx_hash: Option<String>,
+    pub ack_block_height: Option<i32>,
+    pub timeout_tx_hash: Option<String>,
+    pub timeout_block_height: Option<i32>,
+}
+
+impl IbcPacketOutcomeUpdateDb {
+    pub fn from_outcome(outcome: &IbcPacketOutcome, height: u64) -> Self {
+        let tx_hash = outcome.tx_id.as_ref().map(|id| id.to_string());
+        let timed_out = matches!(outcome.status, IbcAckStatus::Timeout);
+
+        Self {
+            status: outcome.status.clone().into(),
+            refunded: outcome.is_refunded(),
+            ack_tx_hash: tx_hash.clone().filter(|_| !timed_out),
+            ack_block_height: (!timed_out).then_some(height as i32),
+            timeout_tx_hash: tx_hash.filter(|_| timed_out),
+            timeout_block_height: timed_out.then_some(height as i32),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod accounts;
 pub mod balances;
 pub mod blocks;
 pub mod bond;
@@ -11,15 +12,20 @@ pub mod helpers;
 pub mod ibc;
 pub mod masp;
 pub mod migrations;
+pub mod notifications;
 pub mod parameters;
 pub mod pgf;
 pub mod pos_rewards;
 pub mod redelegation;
 pub mod revealed_pk;
 pub mod schema;
+pub mod slashes;
 pub mod token;
 pub mod token_supplies_per_epoch;
 pub mod transactions;
 pub mod unbond;
+pub mod uptime;
+pub mod validator_history;
 pub mod validators;
 pub mod views;
+pub mod webhooks;
@@ -0,0 +1,31 @@
+use diesel::sql_types::Text;
+use diesel::{PgConnection, QueryResult, RunQueryDsl, sql_query};
+use serde::{Deserialize, Serialize};
+
+/// Postgres channel the crawlers NOTIFY on once data has been committed
+pub const INDEXER_NOTIFICATION_CHANNEL: &str = "indexer_events";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IndexerNotification {
+    /// The transactions of the block at `height` have been indexed
+    Block { height: i32 },
+    /// The governance proposal `id` was created or voted on
+    Proposal { id: i32 },
+}
+
+impl IndexerNotification {
+    /// Queue the notification on the current connection. When called within
+    /// a db transaction, the notification is only delivered once the
+    /// transaction commits.
+    pub fn notify(&self, conn: &mut PgConnection) -> QueryResult<()> {
+        let payload = serde_json::to_string(self)
+            .expect("Notification should serialize to json");
+
+        sql_query("SELECT pg_notify($1, $2)")
+            .bind::<Text, _>(INDEXER_NOTIFICATION_CHANNEL)
+            .bind::<Text, _>(payload)
+            .execute(conn)
+            .map(|_| ())
+    }
+}
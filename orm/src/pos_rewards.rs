@@ -2,9 +2,9 @@ use std::str::FromStr;
 
 use bigdecimal::BigDecimal;
 use diesel::{Insertable, Queryable, Selectable};
-use shared::rewards::Reward;
+use shared::rewards::{Reward, RewardAccrual};
 
-use crate::schema::pos_rewards;
+use crate::schema::{pos_reward_accruals, pos_rewards};
 
 #[derive(Insertable, Queryable, Selectable, Clone)]
 #[diesel(table_name = pos_rewards)]
@@ -31,3 +31,37 @@ impl PosRewardInsertDb {
         }
     }
 }
+
+#[derive(Insertable, Clone)]
+#[diesel(table_name = pos_reward_accruals)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PosRewardAccrualInsertDb {
+    pub owner: String,
+    pub validator_id: i32,
+    pub epoch: i32,
+    pub raw_amount: BigDecimal,
+}
+
+#[derive(Queryable, Selectable, Clone)]
+#[diesel(table_name = pos_reward_accruals)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct PosRewardAccrualDb {
+    pub id: i32,
+    pub owner: String,
+    pub validator_id: i32,
+    pub epoch: i32,
+    pub raw_amount: BigDecimal,
+    pub claimed_in_tx: Option<String>,
+}
+
+impl PosRewardAccrualInsertDb {
+    pub fn from_accrual(accrual: RewardAccrual, validator_id: i32) -> Self {
+        Self {
+            owner: accrual.delegation_pair.delegator_address.to_string(),
+            validator_id,
+            epoch: accrual.epoch,
+            raw_amount: BigDecimal::from_str(&accrual.amount.to_string())
+                .unwrap(),
+        }
+    }
+}
@@ -41,6 +41,30 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "history_kind"))]
     pub struct HistoryKind;
 
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_channel_state"))]
+    pub struct IbcChannelState;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_connection_state"))]
+    pub struct IbcConnectionState;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "ibc_packet_direction"))]
+    pub struct IbcPacketDirection;
+
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
@@ -89,6 +113,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "payment_recurrence"))]
     pub struct PaymentRecurrence;
 
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "slash_type"))]
+    pub struct SlashType;
+
     #[derive(
         diesel::query_builder::QueryId,
         std::fmt::Debug,
@@ -128,6 +160,36 @@ pub mod sql_types {
     )]
     #[diesel(postgres_type(name = "vote_kind"))]
     pub struct VoteKind;
+
+    #[derive(
+        diesel::query_builder::QueryId,
+        std::fmt::Debug,
+        diesel::sql_types::SqlType,
+    )]
+    #[diesel(postgres_type(name = "webhook_topic"))]
+    pub struct WebhookTopic;
+}
+
+diesel::table! {
+    account_public_keys (id) {
+        id -> Int4,
+        #[max_length = 45]
+        address -> Varchar,
+        key_index -> Int4,
+        public_key -> Varchar,
+    }
+}
+
+diesel::table! {
+    accounts (address) {
+        #[max_length = 45]
+        address -> Varchar,
+        threshold -> Int4,
+        #[max_length = 64]
+        vp_code_hash -> Nullable<Varchar>,
+        creation_height -> Nullable<Int4>,
+        last_update_height -> Int4,
+    }
 }
 
 diesel::table! {
@@ -285,6 +347,79 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcChannelState;
+
+    ibc_channels (channel_id) {
+        channel_id -> Varchar,
+        port_id -> Varchar,
+        counterparty_port_id -> Varchar,
+        counterparty_channel_id -> Nullable<Varchar>,
+        connection_id -> Varchar,
+        state -> IbcChannelState,
+        last_update_height -> Int4,
+    }
+}
+
+diesel::table! {
+    ibc_clients (client_id) {
+        client_id -> Varchar,
+        client_type -> Varchar,
+        counterparty_chain_id -> Nullable<Varchar>,
+        consensus_height -> Varchar,
+        creation_height -> Nullable<Int4>,
+        last_update_height -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcConnectionState;
+
+    ibc_connections (connection_id) {
+        connection_id -> Varchar,
+        client_id -> Varchar,
+        counterparty_connection_id -> Nullable<Varchar>,
+        counterparty_client_id -> Varchar,
+        state -> IbcConnectionState,
+        last_update_height -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IbcPacketDirection;
+    use super::sql_types::IbcStatus;
+
+    ibc_packets (id) {
+        id -> Varchar,
+        direction -> IbcPacketDirection,
+        source_port -> Varchar,
+        source_channel -> Varchar,
+        dest_port -> Varchar,
+        dest_channel -> Varchar,
+        sequence -> Int8,
+        denom -> Nullable<Varchar>,
+        amount -> Nullable<Numeric>,
+        sender -> Nullable<Varchar>,
+        receiver -> Nullable<Varchar>,
+        memo -> Nullable<Varchar>,
+        timeout_timestamp -> Int8,
+        timeout_height -> Varchar,
+        status -> IbcStatus,
+        refunded -> Bool,
+        send_tx_hash -> Nullable<Varchar>,
+        send_block_height -> Nullable<Int4>,
+        recv_tx_hash -> Nullable<Varchar>,
+        recv_block_height -> Nullable<Int4>,
+        ack_tx_hash -> Nullable<Varchar>,
+        ack_block_height -> Nullable<Int4>,
+        timeout_tx_hash -> Nullable<Varchar>,
+        timeout_block_height -> Nullable<Int4>,
+    }
+}
+
 diesel::table! {
     ibc_rate_limits (id) {
         id -> Int4,
@@ -328,6 +463,8 @@ diesel::table! {
         data -> Nullable<Varchar>,
         memo -> Nullable<Varchar>,
         exit_code -> TransactionResult,
+        memo_text -> Nullable<Varchar>,
+        batch_index -> Nullable<Int4>,
     }
 }
 
@@ -372,6 +509,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    pos_reward_accruals (id) {
+        id -> Int4,
+        owner -> Varchar,
+        validator_id -> Int4,
+        epoch -> Int4,
+        raw_amount -> Numeric,
+        #[max_length = 64]
+        claimed_in_tx -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     pos_rewards (id) {
         id -> Int4,
@@ -415,6 +564,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SlashType;
+
+    slashes (id) {
+        id -> Int4,
+        validator_id -> Int4,
+        infraction_epoch -> Int4,
+        processing_epoch -> Int4,
+        block_height -> Int4,
+        rate -> Numeric,
+        slash_type -> SlashType,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::TokenType;
@@ -447,6 +611,7 @@ diesel::table! {
         inner_tx_id -> Varchar,
         target -> Varchar,
         kind -> HistoryKind,
+        block_height -> Int4,
     }
 }
 
@@ -460,6 +625,38 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    validator_consensus_addresses (consensus_address) {
+        consensus_address -> Varchar,
+        validator_id -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ValidatorState;
+
+    validator_history (id) {
+        id -> Int4,
+        validator_id -> Int4,
+        epoch -> Int4,
+        voting_power -> Int4,
+        max_commission -> Varchar,
+        commission -> Varchar,
+        state -> ValidatorState,
+    }
+}
+
+diesel::table! {
+    validator_uptime (id) {
+        id -> Int4,
+        validator_id -> Int4,
+        epoch -> Int4,
+        signed_blocks -> Int4,
+        missed_blocks -> Int4,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::ValidatorState;
@@ -480,6 +677,74 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook_dead_letters (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        event_id -> Varchar,
+        payload -> Jsonb,
+        attempts -> Int4,
+        last_error -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        failed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Int4,
+        webhook_id -> Int4,
+        event_id -> Varchar,
+        payload -> Jsonb,
+        attempts -> Int4,
+        last_error -> Nullable<Varchar>,
+        next_attempt_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    webhook_ibc_rate_limit_snapshots (address) {
+        #[max_length = 45]
+        address -> Varchar,
+        epoch -> Int4,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::GovernanceResult;
+
+    webhook_proposal_snapshots (proposal_id) {
+        proposal_id -> Int4,
+        result -> GovernanceResult,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ValidatorState;
+
+    webhook_validator_snapshots (validator_id) {
+        validator_id -> Int4,
+        state -> ValidatorState,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WebhookTopic;
+
+    webhooks (id) {
+        id -> Int4,
+        url -> Varchar,
+        secret -> Varchar,
+        topic -> WebhookTopic,
+        filter -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::TransactionResult;
@@ -496,9 +761,11 @@ diesel::table! {
         gas_used -> Nullable<Int4>,
         amount_per_gas_unit -> Nullable<Varchar>,
         masp_fee_payment -> Nullable<Varchar>,
+        block_index -> Nullable<Int4>,
     }
 }
 
+diesel::joinable!(account_public_keys -> accounts (address));
 diesel::joinable!(balance_changes -> blocks (height));
 diesel::joinable!(balance_changes -> token (token));
 diesel::joinable!(bonds -> validators (validator_id));
@@ -509,15 +776,24 @@ diesel::joinable!(ibc_token -> token (address));
 diesel::joinable!(ibc_token_flows -> token (address));
 diesel::joinable!(inner_transactions -> wrapper_transactions (wrapper_id));
 diesel::joinable!(masp_pool -> inner_transactions (inner_tx_id));
+diesel::joinable!(pos_reward_accruals -> validators (validator_id));
 diesel::joinable!(pos_rewards -> validators (validator_id));
 diesel::joinable!(public_good_funding -> governance_proposals (proposal_id));
 diesel::joinable!(redelegation -> validators (validator_id));
+diesel::joinable!(slashes -> validators (validator_id));
 diesel::joinable!(token_supplies_per_epoch -> token (address));
 diesel::joinable!(transaction_history -> inner_transactions (inner_tx_id));
 diesel::joinable!(unbonds -> validators (validator_id));
+diesel::joinable!(validator_consensus_addresses -> validators (validator_id));
+diesel::joinable!(validator_history -> validators (validator_id));
+diesel::joinable!(validator_uptime -> validators (validator_id));
+diesel::joinable!(webhook_dead_letters -> webhooks (webhook_id));
+diesel::joinable!(webhook_deliveries -> webhooks (webhook_id));
 diesel::joinable!(wrapper_transactions -> blocks (block_height));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_public_keys,
+    accounts,
     balance_changes,
     blocks,
     bonds,
@@ -529,6 +805,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     governance_proposals,
     governance_votes,
     ibc_ack,
+    ibc_channels,
+    ibc_clients,
+    ibc_connections,
+    ibc_packets,
     ibc_rate_limits,
     ibc_token,
     ibc_token_flows,
@@ -536,14 +816,25 @@ diesel::allow_tables_to_appear_in_same_query!(
     masp_pool,
     masp_pool_aggregate,
     masp_rates,
+    pos_reward_accruals,
     pos_rewards,
     public_good_funding,
     redelegation,
     revealed_pk,
+    slashes,
     token,
     token_supplies_per_epoch,
     transaction_history,
     unbonds,
+    validator_consensus_addresses,
+    validator_history,
+    validator_uptime,
     validators,
+    webhook_dead_letters,
+    webhook_deliveries,
+    webhook_ibc_rate_limit_snapshots,
+    webhook_proposal_snapshots,
+    webhook_validator_snapshots,
+    webhooks,
     wrapper_transactions,
 );
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use shared::pos::{Slash, SlashType};
+
+use crate::schema::slashes;
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::SlashType"]
+pub enum SlashTypeDb {
+    DuplicateVote,
+    LightClientAttack,
+}
+
+impl From<SlashType> for SlashTypeDb {
+    fn from(value: SlashType) -> Self {
+        match value {
+            SlashType::DuplicateVote => Self::DuplicateVote,
+            SlashType::LightClientAttack => Self::LightClientAttack,
+        }
+    }
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = slashes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SlashInsertDb {
+    pub validator_id: i32,
+    pub infraction_epoch: i32,
+    pub processing_epoch: i32,
+    pub block_height: i32,
+    pub rate: BigDecimal,
+    pub slash_type: SlashTypeDb,
+}
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = slashes)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct SlashDb {
+    pub id: i32,
+    pub validator_id: i32,
+    pub infraction_epoch: i32,
+    pub processing_epoch: i32,
+    pub block_height: i32,
+    pub rate: BigDecimal,
+    pub slash_type: SlashTypeDb,
+}
+
+impl SlashInsertDb {
+    pub fn from_slash(slash: Slash, validator_id: i32) -> Self {
+        Self {
+            validator_id,
+            infraction_epoch: slash.infraction_epoch as i32,
+            processing_epoch: slash.processing_epoch as i32,
+            block_height: slash.block_height as i32,
+            rate: BigDecimal::from_str(&slash.rate)
+                .expect("Invalid slash rate"),
+            slash_type: slash.kind.into(),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use shared::transaction::{
     InnerTransaction, TransactionExitStatus, TransactionHistoryKind,
     TransactionKind, TransactionTarget, WrapperTransaction,
 };
+use shared::utils;
 
 use crate::schema::{
     inner_transactions, transaction_history, wrapper_transactions,
@@ -37,6 +38,7 @@ pub enum TransactionKindDb {
     UnjailValidator,
     ChangeConsensusKey,
     InitAccount,
+    UpdateAccount,
     Unknown,
 }
 
@@ -51,6 +53,7 @@ impl From<TransactionKind> for TransactionKindDb {
                 Self::UnshieldingTransfer
             }
             TransactionKind::InitAccount(_) => Self::InitAccount,
+            TransactionKind::UpdateAccount(_) => Self::UpdateAccount,
             TransactionKind::ChangeConsensusKey(_) => Self::ChangeConsensusKey,
             TransactionKind::ShieldingTransfer(_) => Self::ShieldingTransfer,
             TransactionKind::MixedTransfer(_) => Self::MixedTransfer,
@@ -114,6 +117,8 @@ pub struct InnerTransactionInsertDb {
     pub data: Option<String>,
     pub memo: Option<String>,
     pub exit_code: TransactionResultDb,
+    pub memo_text: Option<String>,
+    pub batch_index: Option<i32>,
 }
 
 pub type InnerTransactionDb = InnerTransactionInsertDb;
@@ -125,8 +130,10 @@ impl InnerTransactionInsertDb {
             wrapper_id: tx.wrapper_id.to_string(),
             kind: TransactionKindDb::from(tx.kind),
             data: tx.data,
+            memo_text: tx.memo.as_deref().and_then(utils::memo_to_text),
             memo: tx.memo,
             exit_code: TransactionResultDb::from(tx.exit_code),
+            batch_index: Some(tx.index as i32),
         }
     }
 }
@@ -145,6 +152,7 @@ pub struct WrapperTransactionDb {
     pub block_height: i32,
     pub exit_code: TransactionResultDb,
     pub atomic: bool,
+    pub block_index: Option<i32>,
 }
 
 pub type WrapperTransactionInsertDb = WrapperTransactionDb;
@@ -162,6 +170,7 @@ impl WrapperTransactionInsertDb {
             block_height: tx.block_height as i32,
             exit_code: TransactionResultDb::from(tx.exit_code),
             atomic: tx.atomic,
+            block_index: Some(tx.index as i32),
         }
     }
 }
@@ -190,6 +199,7 @@ pub struct TransactionHistoryDb {
     pub inner_tx_id: String,
     pub target: String,
     pub kind: TransactionHistoryKindDb,
+    pub block_height: i32,
 }
 
 #[derive(Serialize, Insertable, Clone)]
@@ -199,14 +209,16 @@ pub struct TransactionHistoryInsertDb {
     pub inner_tx_id: String,
     pub target: String,
     pub kind: TransactionHistoryKindDb,
+    pub block_height: i32,
 }
 
 impl TransactionHistoryInsertDb {
-    pub fn from(target: TransactionTarget) -> Self {
+    pub fn from(target: TransactionTarget, block_height: i32) -> Self {
         Self {
             inner_tx_id: target.inner_tx.to_string(),
             target: target.address,
             kind: TransactionHistoryKindDb::from(target.kind),
+            block_height,
         }
     }
 }
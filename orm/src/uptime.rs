@@ -0,0 +1,44 @@
+use diesel::{Insertable, Queryable, Selectable};
+use shared::uptime::ValidatorUptime;
+
+use crate::schema::{validator_consensus_addresses, validator_uptime};
+
+#[derive(Insertable, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = validator_consensus_addresses)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorConsensusAddressDb {
+    pub consensus_address: String,
+    pub validator_id: i32,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = validator_uptime)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorUptimeInsertDb {
+    pub validator_id: i32,
+    pub epoch: i32,
+    pub signed_blocks: i32,
+    pub missed_blocks: i32,
+}
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = validator_uptime)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorUptimeDb {
+    pub id: i32,
+    pub validator_id: i32,
+    pub epoch: i32,
+    pub signed_blocks: i32,
+    pub missed_blocks: i32,
+}
+
+impl ValidatorUptimeInsertDb {
+    pub fn from_uptime(uptime: ValidatorUptime, validator_id: i32) -> Self {
+        Self {
+            validator_id,
+            epoch: uptime.epoch as i32,
+            signed_blocks: uptime.signed_blocks as i32,
+            missed_blocks: uptime.missed_blocks as i32,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use diesel::{Insertable, Queryable, Selectable};
+
+use crate::schema::validator_history;
+use crate::validators::{ValidatorInsertDb, ValidatorStateDb};
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = validator_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorHistoryInsertDb {
+    pub validator_id: i32,
+    pub epoch: i32,
+    pub voting_power: i32,
+    pub max_commission: String,
+    pub commission: String,
+    pub state: ValidatorStateDb,
+}
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = validator_history)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ValidatorHistoryDb {
+    pub id: i32,
+    pub validator_id: i32,
+    pub epoch: i32,
+    pub voting_power: i32,
+    pub max_commission: String,
+    pub commission: String,
+    pub state: ValidatorStateDb,
+}
+
+impl ValidatorHistoryInsertDb {
+    pub fn from_validator(
+        validator: &ValidatorInsertDb,
+        validator_id: i32,
+        epoch: u32,
+    ) -> Self {
+        Self {
+            validator_id,
+            epoch: epoch as i32,
+            voting_power: validator.voting_power,
+            max_commission: validator.max_commission.clone(),
+            commission: validator.commission.clone(),
+            state: validator.state.clone(),
+        }
+    }
+}
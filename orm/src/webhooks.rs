@@ -0,0 +1,174 @@
+use chrono::NaiveDateTime;
+use diesel::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::webhook::{Webhook, WebhookTopic};
+
+use crate::governance_proposal::GovernanceProposalResultDb;
+use crate::schema::{
+    webhook_dead_letters, webhook_deliveries, webhook_ibc_rate_limit_snapshots,
+    webhook_proposal_snapshots, webhook_validator_snapshots, webhooks,
+};
+use crate::validators::ValidatorStateDb;
+
+#[derive(Debug, Clone, Serialize, Deserialize, diesel_derive_enum::DbEnum)]
+#[ExistingTypePath = "crate::schema::sql_types::WebhookTopic"]
+pub enum WebhookTopicDb {
+    AddressActivity,
+    TxKind,
+    ProposalStatus,
+    ValidatorState,
+    IbcRateLimit,
+}
+
+impl From<WebhookTopic> for WebhookTopicDb {
+    fn from(value: WebhookTopic) -> Self {
+        match value {
+            WebhookTopic::AddressActivity => Self::AddressActivity,
+            WebhookTopic::TxKind => Self::TxKind,
+            WebhookTopic::ProposalStatus => Self::ProposalStatus,
+            WebhookTopic::ValidatorState => Self::ValidatorState,
+            WebhookTopic::IbcRateLimit => Self::IbcRateLimit,
+        }
+    }
+}
+
+impl From<WebhookTopicDb> for WebhookTopic {
+    fn from(value: WebhookTopicDb) -> Self {
+        match value {
+            WebhookTopicDb::AddressActivity => Self::AddressActivity,
+            WebhookTopicDb::TxKind => Self::TxKind,
+            WebhookTopicDb::ProposalStatus => Self::ProposalStatus,
+            WebhookTopicDb::ValidatorState => Self::ValidatorState,
+            WebhookTopicDb::IbcRateLimit => Self::IbcRateLimit,
+        }
+    }
+}
+
+#[derive(Serialize, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = webhooks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookDb {
+    pub id: i32,
+    pub url: String,
+    pub secret: String,
+    pub topic: WebhookTopicDb,
+    pub filter: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<WebhookDb> for Webhook {
+    fn from(value: WebhookDb) -> Self {
+        Self {
+            id: value.id,
+            url: value.url,
+            secret: value.secret,
+            topic: WebhookTopic::from(value.topic),
+            filter: value.filter,
+        }
+    }
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = webhooks)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookInsertDb {
+    pub url: String,
+    pub secret: String,
+    pub topic: WebhookTopicDb,
+    pub filter: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = webhook_deliveries)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookDeliveryDb {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event_id: String,
+    pub payload: Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = webhook_deliveries)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookDeliveryInsertDb {
+    pub webhook_id: i32,
+    pub event_id: String,
+    pub payload: Value,
+    pub next_attempt_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Queryable, Selectable, Clone, Debug)]
+#[diesel(table_name = webhook_dead_letters)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookDeadLetterDb {
+    pub id: i32,
+    pub webhook_id: i32,
+    pub event_id: String,
+    pub payload: Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub failed_at: NaiveDateTime,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = webhook_dead_letters)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookDeadLetterInsertDb {
+    pub webhook_id: i32,
+    pub event_id: String,
+    pub payload: Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub failed_at: NaiveDateTime,
+}
+
+impl WebhookDeadLetterInsertDb {
+    pub fn from_delivery(
+        delivery: WebhookDeliveryDb,
+        failed_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            webhook_id: delivery.webhook_id,
+            event_id: delivery.event_id,
+            payload: delivery.payload,
+            attempts: delivery.attempts,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at,
+            failed_at,
+        }
+    }
+}
+
+#[derive(Queryable, Selectable, Insertable, Clone, Debug)]
+#[diesel(table_name = webhook_proposal_snapshots)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookProposalSnapshotDb {
+    pub proposal_id: i32,
+    pub result: GovernanceProposalResultDb,
+}
+
+#[derive(Queryable, Selectable, Insertable, Clone, Debug)]
+#[diesel(table_name = webhook_validator_snapshots)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookValidatorSnapshotDb {
+    pub validator_id: i32,
+    pub state: ValidatorStateDb,
+}
+
+#[derive(Queryable, Selectable, Insertable, Clone, Debug)]
+#[diesel(table_name = webhook_ibc_rate_limit_snapshots)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookIbcRateLimitSnapshotDb {
+    pub address: String,
+    pub epoch: i32,
+}
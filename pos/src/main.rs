@@ -1,4 +1,6 @@
+use std::collections::HashSet;
 use std::convert::identity;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use chrono::{NaiveDateTime, Utc};
@@ -12,14 +14,21 @@ use pos::app_state::AppState;
 use pos::config::AppConfig;
 use pos::repository::{self};
 use pos::services::{
-    namada as namada_service, tendermint as tendermint_service,
+    db as db_service, namada as namada_service,
+    tendermint as tendermint_service,
 };
+use shared::block::Epoch;
 use shared::client::Client;
 use shared::crawler;
 use shared::crawler_state::{CrawlerName, EpochCrawlerState};
 use shared::error::{AsDbError, AsRpcError, ContextDbInteractError, MainError};
+use shared::uptime::epoch_uptime;
+use shared::validator::ValidatorState;
 use tendermint_rpc::HttpClient;
 
+/// Number of past epochs the uptime is computed for when catching up
+const UPTIME_BACKFILL_EPOCHS: Epoch = 10;
+
 #[tokio::main]
 async fn main() -> Result<(), MainError> {
     let config = AppConfig::parse();
@@ -89,6 +98,45 @@ async fn crawling_fn(
         "Queried validators successfully...",
     );
 
+    let mut uptime_commits = Vec::new();
+    for epoch in uptime_epochs(&conn, epoch_to_process).await? {
+        let signers = db_service::get_epoch_commit_signers(&conn, epoch)
+            .await
+            .into_db_error()?;
+
+        if signers.is_empty() {
+            tracing::warn!(epoch, "No cometbft blocks stored, skipping uptime");
+            continue;
+        }
+
+        let consensus_validators =
+            namada_service::get_consensus_validators_at_epoch(&client, epoch)
+                .await
+                .into_rpc_error()?;
+        uptime_commits.push((epoch, consensus_validators, signers));
+    }
+
+    // Validators which left the consensus set since still signed the commits
+    // of the epochs the uptime is computed for
+    let consensus_validators = validators_set
+        .validators
+        .iter()
+        .filter(|validator| validator.state == ValidatorState::Consensus)
+        .map(|validator| validator.address.clone())
+        .chain(
+            uptime_commits
+                .iter()
+                .flat_map(|(_, validators, _)| validators.iter().cloned()),
+        )
+        .collect::<HashSet<_>>();
+
+    let consensus_addresses = namada_service::get_consensus_addresses(
+        &client,
+        consensus_validators.into_iter().collect(),
+    )
+    .await
+    .into_rpc_error()?;
+
     let timestamp = DateTimeUtc::now().0.timestamp();
     let crawler_state = EpochCrawlerState {
         last_processed_epoch: epoch_to_process,
@@ -112,6 +160,36 @@ async fn crawling_fn(
                     validators_dbo,
                 )?;
 
+                repository::pos::upsert_validator_history(
+                    transaction_conn,
+                    validators_dbo,
+                    epoch_to_process,
+                )?;
+
+                repository::uptime::upsert_consensus_addresses(
+                    transaction_conn,
+                    consensus_addresses,
+                )?;
+
+                let known_consensus_addresses =
+                    repository::uptime::get_consensus_addresses(
+                        transaction_conn,
+                    )?;
+
+                for (epoch, consensus_validators, signers) in uptime_commits {
+                    let uptime = epoch_uptime(
+                        epoch,
+                        &consensus_validators,
+                        &known_consensus_addresses,
+                        &signers,
+                    );
+
+                    repository::uptime::upsert_uptime(
+                        transaction_conn,
+                        uptime,
+                    )?;
+                }
+
                 repository::crawler_state::upsert_crawler_state(
                     transaction_conn,
                     crawler_state,
@@ -130,6 +208,34 @@ async fn crawling_fn(
     Ok(())
 }
 
+/// Epochs whose uptime is not computed yet and whose blocks are all stored
+/// by the cometbft crawler, which is the case once it moved past them
+async fn uptime_epochs(
+    conn: &Object,
+    epoch: Epoch,
+) -> Result<RangeInclusive<Epoch>, MainError> {
+    let last_block_epoch = db_service::get_last_block_epoch(conn)
+        .await
+        .into_db_error()?;
+    let last_uptime_epoch = db_service::get_last_uptime_epoch(conn)
+        .await
+        .into_db_error()?;
+
+    let Some(to) = last_block_epoch
+        .map(|last_block_epoch| last_block_epoch.min(epoch))
+        .and_then(|last_epoch| last_epoch.checked_sub(1))
+    else {
+        return Ok(RangeInclusive::new(1, 0));
+    };
+
+    let from = last_uptime_epoch
+        .map(|last_uptime_epoch| last_uptime_epoch + 1)
+        .unwrap_or(to)
+        .max(to.saturating_sub(UPTIME_BACKFILL_EPOCHS - 1));
+
+    Ok(from..=to)
+}
+
 async fn can_process(
     epoch: u32,
     client: Arc<HttpClient>,
@@ -1,2 +1,3 @@
 pub mod crawler_state;
 pub mod pos;
+pub mod uptime;
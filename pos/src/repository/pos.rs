@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
 use diesel::upsert::excluded;
-use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
-use orm::schema::validators;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
+use orm::schema::{validator_history, validators};
+use orm::validator_history::ValidatorHistoryInsertDb;
 use orm::validators::ValidatorInsertDb;
+use shared::block::Epoch;
 
 pub fn upsert_validators(
     transaction_conn: &mut PgConnection,
@@ -26,3 +30,55 @@ pub fn upsert_validators(
 
     Ok(())
 }
+
+/// Ids of the validators, by namada address
+pub fn get_validator_ids(
+    transaction_conn: &mut PgConnection,
+) -> anyhow::Result<HashMap<String, i32>> {
+    let ids = validators::table
+        .select((validators::namada_address, validators::id))
+        .load::<(String, i32)>(transaction_conn)
+        .context("Failed to read validators from db")?;
+
+    Ok(ids.into_iter().collect())
+}
+
+/// Record the voting power, commission and state of the validators at
+/// `epoch`
+pub fn upsert_validator_history(
+    transaction_conn: &mut PgConnection,
+    validators_db: &[ValidatorInsertDb],
+    epoch: Epoch,
+) -> anyhow::Result<()> {
+    let validator_ids = get_validator_ids(transaction_conn)?;
+
+    let history_db = validators_db
+        .iter()
+        .filter_map(|validator| {
+            validator_ids.get(&validator.namada_address).map(|id| {
+                ValidatorHistoryInsertDb::from_validator(validator, *id, epoch)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(validator_history::table)
+        .values::<&Vec<ValidatorHistoryInsertDb>>(&history_db)
+        .on_conflict((
+            validator_history::validator_id,
+            validator_history::epoch,
+        ))
+        .do_update()
+        .set((
+            validator_history::voting_power
+                .eq(excluded(validator_history::voting_power)),
+            validator_history::max_commission
+                .eq(excluded(validator_history::max_commission)),
+            validator_history::commission
+                .eq(excluded(validator_history::commission)),
+            validator_history::state.eq(excluded(validator_history::state)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update validator history in db")?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use diesel::upsert::excluded;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
+use orm::schema::{
+    validator_consensus_addresses, validator_uptime, validators,
+};
+use orm::uptime::{ValidatorConsensusAddressDb, ValidatorUptimeInsertDb};
+use shared::id::Id;
+use shared::uptime::ValidatorUptime;
+
+use super::pos::get_validator_ids;
+
+pub fn upsert_consensus_addresses(
+    transaction_conn: &mut PgConnection,
+    consensus_addresses: Vec<(Id, String)>,
+) -> anyhow::Result<()> {
+    let validator_ids = get_validator_ids(transaction_conn)?;
+
+    let consensus_addresses_db = consensus_addresses
+        .into_iter()
+        .filter_map(|(validator, consensus_address)| {
+            validator_ids
+                .get(&validator.to_string())
+                .map(|validator_id| ValidatorConsensusAddressDb {
+                    consensus_address,
+                    validator_id: *validator_id,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(validator_consensus_addresses::table)
+        .values::<&Vec<ValidatorConsensusAddressDb>>(&consensus_addresses_db)
+        .on_conflict(validator_consensus_addresses::consensus_address)
+        .do_update()
+        .set(
+            validator_consensus_addresses::validator_id
+                .eq(excluded(validator_consensus_addresses::validator_id)),
+        )
+        .execute(transaction_conn)
+        .context("Failed to update validator consensus addresses in db")?;
+
+    Ok(())
+}
+
+/// Every consensus address ever used by a validator
+pub fn get_consensus_addresses(
+    transaction_conn: &mut PgConnection,
+) -> anyhow::Result<HashMap<String, Id>> {
+    let consensus_addresses = validator_consensus_addresses::table
+        .inner_join(validators::table)
+        .select((
+            validator_consensus_addresses::consensus_address,
+            validators::namada_address,
+        ))
+        .load::<(String, String)>(transaction_conn)
+        .context("Failed to read validator consensus addresses from db")?;
+
+    Ok(consensus_addresses
+        .into_iter()
+        .map(|(consensus_address, address)| {
+            (consensus_address, Id::Account(address))
+        })
+        .collect())
+}
+
+pub fn upsert_uptime(
+    transaction_conn: &mut PgConnection,
+    uptime: Vec<ValidatorUptime>,
+) -> anyhow::Result<()> {
+    let validator_ids = get_validator_ids(transaction_conn)?;
+
+    let uptime_db = uptime
+        .into_iter()
+        .filter_map(|uptime| {
+            validator_ids
+                .get(&uptime.validator.to_string())
+                .copied()
+                .map(|validator_id| {
+                    ValidatorUptimeInsertDb::from_uptime(uptime, validator_id)
+                })
+        })
+        .collect::<Vec<_>>();
+
+    diesel::insert_into(validator_uptime::table)
+        .values::<&Vec<ValidatorUptimeInsertDb>>(&uptime_db)
+        .on_conflict((validator_uptime::validator_id, validator_uptime::epoch))
+        .do_update()
+        .set((
+            validator_uptime::signed_blocks
+                .eq(excluded(validator_uptime::signed_blocks)),
+            validator_uptime::missed_blocks
+                .eq(excluded(validator_uptime::missed_blocks)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update validator uptime in db")?;
+
+    Ok(())
+}
@@ -1,10 +1,16 @@
+use std::collections::HashSet;
+
 use anyhow::Context;
 use deadpool_diesel::postgres::Object;
 use diesel::dsl::max;
-use diesel::{QueryDsl, RunQueryDsl};
-use orm::schema::crawler_state;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use orm::schema::{cometbft_block, crawler_state, validator_uptime};
 use shared::block::Epoch;
 use shared::error::ContextDbInteractError;
+use shared::uptime::commit_signers;
+use tendermint_rpc::endpoint::block::Response as TendermintBlockResponse;
+
+const BLOCKS_CHUNK_SIZE: usize = 100;
 
 pub async fn get_last_synched_epoch(
     conn: &Object,
@@ -21,3 +27,85 @@ pub async fn get_last_synched_epoch(
 
     Ok(epoch.map(|h| h as Epoch))
 }
+
+pub async fn get_last_uptime_epoch(
+    conn: &Object,
+) -> anyhow::Result<Option<Epoch>> {
+    let epoch = conn
+        .interact(move |conn| {
+            validator_uptime::table
+                .select(max(validator_uptime::epoch))
+                .first::<Option<i32>>(conn)
+        })
+        .await
+        .context_db_interact_error()?
+        .context("Failed to read validator uptime max epoch in db")?;
+
+    Ok(epoch.map(|e| e as Epoch))
+}
+
+/// Last epoch with blocks stored by the cometbft crawler
+pub async fn get_last_block_epoch(
+    conn: &Object,
+) -> anyhow::Result<Option<Epoch>> {
+    let epoch = conn
+        .interact(move |conn| {
+            cometbft_block::table
+                .select(max(cometbft_block::epoch))
+                .first::<Option<i32>>(conn)
+        })
+        .await
+        .context_db_interact_error()?
+        .context("Failed to read cometbft block max epoch in db")?;
+
+    Ok(epoch.map(|e| e as Epoch))
+}
+
+/// Signers of the commits stored in the blocks of `epoch`. The commit of a
+/// block is stored in the next one, so the last commit of an epoch counts
+/// towards the next epoch. Blocks are read in chunks and decoded once the
+/// connection is released.
+pub async fn get_epoch_commit_signers(
+    conn: &Object,
+    epoch: Epoch,
+) -> anyhow::Result<Vec<HashSet<String>>> {
+    let heights = conn
+        .interact(move |conn| {
+            cometbft_block::table
+                .filter(cometbft_block::epoch.eq(epoch as i32))
+                .select(cometbft_block::id)
+                .order(cometbft_block::id.asc())
+                .load::<i32>(conn)
+        })
+        .await
+        .context_db_interact_error()?
+        .context("Failed to read cometbft blocks from db")?;
+
+    let mut signers = Vec::with_capacity(heights.len());
+
+    for chunk in heights.chunks(BLOCKS_CHUNK_SIZE) {
+        let chunk = chunk.to_vec();
+        let blocks = conn
+            .interact(move |conn| {
+                cometbft_block::table
+                    .filter(cometbft_block::id.eq_any(chunk))
+                    .select(cometbft_block::encoded_block)
+                    .load::<String>(conn)
+            })
+            .await
+            .context_db_interact_error()?
+            .context("Failed to read cometbft blocks from db")?;
+
+        for block in blocks {
+            let block: TendermintBlockResponse =
+                serde_json::from_str(&block)
+                    .context("Failed to decode cometbft block")?;
+
+            if let Some(commit) = block.block.last_commit {
+                signers.push(commit_signers(&commit));
+            }
+        }
+    }
+
+    Ok(signers)
+}
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use anyhow::Context;
 use futures::{StreamExt, TryStreamExt};
 use namada_core::chain::Epoch as NamadaSdkEpoch;
+use namada_core::key::tm_consensus_key_raw_hash;
 use namada_sdk::address::Address;
 use namada_sdk::rpc;
 use shared::block::Epoch;
@@ -150,6 +151,55 @@ pub async fn get_validators_state(
     Ok(ValidatorSet { validators, epoch })
 }
 
+/// Consensus addresses of the given validators, as upper case hex
+pub async fn get_consensus_addresses(
+    client: &HttpClient,
+    validators: Vec<Id>,
+) -> anyhow::Result<Vec<(Id, String)>> {
+    futures::stream::iter(validators)
+        .map(|validator| async move {
+            let address = Address::from(validator.clone());
+            let consensus_key =
+                rpc::query_validator_consensus_keys(client, &address)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to query the consensus key of validator \
+                             {address}"
+                        )
+                    })?;
+
+            anyhow::Ok(consensus_key.map(|consensus_key| {
+                (validator, tm_consensus_key_raw_hash(&consensus_key))
+            }))
+        })
+        .buffer_unordered(32)
+        .try_filter_map(
+            |consensus_address| async move { Ok(consensus_address) },
+        )
+        .try_collect()
+        .await
+}
+
+pub async fn get_consensus_validators_at_epoch(
+    client: &HttpClient,
+    epoch: Epoch,
+) -> anyhow::Result<HashSet<Id>> {
+    let validators = rpc::get_all_consensus_validators(client, to_epoch(epoch))
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to query Namada's consensus validators at epoch \
+                 {epoch}"
+            )
+        })?;
+
+    Ok(validators
+        .into_iter()
+        .map(|validator| Id::Account(validator.address.to_string()))
+        .collect())
+}
+
 pub async fn get_current_epoch(client: &HttpClient) -> anyhow::Result<Epoch> {
     let epoch = rpc::query_epoch(client)
         .await
@@ -138,6 +138,12 @@ async fn crawling_fn(
     conn.interact(move |conn| {
         conn.build_transaction().read_write().run(
             |transaction_conn: &mut diesel::pg::PgConnection| {
+                repository::pos_rewards::insert_reward_accruals(
+                    transaction_conn,
+                    &non_zero_rewards,
+                    epoch_to_process as i32,
+                )?;
+
                 repository::pos_rewards::upsert_rewards(
                     transaction_conn,
                     non_zero_rewards,
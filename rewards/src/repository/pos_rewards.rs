@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use anyhow::Context;
+use bigdecimal::BigDecimal;
 use diesel::upsert::excluded;
 use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
-use orm::pos_rewards::PosRewardInsertDb;
-use orm::schema::{pos_rewards, validators};
+use orm::pos_rewards::{PosRewardAccrualInsertDb, PosRewardInsertDb};
+use orm::schema::{pos_reward_accruals, pos_rewards, validators};
+use shared::balance::Amount;
 use shared::rewards::Reward;
 use shared::tuple_len::TupleLen;
 
@@ -66,3 +70,84 @@ fn upsert_rewards_chunk(
 
     Ok(())
 }
+
+/// Appends to the ledger the rewards each delegation earned during `epoch`,
+/// computed against the unclaimed rewards stored for the previous epoch.
+/// Delegations missing from that snapshot had nothing unclaimed. Without a
+/// snapshot of the previous epoch, e.g. on the first crawled epoch or after
+/// downtime, the rewards earned during `epoch` are unknown and none are
+/// recorded.
+pub fn insert_reward_accruals(
+    transaction_conn: &mut PgConnection,
+    rewards: &[Reward],
+    epoch: i32,
+) -> anyhow::Result<()> {
+    let previous_rewards = pos_rewards::table
+        .inner_join(validators::table)
+        .filter(pos_rewards::epoch.eq(epoch - 1))
+        .select((
+            pos_rewards::owner,
+            validators::namada_address,
+            pos_rewards::raw_amount,
+            pos_rewards::claimed,
+        ))
+        .load::<(String, String, BigDecimal, bool)>(transaction_conn)
+        .context("Failed to query previous epoch rewards")?;
+
+    if previous_rewards.is_empty() {
+        tracing::warn!(
+            epoch,
+            "No rewards stored for the previous epoch, skipping accruals"
+        );
+        return anyhow::Ok(());
+    }
+
+    let previous_unclaimed: HashMap<(String, String), Amount> =
+        previous_rewards
+            .into_iter()
+            .filter(|(_, _, _, claimed)| !claimed)
+            .map(|(owner, validator, amount, _)| {
+                ((owner, validator), Amount::from(amount))
+            })
+            .collect();
+
+    let validator_ids: HashMap<String, i32> = validators::table
+        .select((validators::namada_address, validators::id))
+        .load::<(String, i32)>(transaction_conn)
+        .context("Failed to query validators")?
+        .into_iter()
+        .collect();
+
+    let accruals = rewards
+        .iter()
+        .filter_map(|reward| {
+            let validator =
+                reward.delegation_pair.validator_address.to_string();
+            let previous = previous_unclaimed.get(&(
+                reward.delegation_pair.delegator_address.to_string(),
+                validator.clone(),
+            ));
+            let validator_id = *validator_ids
+                .get(&validator)
+                .expect("Failed to get validator");
+
+            reward.accrual(previous).map(|accrual| {
+                PosRewardAccrualInsertDb::from_accrual(accrual, validator_id)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let accruals_col_count = pos_reward_accruals::all_columns.len() as i64;
+
+    for chunk in
+        accruals.chunks((MAX_PARAM_SIZE as i64 / accruals_col_count) as usize)
+    {
+        diesel::insert_into(pos_reward_accruals::table)
+            .values(chunk)
+            .on_conflict_do_nothing()
+            .execute(transaction_conn)
+            .context("Failed to insert reward accruals in db")?;
+    }
+
+    anyhow::Ok(())
+}
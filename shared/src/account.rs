@@ -0,0 +1,42 @@
+use crate::block::BlockHeight;
+use crate::id::Id;
+
+/// Established account created or updated by a transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountChange {
+    pub address: Id,
+    /// Set when the transaction changed the validity predicate
+    pub vp_code_hash: Option<Id>,
+    /// Set when the transaction created the account
+    pub creation_height: Option<BlockHeight>,
+    pub height: BlockHeight,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    pub address: Id,
+    pub public_keys: Vec<String>,
+    pub threshold: u8,
+    pub vp_code_hash: Option<Id>,
+    pub creation_height: Option<BlockHeight>,
+    pub last_update_height: BlockHeight,
+}
+
+impl Account {
+    /// Keys and threshold are read from the chain, the validity predicate and
+    /// heights come from the transaction that changed the account
+    pub fn from(
+        change: AccountChange,
+        public_keys: Vec<String>,
+        threshold: u8,
+    ) -> Self {
+        Self {
+            address: change.address,
+            public_keys,
+            threshold,
+            vp_code_hash: change.vp_code_hash,
+            creation_height: change.creation_height,
+            last_update_height: change.height,
+        }
+    }
+}
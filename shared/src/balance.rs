@@ -67,6 +67,10 @@ impl Amount {
         self.0.checked_add(other.0).map(Self)
     }
 
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
     }
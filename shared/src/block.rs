@@ -10,6 +10,7 @@ use namada_sdk::token::Transfer;
 use subtle_encoding::hex;
 use tendermint_rpc::endpoint::block::Response as TendermintBlockResponse;
 
+use crate::account::AccountChange;
 use crate::block_result::BlockResult;
 use crate::checksums::Checksums;
 use crate::header::BlockHeader;
@@ -400,6 +401,16 @@ impl Block {
                         vec![]
                     }
                 }
+                TransactionKind::UpdateAccount(data) => {
+                    if let Some(data) = data {
+                        vec![TransactionTarget::sent(
+                            tx.tx_id,
+                            data.addr.to_string(),
+                        )]
+                    } else {
+                        vec![]
+                    }
+                }
                 TransactionKind::IbcMsg(_)
                 | TransactionKind::InitAccount(_)
                 | TransactionKind::Unknown(_) => vec![],
@@ -608,7 +619,9 @@ impl Block {
             .collect()
     }
 
-    pub fn pos_rewards(&self) -> HashSet<(Id, Id)> {
+    /// Returns the (source, validator, inner tx id) of every successful
+    /// rewards claim in the block
+    pub fn pos_rewards(&self) -> HashSet<(Id, Id, Id)> {
         self.transactions
             .iter()
             .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
@@ -630,7 +643,11 @@ impl Block {
                         .to_owned()
                         .unwrap_or_else(|| validator.clone());
 
-                    Some((Id::from(source), Id::from(validator)))
+                    Some((
+                        Id::from(source),
+                        Id::from(validator),
+                        tx.tx_id.clone(),
+                    ))
                 }
                 _ => None,
             })
@@ -929,6 +946,7 @@ impl Block {
             TransactionKind::Redelegation(_)
             | TransactionKind::ChangeConsensusKey(_)
             | TransactionKind::InitAccount(_)
+            | TransactionKind::UpdateAccount(_)
             | TransactionKind::CommissionChange(_)
             | TransactionKind::RevealPk(_)
             | TransactionKind::DeactivateValidator(_)
@@ -1227,6 +1245,56 @@ impl Block {
             .collect()
     }
 
+    pub fn account_changes(&self) -> Vec<AccountChange> {
+        let height = self.header.height;
+
+        self.transactions
+            .iter()
+            .fold(vec![], |mut acc, (wrapper_tx, inner_txs)| {
+                // Extract successful inner txs
+                for inner_tx in inner_txs {
+                    if inner_tx.was_successful(wrapper_tx) {
+                        acc.push(inner_tx)
+                    }
+                }
+
+                acc
+            })
+            .iter()
+            .flat_map(|tx| match &tx.kind {
+                TransactionKind::InitAccount(Some(data)) => tx
+                    .initialized_accounts
+                    .iter()
+                    .map(|address| AccountChange {
+                        address: address.clone(),
+                        vp_code_hash: Some(Id::from(data.vp_code_hash)),
+                        creation_height: Some(height),
+                        height,
+                    })
+                    .collect(),
+                TransactionKind::UpdateAccount(Some(data)) => {
+                    vec![AccountChange {
+                        address: Id::from(data.addr.clone()),
+                        vp_code_hash: data.vp_code_hash.map(Id::from),
+                        creation_height: None,
+                        height,
+                    }]
+                }
+                // The account becoming a validator is usually initialized in
+                // the same batch, its keys are refreshed all the same
+                TransactionKind::BecomeValidator(Some(data)) => {
+                    vec![AccountChange {
+                        address: Id::from(data.address.clone()),
+                        vp_code_hash: None,
+                        creation_height: None,
+                        height,
+                    }]
+                }
+                _ => vec![],
+            })
+            .collect()
+    }
+
     pub fn revealed_pks(&self) -> Vec<(PublicKey, Id)> {
         self.transactions
             .iter()
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -15,8 +15,11 @@ use namada_tx::event::MaspTxRef;
 use tendermint_rpc::endpoint::block_results::Response as TendermintBlockResultResponse;
 
 use crate::balance::Amount;
+use crate::ibc::{
+    IbcChannel, IbcChannelState, IbcClient, IbcConnection, IbcConnectionState,
+};
 use crate::id::Id;
-use crate::transaction::{IbcTokenAction, TransactionExitStatus};
+use crate::transaction::{IbcAckStatus, IbcTokenAction, TransactionExitStatus};
 
 #[derive(Debug, Clone)]
 pub enum IbcCorePacketKind {
@@ -24,15 +27,20 @@ pub enum IbcCorePacketKind {
     Recv,
     Ack,
     Timeout,
+    WriteAck,
 }
 
 #[derive(Debug, Clone)]
 pub enum EventKind {
     Applied,
     IbcCore(IbcCorePacketKind),
+    IbcChannel(IbcChannelState),
+    IbcConnection(IbcConnectionState),
+    IbcClient { created: bool },
     FungibleTokenPacket,
     MaspFeePayment,
     MaspTransfer,
+    Slash,
     Unknown,
 }
 
@@ -42,9 +50,34 @@ impl From<&String> for EventKind {
             "tx/applied" => Self::Applied,
             "send_packet" => Self::IbcCore(IbcCorePacketKind::Send),
             "recv_packet" => Self::IbcCore(IbcCorePacketKind::Recv),
+            "acknowledge_packet" => Self::IbcCore(IbcCorePacketKind::Ack),
+            "timeout_packet" => Self::IbcCore(IbcCorePacketKind::Timeout),
+            "write_acknowledgement" => {
+                Self::IbcCore(IbcCorePacketKind::WriteAck)
+            }
+            "channel_open_init" => Self::IbcChannel(IbcChannelState::Init),
+            "channel_open_try" => Self::IbcChannel(IbcChannelState::TryOpen),
+            "channel_open_ack" | "channel_open_confirm" => {
+                Self::IbcChannel(IbcChannelState::Open)
+            }
+            "channel_close_init" | "channel_close_confirm" => {
+                Self::IbcChannel(IbcChannelState::Closed)
+            }
+            "connection_open_init" => {
+                Self::IbcConnection(IbcConnectionState::Init)
+            }
+            "connection_open_try" => {
+                Self::IbcConnection(IbcConnectionState::TryOpen)
+            }
+            "connection_open_ack" | "connection_open_confirm" => {
+                Self::IbcConnection(IbcConnectionState::Open)
+            }
+            "create_client" => Self::IbcClient { created: true },
+            "update_client" => Self::IbcClient { created: false },
             "fungible_token_packet" => Self::FungibleTokenPacket,
             "masp/fee-payment" => Self::MaspFeePayment,
             "masp/transfer" => Self::MaspTransfer,
+            "proof-of-stake/slash" => Self::Slash,
             _ => Self::Unknown,
         }
     }
@@ -84,6 +117,7 @@ impl From<&str> for TxEventStatusCode {
 pub struct BatchResults {
     pub batch_errors: BTreeMap<Id, BTreeMap<Id, String>>,
     pub batch_results: BTreeMap<Id, bool>,
+    pub initialized_accounts: BTreeMap<Id, Vec<Id>>,
 }
 
 impl From<TxResult<String>> for BatchResults {
@@ -122,6 +156,22 @@ impl From<TxResult<String>> for BatchResults {
                     acc
                 },
             ),
+            initialized_accounts: value
+                .iter()
+                .filter_map(|(tx_hash, result)| {
+                    let accounts = result
+                        .as_ref()
+                        .ok()?
+                        .initialized_accounts
+                        .iter()
+                        .cloned()
+                        .map(Id::from)
+                        .collect::<Vec<_>>();
+
+                    (!accounts.is_empty())
+                        .then(|| (Id::from(*tx_hash), accounts))
+                })
+                .collect(),
         }
     }
 }
@@ -199,6 +249,28 @@ pub struct IbcPacket {
 }
 
 impl IbcPacket {
+    /// Read the packet from the attributes of a core IBC packet event.
+    /// Only `send_packet`, `recv_packet` and `write_acknowledgement` carry
+    /// the packet data.
+    fn from_attributes(attributes: &BTreeMap<String, String>) -> Option<Self> {
+        Some(Self {
+            source_port: attributes.get("packet_src_port")?.to_owned(),
+            dest_port: attributes.get("packet_dst_port")?.to_owned(),
+            source_channel: attributes.get("packet_src_channel")?.to_owned(),
+            dest_channel: attributes.get("packet_dst_channel")?.to_owned(),
+            sequence: attributes.get("packet_sequence")?.to_owned(),
+            timeout_timestamp: attributes
+                .get("packet_timeout_timestamp")
+                .and_then(|timestamp| timestamp.parse::<u64>().ok())
+                .unwrap_or_default(),
+            timeout_height: attributes
+                .get("packet_timeout_height")
+                .cloned()
+                .unwrap_or_default(),
+            data: attributes.get("packet_data").cloned().unwrap_or_default(),
+        })
+    }
+
     pub fn as_fungible_token_packet(&self) -> Option<FungibleTokenPacket> {
         let packet_data: Ics20PacketData =
             serde_json::from_str(&self.data).ok()?;
@@ -240,6 +312,15 @@ pub enum TxAttributesType {
     TxApplied(TxApplied),
     SendPacket(IbcPacket),
     RecvPacket(IbcPacket),
+    AckPacket(IbcPacket),
+    TimeoutPacket(IbcPacket),
+    WriteAckPacket {
+        packet: IbcPacket,
+        status: IbcAckStatus,
+    },
+    IbcChannel(IbcChannel),
+    IbcConnection(IbcConnection),
+    IbcClient(IbcClient),
     FungibleTokenPacket {
         is_ack: bool,
         success: bool,
@@ -247,6 +328,9 @@ pub enum TxAttributesType {
     },
     MaspFeePayment(MaspTxData),
     MaspTransfer(MaspTxData),
+    Slash {
+        validator: Id,
+    },
 }
 
 impl TxAttributesType {
@@ -289,50 +373,61 @@ impl TxAttributesType {
                     },
                 })
             }
-            EventKind::IbcCore(
-                kind @ (IbcCorePacketKind::Ack | IbcCorePacketKind::Timeout),
-            ) => {
-                tracing::warn!(?kind, "Received unhandled IBC packet kind");
-                None
+            EventKind::IbcCore(kind) => {
+                let packet = IbcPacket::from_attributes(attributes)?;
+
+                Some(match kind {
+                    IbcCorePacketKind::Send => Self::SendPacket(packet),
+                    IbcCorePacketKind::Recv => Self::RecvPacket(packet),
+                    IbcCorePacketKind::Ack => Self::AckPacket(packet),
+                    IbcCorePacketKind::Timeout => Self::TimeoutPacket(packet),
+                    IbcCorePacketKind::WriteAck => Self::WriteAckPacket {
+                        status: attributes
+                            .get("packet_ack")
+                            .map(|ack| IbcAckStatus::from_ack_json(ack))
+                            .unwrap_or(IbcAckStatus::Unknown),
+                        packet,
+                    },
+                })
+            }
+            EventKind::IbcChannel(state) => {
+                Some(Self::IbcChannel(IbcChannel {
+                    port_id: attributes.get("port_id")?.to_owned(),
+                    channel_id: attributes.get("channel_id")?.to_owned(),
+                    counterparty_port_id: attributes
+                        .get("counterparty_port_id")?
+                        .to_owned(),
+                    counterparty_channel_id: attributes
+                        .get("counterparty_channel_id")
+                        .filter(|id| !id.is_empty())
+                        .cloned(),
+                    connection_id: attributes.get("connection_id")?.to_owned(),
+                    state: *state,
+                }))
+            }
+            EventKind::IbcConnection(state) => {
+                Some(Self::IbcConnection(IbcConnection {
+                    connection_id: attributes.get("connection_id")?.to_owned(),
+                    client_id: attributes.get("client_id")?.to_owned(),
+                    counterparty_connection_id: attributes
+                        .get("counterparty_connection_id")
+                        .filter(|id| !id.is_empty())
+                        .cloned(),
+                    counterparty_client_id: attributes
+                        .get("counterparty_client_id")?
+                        .to_owned(),
+                    state: *state,
+                }))
             }
-            EventKind::IbcCore(
-                kind @ (IbcCorePacketKind::Send | IbcCorePacketKind::Recv),
-            ) => {
-                let source_port =
-                    attributes.get("packet_src_port").unwrap().to_owned();
-                let dest_port =
-                    attributes.get("packet_dst_port").unwrap().to_owned();
-                let source_channel =
-                    attributes.get("packet_src_channel").unwrap().to_owned();
-                let dest_channel =
-                    attributes.get("packet_dst_channel").unwrap().to_owned();
-                let sequence =
-                    attributes.get("packet_sequence").unwrap().to_owned();
-                let timeout_timestamp = attributes
-                    .get("packet_timeout_timestamp")
-                    .unwrap_or(&"0".to_string())
-                    .parse::<u64>()
-                    .unwrap_or_default()
-                    .to_owned();
-                let timeout_height =
-                    attributes.get("packet_timeout_height").unwrap().to_owned();
-                let data = attributes.get("packet_data").unwrap().to_owned();
-
-                let constructor = match kind {
-                    IbcCorePacketKind::Send => Self::SendPacket,
-                    IbcCorePacketKind::Recv => Self::RecvPacket,
-                    _ => unreachable!(),
-                };
-
-                Some(constructor(IbcPacket {
-                    source_port,
-                    dest_port,
-                    source_channel,
-                    dest_channel,
-                    timeout_timestamp,
-                    timeout_height,
-                    sequence,
-                    data,
+            EventKind::IbcClient { created } => {
+                Some(Self::IbcClient(IbcClient {
+                    client_id: attributes.get("client_id")?.to_owned(),
+                    client_type: attributes.get("client_type")?.to_owned(),
+                    counterparty_chain_id: None,
+                    consensus_height: attributes
+                        .get("consensus_height")?
+                        .to_owned(),
+                    created: *created,
                 }))
             }
             EventKind::Applied => Some(Self::TxApplied(TxApplied {
@@ -384,6 +479,11 @@ impl TxAttributesType {
 
                 Some(Self::MaspTransfer(MaspTxData { indexed_tx, data }))
             }
+            EventKind::Slash => Some(Self::Slash {
+                validator: Id::Account(
+                    attributes.get("slashed-validator")?.to_owned(),
+                ),
+            }),
         }
     }
 
@@ -499,6 +599,50 @@ impl From<&TendermintBlockResultResponse> for BlockResult {
 }
 
 impl BlockResult {
+    /// Validators whose slashes were processed in this block
+    pub fn slashed_validators(&self) -> HashSet<Id> {
+        self.begin_events
+            .iter()
+            .chain(&self.end_events)
+            .filter_map(|event| match &event.attributes {
+                Some(TxAttributesType::Slash { validator }) => {
+                    Some(validator.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Latest handshake step of every channel touched in this block
+    pub fn ibc_channels(&self) -> Vec<IbcChannel> {
+        self.end_events
+            .iter()
+            .filter_map(|event| match &event.attributes {
+                Some(TxAttributesType::IbcChannel(channel)) => {
+                    Some((channel.channel_id.clone(), channel.clone()))
+                }
+                _ => None,
+            })
+            .collect::<BTreeMap<_, _>>()
+            .into_values()
+            .collect()
+    }
+
+    /// Latest handshake step of every connection touched in this block
+    pub fn ibc_connections(&self) -> Vec<IbcConnection> {
+        self.end_events
+            .iter()
+            .filter_map(|event| match &event.attributes {
+                Some(TxAttributesType::IbcConnection(connection)) => {
+                    Some((connection.connection_id.clone(), connection.clone()))
+                }
+                _ => None,
+            })
+            .collect::<BTreeMap<_, _>>()
+            .into_values()
+            .collect()
+    }
+
     pub fn is_wrapper_tx_applied(&self, tx_hash: &Id) -> TransactionExitStatus {
         let exit_status = self
             .end_events
@@ -561,6 +705,24 @@ impl BlockResult {
         exit_status.unwrap_or(TransactionExitStatus::Rejected)
     }
 
+    pub fn initialized_accounts(
+        &self,
+        wrapper_hash: &Id,
+        inner_hash: &Id,
+    ) -> Vec<Id> {
+        self.end_events
+            .iter()
+            .find_map(|event| match &event.attributes {
+                Some(TxAttributesType::TxApplied(data))
+                    if data.hash.eq(wrapper_hash) =>
+                {
+                    data.batch.initialized_accounts.get(inner_hash).cloned()
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
     pub fn masp_ref(&self, indexed_tx: &IndexedTx) -> Option<(MaspRef, bool)> {
         self.end_events
             .iter()
@@ -638,7 +800,17 @@ mod tests {
             })
             .collect();
 
-        assert_eq!(events.len(), 1);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events.remove(0),
+            Event {
+                kind: EventKind::IbcCore(IbcCorePacketKind::Ack),
+                inner_tx_hash: None,
+                attributes: Some(TxAttributesType::AckPacket(packet)),
+            }
+            if packet.id() == "transfer/channel-0/transfer/channel-85077/56446"
+                && packet.data.is_empty()
+        ));
         assert!(matches!(
             events.remove(0),
             Event {
@@ -667,6 +839,97 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn slash_events() {
+        let validator = "tnam1q8lhvxys53dlc8wzlg7dyqf9avd0vff6wvav4amt";
+        let kind = EventKind::from(&"proof-of-stake/slash".to_owned());
+        let attributes = BTreeMap::from([
+            ("slashed-validator".to_owned(), validator.to_owned()),
+            ("slashed-amount".to_owned(), "1000000".to_owned()),
+        ]);
+
+        let block_result = BlockResult {
+            height: 1,
+            begin_events: vec![],
+            end_events: vec![Event {
+                attributes: TxAttributesType::deserialize(&kind, &attributes),
+                kind,
+                inner_tx_hash: None,
+            }],
+        };
+
+        assert_eq!(
+            block_result.slashed_validators(),
+            HashSet::from([Id::Account(validator.to_owned())])
+        );
+    }
+
+    #[test]
+    fn ibc_channel_and_connection_events() {
+        let event = |kind: &str, attributes: &[(&str, &str)]| {
+            let kind = EventKind::from(&kind.to_owned());
+            let attributes = attributes
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect();
+            Event {
+                attributes: TxAttributesType::deserialize(&kind, &attributes),
+                kind,
+                inner_tx_hash: None,
+            }
+        };
+
+        let block_result = BlockResult {
+            height: 1,
+            begin_events: vec![],
+            end_events: vec![
+                event(
+                    "connection_open_init",
+                    &[
+                        ("connection_id", "connection-3"),
+                        ("client_id", "07-tendermint-5"),
+                        ("counterparty_connection_id", ""),
+                        ("counterparty_client_id", "07-tendermint-4512"),
+                    ],
+                ),
+                event(
+                    "channel_open_init",
+                    &[
+                        ("port_id", "transfer"),
+                        ("channel_id", "channel-7"),
+                        ("counterparty_port_id", "transfer"),
+                        ("counterparty_channel_id", ""),
+                        ("connection_id", "connection-3"),
+                        ("version", "ics20-1"),
+                    ],
+                ),
+                event(
+                    "channel_open_ack",
+                    &[
+                        ("port_id", "transfer"),
+                        ("channel_id", "channel-7"),
+                        ("counterparty_port_id", "transfer"),
+                        ("counterparty_channel_id", "channel-1024"),
+                        ("connection_id", "connection-3"),
+                    ],
+                ),
+            ],
+        };
+
+        let connections = block_result.ibc_connections();
+        assert_eq!(connections.len(), 1);
+        assert_eq!(connections[0].state, IbcConnectionState::Init);
+        assert_eq!(connections[0].counterparty_connection_id, None);
+
+        let channels = block_result.ibc_channels();
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].state, IbcChannelState::Open);
+        assert_eq!(
+            channels[0].counterparty_channel_id.as_deref(),
+            Some("channel-1024")
+        );
+    }
+
     fn example_events() -> Vec<TestEvent> {
         vec![
             TestEvent {
@@ -45,19 +45,58 @@ where
                 f(index).await?;
                 Ok(())
             },
-            |e: &MainError| {
-                !must_exit.load(atomic::Ordering::Relaxed)
-                    && (e.eq(&MainError::RpcError)
-                        || e.eq(&MainError::Database)
-                        || e.eq(&MainError::NoAction))
-            },
+            |e: &MainError| should_retry(&must_exit, e),
+        )
+        .await;
+    }
+
+    Ok(())
+}
+
+/// Same as [`crawl`], but `f` is given the first index of a batch and the
+/// maximum number of indexes it may process at once, and returns the last
+/// index it processed.
+pub async fn crawl_batch<F, Fut>(
+    f: F,
+    first_index: u32,
+    batch_size: u32,
+    interval: Option<u64>,
+) -> Result<(), MainError>
+where
+    F: Fn(u32, u32) -> Fut,
+    Fut: Future<Output = Result<u32, MainError>>,
+{
+    let interval = interval.unwrap_or(5000);
+    let retry_strategy = FixedInterval::from_millis(interval).map(jitter);
+    let must_exit = must_exit_handle();
+    let mut index = first_index;
+
+    while !must_exit.load(atomic::Ordering::Relaxed) {
+        let result = RetryIf::spawn(
+            retry_strategy.clone(),
+            || f(index, batch_size),
+            |e: &MainError| should_retry(&must_exit, e),
         )
         .await;
+
+        index = match result {
+            Ok(last_index) => last_index + 1,
+            // Like crawl, skip the index if it failed with an error that can't
+            // be retried
+            Err(_) => index + 1,
+        };
     }
 
     Ok(())
 }
 
+fn should_retry(must_exit: &AtomicBool, error: &MainError) -> bool {
+    !must_exit.load(atomic::Ordering::Relaxed)
+        && (error.eq(&MainError::RpcError)
+            || error.eq(&MainError::Database)
+            || error.eq(&MainError::NoAction))
+}
+
 fn must_exit_handle() -> Arc<AtomicBool> {
     let handle = Arc::new(AtomicBool::new(false));
     let task_handle = Arc::clone(&handle);
@@ -8,6 +8,7 @@ pub enum CrawlerName {
     Rewards,
     Transactions,
     Cometbft,
+    Webhooks,
 }
 
 #[derive(Clone, Debug, PartialEq)]
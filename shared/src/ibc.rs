@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, Zero};
+use namada_ibc::apps::transfer::types::PrefixedDenom;
+
+use crate::block_result::{FungibleTokenPacket, IbcPacket};
+use crate::id::Id;
+use crate::transaction::IbcAckStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IbcChannelState {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IbcConnectionState {
+    Init,
+    TryOpen,
+    Open,
+}
+
+/// A channel handshake step, as seen in a `channel_open_*` or
+/// `channel_close_*` event
+#[derive(Debug, Clone)]
+pub struct IbcChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub counterparty_port_id: String,
+    /// Unknown until the counterparty has answered the handshake
+    pub counterparty_channel_id: Option<String>,
+    pub connection_id: String,
+    pub state: IbcChannelState,
+}
+
+/// A connection handshake step, as seen in a `connection_open_*` event
+#[derive(Debug, Clone)]
+pub struct IbcConnection {
+    pub connection_id: String,
+    pub client_id: String,
+    /// Unknown until the counterparty has answered the handshake
+    pub counterparty_connection_id: Option<String>,
+    pub counterparty_client_id: String,
+    pub state: IbcConnectionState,
+}
+
+/// A light client creation or update, as seen in a `create_client` or
+/// `update_client` event
+#[derive(Debug, Clone)]
+pub struct IbcClient {
+    pub client_id: String,
+    pub client_type: String,
+    /// Chain id of the tracked chain. Events do not carry it, so it is
+    /// recovered from the client state or header of the IBC message.
+    pub counterparty_chain_id: Option<String>,
+    /// Latest counterparty height the client has a consensus state for
+    pub consensus_height: String,
+    pub created: bool,
+}
+
+impl IbcClient {
+    /// Fold a later event for the same client into this one
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            counterparty_chain_id: later
+                .counterparty_chain_id
+                .or(self.counterparty_chain_id),
+            created: self.created || later.created,
+            ..later
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IbcPacketDirection {
+    Sent,
+    Received,
+}
+
+/// A packet sent or received by this chain
+#[derive(Debug, Clone)]
+pub struct IbcPacketRecord {
+    pub direction: IbcPacketDirection,
+    pub packet: IbcPacket,
+    /// Payload of the packet, when it is an ICS-20 token transfer
+    pub transfer: Option<FungibleTokenPacket>,
+    pub tx_id: Option<Id>,
+    /// Acknowledgement written by this chain, for received packets
+    pub status: IbcAckStatus,
+}
+
+/// Acknowledgement or timeout of a packet sent by this chain
+#[derive(Debug, Clone)]
+pub struct IbcPacketOutcome {
+    pub id: String,
+    pub tx_id: Option<Id>,
+    pub status: IbcAckStatus,
+}
+
+impl IbcPacketOutcome {
+    /// Failed and timed out transfers are refunded to the sender as part of
+    /// the same transaction
+    pub fn is_refunded(&self) -> bool {
+        matches!(self.status, IbcAckStatus::Fail | IbcAckStatus::Timeout)
+    }
+}
+
+/// Split an ICS-20 denom, e.g. `transfer/channel-1/uatom`, into its trace
+/// path and base denom. The path is empty for denoms native to this chain.
+pub fn split_denom_trace(denom: &str) -> (String, String) {
+    match PrefixedDenom::from_str(denom) {
+        Ok(denom) => {
+            (denom.trace_path.to_string(), denom.base_denom.to_string())
+        }
+        Err(_) => (String::new(), denom.to_owned()),
+    }
+}
+
+/// Flows of a token during an epoch, against the throughput limit of that
+/// epoch
+#[derive(Debug, Clone)]
+pub struct IbcRateLimitUsage {
+    pub address: String,
+    pub epoch: u32,
+    pub deposit: BigDecimal,
+    pub withdraw: BigDecimal,
+    pub throughput_limit: BigDecimal,
+}
+
+impl IbcRateLimitUsage {
+    /// Net amount moved in either direction, which is what the rate limit
+    /// applies to
+    pub fn throughput(&self) -> BigDecimal {
+        (&self.withdraw - &self.deposit).abs()
+    }
+
+    /// Percent of the throughput limit consumed, `None` when the token has
+    /// no limit
+    pub fn utilization(&self) -> Option<BigDecimal> {
+        if self.throughput_limit.is_zero() {
+            return None;
+        }
+
+        Some(self.throughput() * BigDecimal::from(100) / &self.throughput_limit)
+    }
+
+    /// Whether at least `threshold` percent of the limit is consumed
+    pub fn reached(&self, threshold: &BigDecimal) -> bool {
+        self.utilization()
+            .is_some_and(|utilization| &utilization >= threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn denom_traces_are_split() {
+        assert_eq!(
+            split_denom_trace("transfer/channel-1/uatom"),
+            ("transfer/channel-1".to_owned(), "uatom".to_owned())
+        );
+        assert_eq!(
+            split_denom_trace(
+                "transfer/channel-1/transfer/channel-75/factory/stars1x/dust"
+            ),
+            (
+                "transfer/channel-1/transfer/channel-75".to_owned(),
+                "factory/stars1x/dust".to_owned()
+            )
+        );
+        assert_eq!(
+            split_denom_trace("tnam1q8lhvxys53dlc8wzlg7dyqf9avd0vff6wvav4amt"),
+            (
+                String::new(),
+                "tnam1q8lhvxys53dlc8wzlg7dyqf9avd0vff6wvav4amt".to_owned()
+            )
+        );
+    }
+
+    fn usage(deposit: u64, withdraw: u64, limit: u64) -> IbcRateLimitUsage {
+        IbcRateLimitUsage {
+            address: "tnam1q8lhvxys53dlc8wzlg7dyqf9avd0vff6wvav4amt".to_owned(),
+            epoch: 7,
+            deposit: BigDecimal::from(deposit),
+            withdraw: BigDecimal::from(withdraw),
+            throughput_limit: BigDecimal::from(limit),
+        }
+    }
+
+    #[test]
+    fn rate_limit_utilization() {
+        assert_eq!(
+            usage(100, 300, 400).utilization(),
+            Some(BigDecimal::from(50))
+        );
+        assert_eq!(
+            usage(300, 100, 400).utilization(),
+            Some(BigDecimal::from(50))
+        );
+        assert_eq!(usage(100, 300, 0).utilization(), None);
+    }
+
+    #[test]
+    fn rate_limit_threshold() {
+        let threshold = BigDecimal::from(80);
+
+        assert!(usage(0, 350, 400).reached(&threshold));
+        assert!(usage(0, 320, 400).reached(&threshold));
+        assert!(!usage(0, 300, 400).reached(&threshold));
+        assert!(!usage(500, 100, 0).reached(&threshold));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod account;
 pub mod balance;
 pub mod block;
 pub mod block_result;
@@ -11,6 +12,7 @@ pub mod futures;
 pub mod gas;
 pub mod genesis;
 pub mod header;
+pub mod ibc;
 pub mod id;
 pub mod log_config;
 pub mod masp;
@@ -24,6 +26,8 @@ pub mod ser;
 pub mod token;
 pub mod transaction;
 pub mod tuple_len;
+pub mod uptime;
 pub mod utils;
 pub mod validator;
 pub mod vote;
+pub mod webhook;
@@ -1,4 +1,7 @@
 use fake::Fake;
+use namada_proof_of_stake::types::{
+    Slash as NamadaSlash, SlashType as NamadaSlashType,
+};
 
 use crate::balance::Amount;
 use crate::block::Epoch;
@@ -85,3 +88,52 @@ impl Redelegation {
 }
 
 pub type Redelegations = Vec<Redelegation>;
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum SlashType {
+    DuplicateVote,
+    LightClientAttack,
+}
+
+impl From<NamadaSlashType> for SlashType {
+    fn from(value: NamadaSlashType) -> Self {
+        match value {
+            NamadaSlashType::DuplicateVote => Self::DuplicateVote,
+            NamadaSlashType::LightClientAttack => Self::LightClientAttack,
+        }
+    }
+}
+
+/// A processed slash of a validator. Slashes are processed
+/// `slash_processing_epoch_offset` epochs after the infraction.
+#[derive(Debug, Clone)]
+pub struct Slash {
+    pub validator: Id,
+    pub infraction_epoch: Epoch,
+    pub processing_epoch: Epoch,
+    pub block_height: u64,
+    pub rate: String,
+    pub kind: SlashType,
+}
+
+impl Slash {
+    pub fn from(
+        validator: Id,
+        slash: NamadaSlash,
+        slash_processing_epoch_offset: u64,
+    ) -> Self {
+        let infraction_epoch = slash.epoch.0 as Epoch;
+
+        Self {
+            validator,
+            infraction_epoch,
+            processing_epoch: infraction_epoch
+                + slash_processing_epoch_offset as Epoch,
+            block_height: slash.block_height,
+            rate: slash.rate.to_string(),
+            kind: slash.r#type.into(),
+        }
+    }
+}
+
+pub type Slashes = Vec<Slash>;
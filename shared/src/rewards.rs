@@ -24,3 +24,92 @@ impl Reward {
         }
     }
 }
+
+/// Rewards earned by a delegation during an epoch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardAccrual {
+    pub delegation_pair: DelegationPair,
+    pub amount: Amount,
+    pub epoch: i32,
+}
+
+impl Reward {
+    /// Unclaimed rewards only grow until they are claimed, so the rewards
+    /// earned since the previous epoch are the difference with its unclaimed
+    /// amount. When they shrank, or were marked claimed, they were claimed in
+    /// between and everything left was earned since. Returns none when
+    /// nothing was earned.
+    pub fn accrual(
+        &self,
+        previous_unclaimed: Option<&Amount>,
+    ) -> Option<RewardAccrual> {
+        let amount = previous_unclaimed
+            .and_then(|previous| self.amount.checked_sub(previous))
+            .unwrap_or_else(|| self.amount.clone());
+
+        (!amount.is_zero()).then(|| RewardAccrual {
+            delegation_pair: self.delegation_pair.clone(),
+            amount,
+            epoch: self.epoch,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::token::Amount as NamadaAmount;
+
+    use super::*;
+
+    fn amount(value: u64) -> Amount {
+        Amount::from(NamadaAmount::from_u64(value))
+    }
+
+    #[test]
+    fn accrual_is_the_growth_of_unclaimed_rewards() {
+        let reward = Reward {
+            amount: amount(150),
+            epoch: 10,
+            ..Reward::fake(1)
+        };
+
+        let accrual = reward.accrual(Some(&amount(100))).unwrap();
+
+        assert_eq!(accrual.amount, amount(50));
+        assert_eq!(accrual.epoch, 10);
+        assert_eq!(accrual.delegation_pair, reward.delegation_pair);
+    }
+
+    #[test]
+    fn accrual_after_a_claim_is_the_whole_amount() {
+        let reward = Reward {
+            amount: amount(30),
+            ..Reward::fake(1)
+        };
+
+        assert_eq!(reward.accrual(None).unwrap().amount, amount(30));
+    }
+
+    #[test]
+    fn accrual_after_an_unrecorded_claim_is_the_whole_amount() {
+        let reward = Reward {
+            amount: amount(30),
+            ..Reward::fake(1)
+        };
+
+        assert_eq!(
+            reward.accrual(Some(&amount(40))).unwrap().amount,
+            amount(30)
+        );
+    }
+
+    #[test]
+    fn no_accrual_without_growth() {
+        let reward = Reward {
+            amount: amount(30),
+            ..Reward::fake(1)
+        };
+
+        assert!(reward.accrual(Some(&amount(30))).is_none());
+    }
+}
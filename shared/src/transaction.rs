@@ -4,7 +4,7 @@ use std::fmt::Display;
 use anyhow::Context;
 use bigdecimal::BigDecimal;
 use namada_governance::{InitProposalData, VoteProposalData};
-use namada_sdk::account::InitAccount;
+use namada_sdk::account::{InitAccount, UpdateAccount};
 use namada_sdk::address::Address;
 use namada_sdk::borsh::BorshDeserialize;
 use namada_sdk::key::common::PublicKey;
@@ -89,6 +89,7 @@ pub enum TransactionKind {
     ProposalVote(Option<VoteProposalData>),
     InitProposal(Option<InitProposalData>),
     InitAccount(Option<InitAccount>),
+    UpdateAccount(Option<UpdateAccount>),
     MetadataChange(Option<MetaDataChange>),
     CommissionChange(Option<CommissionChange>),
     RevealPk(Option<RevealPkData>),
@@ -182,6 +183,15 @@ impl TransactionKind {
                 };
                 TransactionKind::InitAccount(data)
             }
+            "tx_update_account" => {
+                let data = if let Ok(data) = UpdateAccount::try_from_slice(data)
+                {
+                    Some(data)
+                } else {
+                    None
+                };
+                TransactionKind::UpdateAccount(data)
+            }
             "tx_vote_proposal" => {
                 let data =
                     if let Ok(data) = VoteProposalData::try_from_slice(data) {
@@ -342,6 +352,8 @@ pub struct InnerTransaction {
     pub extra_sections: HashMap<Id, Vec<u8>>,
     pub notes: u64,
     pub exit_code: TransactionExitStatus,
+    /// Established addresses created by the transaction
+    pub initialized_accounts: Vec<Id>,
 }
 
 impl InnerTransaction {
@@ -495,6 +507,8 @@ impl Transaction {
 
                     let inner_tx_status = block_results
                         .is_inner_tx_accepted(&wrapper_tx_id, &inner_tx_id);
+                    let initialized_accounts = block_results
+                        .initialized_accounts(&wrapper_tx_id, &inner_tx_id);
 
                     let extra_sections = transaction
                         .sections
@@ -573,6 +587,7 @@ impl Transaction {
                         extra_sections,
                         notes,
                         exit_code: inner_tx_status,
+                        initialized_accounts,
                         kind: tx_kind,
                     };
 
@@ -691,6 +706,18 @@ pub enum IbcAckStatus {
     Unknown,
 }
 
+impl IbcAckStatus {
+    /// Status of an acknowledgement in the standard `{"result": ..}` or
+    /// `{"error": ..}` JSON encoding
+    pub fn from_ack_json(ack: &str) -> Self {
+        match serde_json::from_str::<serde_json::Value>(ack) {
+            Ok(value) if value.get("result").is_some() => Self::Success,
+            Ok(value) if value.get("error").is_some() => Self::Fail,
+            _ => Self::Unknown,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IbcAck {
     pub sequence_number: String,
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use tendermint::block::{Commit, CommitSig};
+
+use crate::block::Epoch;
+use crate::id::Id;
+
+/// Blocks signed and missed by a consensus validator during an epoch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorUptime {
+    pub validator: Id,
+    pub epoch: Epoch,
+    pub signed_blocks: u32,
+    pub missed_blocks: u32,
+}
+
+/// Consensus addresses, as upper case hex, of the validators who voted for
+/// the committed block. Absent and nil votes are not signatures.
+pub fn commit_signers(commit: &Commit) -> HashSet<String> {
+    commit
+        .signatures
+        .iter()
+        .filter_map(|signature| match signature {
+            CommitSig::BlockIdFlagCommit {
+                validator_address, ..
+            } => Some(validator_address.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Count the commits signed and missed by each consensus validator of an
+/// epoch. Validators are known by every consensus address they ever had, so
+/// that a key rotation does not show up as missed blocks.
+pub fn epoch_uptime(
+    epoch: Epoch,
+    consensus_validators: &HashSet<Id>,
+    consensus_addresses: &HashMap<String, Id>,
+    commits: &[HashSet<String>],
+) -> Vec<ValidatorUptime> {
+    let signed_by = commits
+        .iter()
+        .map(|signers| {
+            signers
+                .iter()
+                .filter_map(|address| consensus_addresses.get(address))
+                .collect::<HashSet<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    consensus_validators
+        .iter()
+        .map(|validator| {
+            let signed_blocks = signed_by
+                .iter()
+                .filter(|signed| signed.contains(validator))
+                .count() as u32;
+
+            ValidatorUptime {
+                validator: validator.clone(),
+                epoch,
+                signed_blocks,
+                missed_blocks: commits.len() as u32 - signed_blocks,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(address: &str) -> Id {
+        Id::Account(address.to_string())
+    }
+
+    fn signers(addresses: &[&str]) -> HashSet<String> {
+        addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn counts_signed_and_missed_blocks() {
+        let consensus_validators = HashSet::from([id("tnam1a"), id("tnam1b")]);
+        let consensus_addresses = HashMap::from([
+            ("AA".to_string(), id("tnam1a")),
+            ("BB".to_string(), id("tnam1b")),
+            ("CC".to_string(), id("tnam1c")),
+        ]);
+        let commits = [
+            signers(&["AA", "BB"]),
+            signers(&["AA", "CC"]),
+            signers(&["AA"]),
+        ];
+
+        let mut uptime = epoch_uptime(
+            7,
+            &consensus_validators,
+            &consensus_addresses,
+            &commits,
+        );
+        uptime.sort_by_key(|uptime| uptime.validator.to_string());
+
+        assert_eq!(
+            uptime,
+            vec![
+                ValidatorUptime {
+                    validator: id("tnam1a"),
+                    epoch: 7,
+                    signed_blocks: 3,
+                    missed_blocks: 0,
+                },
+                ValidatorUptime {
+                    validator: id("tnam1b"),
+                    epoch: 7,
+                    signed_blocks: 1,
+                    missed_blocks: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rotated_keys_count_as_signed() {
+        let consensus_validators = HashSet::from([id("tnam1a")]);
+        let consensus_addresses = HashMap::from([
+            ("AA".to_string(), id("tnam1a")),
+            ("A2".to_string(), id("tnam1a")),
+        ]);
+        let commits = [signers(&["AA"]), signers(&["A2"]), signers(&[])];
+
+        let uptime = epoch_uptime(
+            1,
+            &consensus_validators,
+            &consensus_addresses,
+            &commits,
+        );
+
+        assert_eq!(uptime[0].signed_blocks, 2);
+        assert_eq!(uptime[0].missed_blocks, 1);
+    }
+}
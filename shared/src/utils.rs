@@ -530,6 +530,19 @@ pub fn is_valid_bech32_address(address: &str, prefix: &str) -> bool {
     }
 }
 
+/// Decodes a hex encoded memo to text, when it is valid UTF-8 that postgres
+/// can store
+pub fn memo_to_text(memo: &str) -> Option<String> {
+    let bytes = subtle_encoding::hex::decode(memo.to_lowercase()).ok()?;
+    let text = String::from_utf8(bytes).ok()?;
+
+    if text.contains('\0') {
+        None
+    } else {
+        Some(text)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -544,6 +557,24 @@ mod tests {
         assert!(res, "Address {address} should be valid");
     }
 
+    #[test]
+    fn memo_to_text() {
+        let memo =
+            String::from_utf8(subtle_encoding::hex::encode("deposit-42"))
+                .unwrap();
+        assert_eq!(super::memo_to_text(&memo), Some("deposit-42".to_string()));
+        assert_eq!(
+            super::memo_to_text(&memo.to_uppercase()),
+            Some("deposit-42".to_string())
+        );
+
+        // invalid utf8
+        assert_eq!(super::memo_to_text("ff00"), None);
+        // nul bytes can't be stored in a postgres varchar
+        assert_eq!(super::memo_to_text("610062"), None);
+        assert_eq!(super::memo_to_text("not hex"), None);
+    }
+
     fn cmp_print(x: &str, y: &str) -> bool {
         if x == y {
             true
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WebhookTopic {
+    AddressActivity,
+    TxKind,
+    ProposalStatus,
+    ValidatorState,
+    IbcRateLimit,
+}
+
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: i32,
+    pub url: String,
+    pub secret: String,
+    pub topic: WebhookTopic,
+    /// Address, transaction kind, proposal id, validator address or token
+    /// address the webhook is restricted to, every event of the topic when
+    /// unset
+    pub filter: Option<String>,
+}
+
+/// Something which happened on chain, delivered to every webhook of its topic
+/// whose filter matches the subject
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookEvent {
+    /// Stable across retries, so that receivers can deduplicate deliveries
+    pub id: String,
+    pub topic: WebhookTopic,
+    pub subject: String,
+    pub block_height: Option<u32>,
+    pub data: Value,
+}
+
+impl WebhookEvent {
+    pub fn matches(&self, webhook: &Webhook) -> bool {
+        webhook.topic == self.topic
+            && webhook
+                .filter
+                .as_ref()
+                .is_none_or(|filter| filter == &self.subject)
+    }
+
+    pub fn payload(&self) -> Value {
+        json!({
+            "id": self.id,
+            "topic": self.topic,
+            "blockHeight": self.block_height,
+            "data": self.data,
+        })
+    }
+}
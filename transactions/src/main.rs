@@ -186,6 +186,15 @@ async fn crawling_fn(
     let ibc_sequence_packet =
         tx_service::get_ibc_packets(&block_results, &block.transactions);
     let ibc_ack_packet = tx_service::get_ibc_ack_packet(&inner_txs);
+    let ibc_packets = tx_service::get_ibc_packet_records(
+        &block_results,
+        &ibc_sequence_packet,
+    );
+    let ibc_packet_outcomes =
+        tx_service::get_ibc_packet_outcomes(&block_results, &ibc_ack_packet);
+    let ibc_channels = block_results.ibc_channels();
+    let ibc_connections = block_results.ibc_connections();
+    let ibc_clients = tx_service::get_ibc_clients(&block_results, &inner_txs);
 
     let ibc_token_flows = {
         let mut flows_map = HashMap::new();
@@ -277,14 +286,45 @@ async fn crawling_fn(
                     ibc_ack_packet,
                 )?;
 
+                transaction_repo::insert_ibc_packets(
+                    transaction_conn,
+                    ibc_packets,
+                    block_height as u64,
+                )?;
+
+                transaction_repo::update_ibc_packet_outcomes(
+                    transaction_conn,
+                    ibc_packet_outcomes,
+                    block_height as u64,
+                )?;
+
                 transaction_repo::upsert_ibc_token_flows(
                     transaction_conn,
                     ibc_token_flows,
                 )?;
 
+                transaction_repo::upsert_ibc_clients(
+                    transaction_conn,
+                    ibc_clients,
+                    block_height as u64,
+                )?;
+
+                transaction_repo::upsert_ibc_connections(
+                    transaction_conn,
+                    ibc_connections,
+                    block_height as u64,
+                )?;
+
+                transaction_repo::upsert_ibc_channels(
+                    transaction_conn,
+                    ibc_channels,
+                    block_height as u64,
+                )?;
+
                 transaction_repo::insert_transactions_history(
                     transaction_conn,
                     transaction_sources,
+                    block_height,
                 )?;
 
                 transaction_repo::insert_gas_estimates(
@@ -294,6 +334,8 @@ async fn crawling_fn(
 
                 masp_repo::insert_masp_entries(transaction_conn, masp_entries)?;
 
+                block_repo::notify_block(transaction_conn, block_height)?;
+
                 anyhow::Ok(())
             })
     })
@@ -2,6 +2,7 @@ use anyhow::Context;
 use diesel::upsert::excluded;
 use diesel::{ExpressionMethods, PgConnection, RunQueryDsl};
 use orm::blocks::BlockInsertDb;
+use orm::notifications::IndexerNotification;
 use orm::schema::blocks;
 use shared::block::Block;
 use tendermint_rpc::endpoint::block::Response as TendermintBlockResponse;
@@ -30,3 +31,16 @@ pub fn upsert_block(
 
     anyhow::Ok(())
 }
+
+pub fn notify_block(
+    transaction_conn: &mut PgConnection,
+    block_height: u32,
+) -> anyhow::Result<()> {
+    IndexerNotification::Block {
+        height: block_height as i32,
+    }
+    .notify(transaction_conn)
+    .context("Failed to notify new block")?;
+
+    anyhow::Ok(())
+}
@@ -10,12 +10,14 @@ use diesel::{
 use orm::crawler_state::{BlockStateInsertDb, CrawlerNameDb};
 use orm::gas::GasEstimationInsertDb;
 use orm::ibc::{
-    IbcAckInsertDb, IbcAckStatusDb, IbcSequencekStatusUpdateDb,
-    IbcTokenFlowsInsertDb,
+    IbcAckInsertDb, IbcAckStatusDb, IbcChannelInsertDb, IbcClientInsertDb,
+    IbcConnectionInsertDb, IbcPacketInsertDb, IbcPacketOutcomeUpdateDb,
+    IbcSequencekStatusUpdateDb, IbcTokenFlowsInsertDb,
 };
 use orm::schema::{
-    crawler_state, gas_estimations, ibc_ack, ibc_token_flows,
-    inner_transactions, transaction_history, wrapper_transactions,
+    crawler_state, gas_estimations, ibc_ack, ibc_channels, ibc_clients,
+    ibc_connections, ibc_packets, ibc_token_flows, inner_transactions,
+    transaction_history, wrapper_transactions,
 };
 use orm::transactions::{
     InnerTransactionInsertDb, TransactionHistoryInsertDb,
@@ -23,6 +25,9 @@ use orm::transactions::{
 };
 use shared::crawler_state::{BlockCrawlerState, CrawlerName};
 use shared::gas::GasEstimation;
+use shared::ibc::{
+    IbcChannel, IbcClient, IbcConnection, IbcPacketOutcome, IbcPacketRecord,
+};
 use shared::transaction::{
     IbcAck, IbcSequence, IbcTokenFlow, InnerTransaction, TransactionTarget,
     WrapperTransaction,
@@ -47,6 +52,8 @@ pub fn insert_inner_transactions(
             // that were previously marked as "unknown".
             inner_transactions::kind.eq(excluded(inner_transactions::kind)),
             inner_transactions::data.eq(excluded(inner_transactions::data)),
+            inner_transactions::batch_index
+                .eq(excluded(inner_transactions::batch_index)),
         ))
         .execute(transaction_conn)
         .context("Failed to insert inner transactions in db")?;
@@ -143,14 +150,54 @@ pub fn update_ibc_sequence(
     anyhow::Ok(())
 }
 
+pub fn insert_ibc_packets(
+    transaction_conn: &mut PgConnection,
+    packets: Vec<IbcPacketRecord>,
+    height: u64,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_packets::table)
+        .values::<Vec<IbcPacketInsertDb>>(
+            packets
+                .into_iter()
+                .map(|packet| IbcPacketInsertDb::from_record(packet, height))
+                .collect(),
+        )
+        .on_conflict_do_nothing()
+        .execute(transaction_conn)
+        .context("Failed to insert ibc packets in db")?;
+
+    anyhow::Ok(())
+}
+
+pub fn update_ibc_packet_outcomes(
+    transaction_conn: &mut PgConnection,
+    outcomes: Vec<IbcPacketOutcome>,
+    height: u64,
+) -> anyhow::Result<()> {
+    for outcome in outcomes {
+        diesel::update(ibc_packets::table)
+            .set(IbcPacketOutcomeUpdateDb::from_outcome(&outcome, height))
+            .filter(ibc_packets::dsl::id.eq(outcome.id))
+            .execute(transaction_conn)
+            .context("Failed to update ibc packet outcome in db")?;
+    }
+    anyhow::Ok(())
+}
+
 pub fn insert_transactions_history(
     transaction_conn: &mut PgConnection,
     txs: HashSet<TransactionTarget>,
+    block_height: u32,
 ) -> anyhow::Result<()> {
     diesel::insert_into(transaction_history::table)
         .values::<&Vec<TransactionHistoryInsertDb>>(
             &txs.into_iter()
-                .map(TransactionHistoryInsertDb::from)
+                .map(|target| {
+                    TransactionHistoryInsertDb::from(
+                        target,
+                        block_height as i32,
+                    )
+                })
                 .collect::<Vec<_>>(),
         )
         .on_conflict_do_nothing()
@@ -222,3 +269,107 @@ where
 
     anyhow::Ok(())
 }
+
+pub fn upsert_ibc_channels(
+    transaction_conn: &mut PgConnection,
+    channels: Vec<IbcChannel>,
+    height: u64,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_channels::table)
+        .values::<Vec<IbcChannelInsertDb>>(
+            channels
+                .into_iter()
+                .map(|channel| {
+                    IbcChannelInsertDb::from_channel(channel, height)
+                })
+                .collect(),
+        )
+        .on_conflict(ibc_channels::channel_id)
+        .do_update()
+        .set((
+            ibc_channels::counterparty_channel_id.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::VarChar>,
+            >(
+                "COALESCE(excluded.counterparty_channel_id, \
+                 ibc_channels.counterparty_channel_id)",
+            )),
+            ibc_channels::state.eq(excluded(ibc_channels::state)),
+            ibc_channels::last_update_height
+                .eq(excluded(ibc_channels::last_update_height)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to upsert ibc channels in db")?;
+
+    anyhow::Ok(())
+}
+
+pub fn upsert_ibc_connections(
+    transaction_conn: &mut PgConnection,
+    connections: Vec<IbcConnection>,
+    height: u64,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_connections::table)
+        .values::<Vec<IbcConnectionInsertDb>>(
+            connections
+                .into_iter()
+                .map(|connection| {
+                    IbcConnectionInsertDb::from_connection(connection, height)
+                })
+                .collect(),
+        )
+        .on_conflict(ibc_connections::connection_id)
+        .do_update()
+        .set((
+            ibc_connections::counterparty_connection_id.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::VarChar>,
+            >(
+                "COALESCE(excluded.counterparty_connection_id, \
+                 ibc_connections.counterparty_connection_id)",
+            )),
+            ibc_connections::state.eq(excluded(ibc_connections::state)),
+            ibc_connections::last_update_height
+                .eq(excluded(ibc_connections::last_update_height)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to upsert ibc connections in db")?;
+
+    anyhow::Ok(())
+}
+
+pub fn upsert_ibc_clients(
+    transaction_conn: &mut PgConnection,
+    clients: Vec<IbcClient>,
+    height: u64,
+) -> anyhow::Result<()> {
+    diesel::insert_into(ibc_clients::table)
+        .values::<Vec<IbcClientInsertDb>>(
+            clients
+                .into_iter()
+                .map(|client| IbcClientInsertDb::from_client(client, height))
+                .collect(),
+        )
+        .on_conflict(ibc_clients::client_id)
+        .do_update()
+        .set((
+            ibc_clients::counterparty_chain_id.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::VarChar>,
+            >(
+                "COALESCE(excluded.counterparty_chain_id, \
+                 ibc_clients.counterparty_chain_id)",
+            )),
+            ibc_clients::creation_height.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::Integer>,
+            >(
+                "COALESCE(ibc_clients.creation_height, \
+                 excluded.creation_height)",
+            )),
+            ibc_clients::consensus_height
+                .eq(excluded(ibc_clients::consensus_height)),
+            ibc_clients::last_update_height
+                .eq(excluded(ibc_clients::last_update_height)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to upsert ibc clients in db")?;
+
+    anyhow::Ok(())
+}
@@ -1,11 +1,20 @@
+use std::collections::{BTreeMap, HashMap};
+
 use bigdecimal::BigDecimal;
 use namada_sdk::address::Address;
 use namada_sdk::hash::Hash;
+use namada_sdk::ibc::clients::tendermint::types::{
+    ClientState as TmClientState, Header as TmHeader,
+};
 use namada_sdk::ibc::core::channel::types::acknowledgement::AcknowledgementStatus;
 use namada_sdk::ibc::core::channel::types::msgs::PacketMsg;
+use namada_sdk::ibc::core::client::types::msgs::ClientMsg;
 use namada_sdk::ibc::core::handler::types::msgs::MsgEnvelope;
 use shared::block_result::{BlockResult, TxAttributesType};
 use shared::gas::GasEstimation;
+use shared::ibc::{
+    IbcClient, IbcPacketDirection, IbcPacketOutcome, IbcPacketRecord,
+};
 use shared::id::Id;
 use shared::transaction::{
     IbcAck, IbcAckStatus, IbcSequence, IbcTokenAction, InnerTransaction,
@@ -182,6 +191,165 @@ pub fn get_ibc_ack_packet(inner_txs: &[InnerTransaction]) -> Vec<IbcAck> {
     }).collect()
 }
 
+/// Packets sent or received in this block. Sent packets take the tx id
+/// resolved for their sequence, received ones the tx id of their event.
+pub fn get_ibc_packet_records(
+    block_results: &BlockResult,
+    sequences: &[IbcSequence],
+) -> Vec<IbcPacketRecord> {
+    let written_acks: HashMap<_, _> = block_results
+        .end_events
+        .iter()
+        .filter_map(|event| match &event.attributes {
+            Some(TxAttributesType::WriteAckPacket { packet, status }) => {
+                Some((packet.id(), status.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| match event.attributes.as_ref()? {
+            TxAttributesType::SendPacket(packet) => Some(IbcPacketRecord {
+                direction: IbcPacketDirection::Sent,
+                transfer: packet.as_fungible_token_packet(),
+                tx_id: sequences
+                    .iter()
+                    .find(|sequence| sequence.id() == packet.id())
+                    .map(|sequence| sequence.tx_id.clone()),
+                status: IbcAckStatus::Unknown,
+                packet: packet.clone(),
+            }),
+            TxAttributesType::RecvPacket(packet) => Some(IbcPacketRecord {
+                direction: IbcPacketDirection::Received,
+                transfer: packet.as_fungible_token_packet(),
+                tx_id: event.inner_tx_hash.clone(),
+                status: written_acks
+                    .get(&packet.id())
+                    .cloned()
+                    .unwrap_or(IbcAckStatus::Unknown),
+                packet: packet.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Acknowledgements and timeouts of packets sent by this chain. The
+/// acknowledgement events do not say whether the packet succeeded, so the
+/// status is read from the acknowledgement message of the same packet.
+pub fn get_ibc_packet_outcomes(
+    block_results: &BlockResult,
+    acks: &[IbcAck],
+) -> Vec<IbcPacketOutcome> {
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| {
+            let (packet, status) = match event.attributes.as_ref()? {
+                TxAttributesType::AckPacket(packet) => (
+                    packet,
+                    acks.iter()
+                        .find(|ack| ack.id() == packet.id())
+                        .map(|ack| ack.status.clone())
+                        .unwrap_or(IbcAckStatus::Unknown),
+                ),
+                TxAttributesType::TimeoutPacket(packet) => {
+                    (packet, IbcAckStatus::Timeout)
+                }
+                _ => return None,
+            };
+
+            Some(IbcPacketOutcome {
+                id: packet.id(),
+                tx_id: event.inner_tx_hash.clone(),
+                status,
+            })
+        })
+        .collect()
+}
+
+/// Light clients created or updated in this block. The counterparty chain id
+/// is not part of the events, so it is read from the tendermint client state
+/// or header carried by the matching IBC message, when there is one.
+pub fn get_ibc_clients(
+    block_results: &BlockResult,
+    inner_txs: &[InnerTransaction],
+) -> Vec<IbcClient> {
+    let mut created_chain_ids = HashMap::new();
+    let mut updated_chain_ids = HashMap::new();
+
+    for tx in inner_txs {
+        let TransactionKind::IbcMsg(Some(ibc_message)) = &tx.kind else {
+            continue;
+        };
+        let namada_sdk::ibc::IbcMessage::Envelope(msg_envelope) =
+            &ibc_message.0
+        else {
+            continue;
+        };
+        match msg_envelope.as_ref() {
+            MsgEnvelope::Client(ClientMsg::CreateClient(msg)) => {
+                if let Ok(client_state) =
+                    TmClientState::try_from(msg.client_state.clone())
+                {
+                    created_chain_ids.insert(
+                        tx.tx_id.clone(),
+                        client_state.chain_id.to_string(),
+                    );
+                }
+            }
+            MsgEnvelope::Client(ClientMsg::UpdateClient(msg)) => {
+                if let Ok(header) =
+                    TmHeader::try_from(msg.client_message.clone())
+                {
+                    updated_chain_ids.insert(
+                        msg.client_id.to_string(),
+                        header.signed_header.header.chain_id.to_string(),
+                    );
+                }
+            }
+            _ => (),
+        }
+    }
+
+    block_results
+        .end_events
+        .iter()
+        .filter_map(|event| {
+            let Some(TxAttributesType::IbcClient(client)) = &event.attributes
+            else {
+                return None;
+            };
+
+            let counterparty_chain_id = if client.created {
+                event
+                    .inner_tx_hash
+                    .as_ref()
+                    .and_then(|hash| created_chain_ids.get(hash))
+            } else {
+                updated_chain_ids.get(&client.client_id)
+            };
+
+            Some(IbcClient {
+                counterparty_chain_id: counterparty_chain_id.cloned(),
+                ..client.clone()
+            })
+        })
+        .fold(BTreeMap::new(), |mut acc, client| {
+            let client = match acc.remove(&client.client_id) {
+                Some(previous) => IbcClient::merge(previous, client),
+                None => client,
+            };
+            acc.insert(client.client_id.clone(), client);
+            acc
+        })
+        .into_values()
+        .collect()
+}
+
 pub fn get_gas_estimates(
     txs: &[(WrapperTransaction, Vec<InnerTransaction>)],
 ) -> Vec<GasEstimation> {
@@ -252,6 +420,7 @@ pub fn get_gas_estimates(
                 TransactionKind::ChangeConsensusKey(_)
                 | TransactionKind::IbcMsg(_)
                 | TransactionKind::InitAccount(_)
+                | TransactionKind::UpdateAccount(_)
                 | TransactionKind::InitProposal(_)
                 | TransactionKind::MetadataChange(_)
                 | TransactionKind::CommissionChange(_)
@@ -356,6 +525,7 @@ mod tests {
             memo: None,
             notes: 0,
             exit_code: TransactionExitStatus::Applied,
+            initialized_accounts: vec![],
         };
         let inner2 = InnerTransaction {
             kind: TransactionKind::IbcRecvTrasparentTransfer((
@@ -395,4 +565,56 @@ mod tests {
             vec![expected_seq(Id::Hash("deadbeef".to_string()))],
         );
     }
+
+    #[test]
+    fn test_get_ibc_packet_outcomes() {
+        let packet = IbcPacket {
+            source_port: "transfer".to_string(),
+            dest_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            dest_channel: "channel-1".to_string(),
+            sequence: "7".to_string(),
+            ..Default::default()
+        };
+        let block_result = BlockResult {
+            end_events: vec![
+                Event {
+                    kind: EventKind::IbcCore(IbcCorePacketKind::Ack),
+                    inner_tx_hash: Some(Id::Hash("deadbeef".to_string())),
+                    attributes: Some(TxAttributesType::AckPacket(
+                        packet.clone(),
+                    )),
+                },
+                Event {
+                    kind: EventKind::IbcCore(IbcCorePacketKind::Timeout),
+                    inner_tx_hash: Some(Id::Hash("cafebabe".to_string())),
+                    attributes: Some(TxAttributesType::TimeoutPacket(
+                        IbcPacket {
+                            sequence: "8".to_string(),
+                            ..packet.clone()
+                        },
+                    )),
+                },
+            ],
+            ..Default::default()
+        };
+        let acks = [IbcAck {
+            sequence_number: "7".to_string(),
+            source_port: "transfer".to_string(),
+            dest_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            dest_channel: "channel-1".to_string(),
+            status: IbcAckStatus::Fail,
+        }];
+
+        let outcomes = get_ibc_packet_outcomes(&block_result, &acks);
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].id, "transfer/channel-1/transfer/channel-0/7");
+        assert!(matches!(outcomes[0].status, IbcAckStatus::Fail));
+        assert!(outcomes[0].is_refunded());
+        assert_eq!(outcomes[1].id, "transfer/channel-1/transfer/channel-0/8");
+        assert!(matches!(outcomes[1].status, IbcAckStatus::Timeout));
+        assert_eq!(outcomes[1].tx_id, Some(Id::Hash("cafebabe".to_string())));
+    }
 }
@@ -0,0 +1,3 @@
+. ../.env
+export DATABASE_URL
+cargo run --release 
@@ -0,0 +1,385 @@
+use axum::extract::{Path, Query, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::{NaiveDateTime, Utc};
+use diesel::PgConnection;
+use orm::webhooks::{
+    WebhookDb, WebhookDeadLetterDb, WebhookInsertDb, WebhookTopicDb,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use shared::webhook::WebhookTopic;
+use thiserror::Error;
+
+use crate::app_state::AppState;
+use crate::repository::webhooks as webhook_repo;
+use crate::services::delivery::generate_secret;
+use crate::services::events::is_transaction_kind;
+use crate::services::target::is_public;
+
+const MAX_DEAD_LETTERS: i64 = 1000;
+
+#[derive(Clone)]
+pub struct ApiState {
+    pub app_state: AppState,
+    pub api_key: Option<String>,
+    pub allow_private_targets: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("Invalid webhook: {0}")]
+    InvalidWebhook(String),
+    #[error("Webhook {0} not found")]
+    WebhookNotFound(i32),
+    #[error("Unknown api key")]
+    Unauthorized,
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            ApiError::InvalidWebhook(_) => StatusCode::BAD_REQUEST,
+            ApiError::WebhookNotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ApiError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let body = json!({
+            "message": self.to_string(),
+            "code": status.as_u16(),
+        });
+
+        (status, Json(body)).into_response()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterWebhook {
+    pub url: String,
+    pub topic: WebhookTopic,
+    /// Address for address activity, transaction kind for transaction kinds,
+    /// and optionally a proposal id or a validator address for proposal and
+    /// validator changes
+    pub filter: Option<String>,
+}
+
+impl RegisterWebhook {
+    /// Returns the parsed url of the webhook
+    fn validate(&self) -> Result<reqwest::Url, ApiError> {
+        let url = reqwest::Url::parse(&self.url)
+            .map_err(|e| ApiError::InvalidWebhook(e.to_string()))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(ApiError::InvalidWebhook(
+                "url must be http or https".to_string(),
+            ));
+        }
+
+        match (self.topic, self.filter.as_deref()) {
+            (WebhookTopic::AddressActivity, None | Some("")) => {
+                Err(ApiError::InvalidWebhook(
+                    "address activity requires an address filter".to_string(),
+                ))
+            }
+            (WebhookTopic::TxKind, Some(kind)) if is_transaction_kind(kind) => {
+                Ok(())
+            }
+            (WebhookTopic::TxKind, _) => Err(ApiError::InvalidWebhook(
+                "transaction kind requires a transaction kind filter, e.g. \
+                 transparentTransfer"
+                    .to_string(),
+            )),
+            (WebhookTopic::ProposalStatus, Some(id))
+                if id.parse::<u64>().is_err() =>
+            {
+                Err(ApiError::InvalidWebhook(
+                    "proposal status filter must be a proposal id".to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }?;
+
+        Ok(url)
+    }
+}
+
+/// Reject urls resolving to the host or its private networks, which would
+/// let anyone allowed to register webhooks probe them
+async fn check_target(url: &reqwest::Url) -> Result<(), ApiError> {
+    let port = url.port_or_known_default().unwrap_or(80);
+    // IPv6 hosts are bracketed, ip literals are resolved to themselves
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+
+    let addresses = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| {
+            ApiError::InvalidWebhook(format!("can not resolve {}: {}", host, e))
+        })?
+        .map(|address| address.ip())
+        .collect::<Vec<_>>();
+
+    if addresses.is_empty() {
+        return Err(ApiError::InvalidWebhook(
+            "url must have a resolvable host".to_string(),
+        ));
+    }
+    if !addresses.into_iter().all(is_public) {
+        return Err(ApiError::InvalidWebhook(
+            "url must not target a loopback, private or link-local address"
+                .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookResponse {
+    pub id: i32,
+    pub url: String,
+    pub topic: WebhookTopic,
+    pub filter: Option<String>,
+    pub created_at: NaiveDateTime,
+    /// Only returned on registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl WebhookResponse {
+    fn from_db(webhook: WebhookDb, with_secret: bool) -> Self {
+        Self {
+            id: webhook.id,
+            url: webhook.url,
+            topic: WebhookTopic::from(webhook.topic),
+            filter: webhook.filter,
+            created_at: webhook.created_at,
+            secret: with_secret.then_some(webhook.secret),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadLetterResponse {
+    pub id: i32,
+    pub event_id: String,
+    pub payload: Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub failed_at: NaiveDateTime,
+}
+
+impl From<WebhookDeadLetterDb> for DeadLetterResponse {
+    fn from(value: WebhookDeadLetterDb) -> Self {
+        Self {
+            id: value.id,
+            event_id: value.event_id,
+            payload: value.payload,
+            attempts: value.attempts,
+            last_error: value.last_error,
+            created_at: value.created_at,
+            failed_at: value.failed_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeadLettersQuery {
+    pub limit: Option<i64>,
+}
+
+pub fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/webhooks", post(register_webhook).get(get_webhooks))
+        .route("/webhooks/{id}", get(get_webhook).delete(delete_webhook))
+        .route("/webhooks/{id}/dead-letters", get(get_dead_letters))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_api_key,
+        ))
+        .route("/health", get(|| async { StatusCode::OK }))
+        .with_state(state)
+}
+
+async fn require_api_key(
+    State(state): State<ApiState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    if let Some(api_key) = &state.api_key {
+        let provided = request
+            .headers()
+            .get("x-api-key")
+            .and_then(|value| value.to_str().ok());
+
+        if !provided.is_some_and(|provided| keys_match(provided, api_key)) {
+            return Err(ApiError::Unauthorized);
+        }
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Compares the digests of the keys, so that the time taken leaks neither
+/// the length of the key nor how much of it was guessed
+fn keys_match(provided: &str, api_key: &str) -> bool {
+    let provided = Sha256::digest(provided.as_bytes());
+    let api_key = Sha256::digest(api_key.as_bytes());
+
+    provided
+        .iter()
+        .zip(api_key.iter())
+        .fold(0, |difference, (a, b)| difference | (a ^ b))
+        == 0
+}
+
+async fn interact<T, F>(state: &ApiState, f: F) -> Result<T, ApiError>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut PgConnection) -> anyhow::Result<T> + Send + 'static,
+{
+    let conn = state
+        .app_state
+        .get_db_connection()
+        .await
+        .map_err(|e| ApiError::Database(e.to_string()))?;
+
+    conn.interact(f)
+        .await
+        .map_err(|e| ApiError::Database(e.to_string()))?
+        .map_err(|e| ApiError::Database(e.to_string()))
+}
+
+async fn register_webhook(
+    State(state): State<ApiState>,
+    Json(request): Json<RegisterWebhook>,
+) -> Result<(StatusCode, Json<WebhookResponse>), ApiError> {
+    let url = request.validate()?;
+    if !state.allow_private_targets {
+        check_target(&url).await?;
+    }
+
+    let webhook = WebhookInsertDb {
+        url: request.url,
+        secret: generate_secret(),
+        topic: WebhookTopicDb::from(request.topic),
+        filter: request.filter.filter(|filter| !filter.is_empty()),
+        created_at: Utc::now().naive_utc(),
+    };
+
+    let webhook = interact(&state, move |conn| {
+        webhook_repo::insert_webhook(conn, webhook)
+    })
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WebhookResponse::from_db(webhook, true)),
+    ))
+}
+
+async fn get_webhooks(
+    State(state): State<ApiState>,
+) -> Result<Json<Vec<WebhookResponse>>, ApiError> {
+    let webhooks = interact(&state, webhook_repo::get_webhooks).await?;
+
+    Ok(Json(
+        webhooks
+            .into_iter()
+            .map(|webhook| WebhookResponse::from_db(webhook, false))
+            .collect(),
+    ))
+}
+
+async fn get_webhook(
+    Path(id): Path<i32>,
+    State(state): State<ApiState>,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    let webhook =
+        interact(&state, move |conn| webhook_repo::get_webhook(conn, id))
+            .await?
+            .ok_or(ApiError::WebhookNotFound(id))?;
+
+    Ok(Json(WebhookResponse::from_db(webhook, false)))
+}
+
+async fn delete_webhook(
+    Path(id): Path<i32>,
+    State(state): State<ApiState>,
+) -> Result<StatusCode, ApiError> {
+    let deleted =
+        interact(&state, move |conn| webhook_repo::delete_webhook(conn, id))
+            .await?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::WebhookNotFound(id))
+    }
+}
+
+async fn get_dead_letters(
+    Path(id): Path<i32>,
+    Query(query): Query<DeadLettersQuery>,
+    State(state): State<ApiState>,
+) -> Result<Json<Vec<DeadLetterResponse>>, ApiError> {
+    let limit = query.limit.unwrap_or(100).clamp(1, MAX_DEAD_LETTERS);
+
+    let dead_letters = interact(&state, move |conn| {
+        if webhook_repo::get_webhook(conn, id)?.is_none() {
+            return anyhow::Ok(None);
+        }
+        webhook_repo::get_dead_letters(conn, id, limit).map(Some)
+    })
+    .await?
+    .ok_or(ApiError::WebhookNotFound(id))?;
+
+    Ok(Json(
+        dead_letters
+            .into_iter()
+            .map(DeadLetterResponse::from)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_keys_are_compared_exactly() {
+        assert!(keys_match("secret-key", "secret-key"));
+        assert!(!keys_match("secret-ke", "secret-key"));
+        assert!(!keys_match("secret-keY", "secret-key"));
+        assert!(!keys_match("", "secret-key"));
+    }
+
+    #[tokio::test]
+    async fn private_targets_are_rejected() {
+        for url in [
+            "http://127.0.0.1:8080/hook",
+            "http://[::1]/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[64:ff9b::a9fe:a9fe]/latest/meta-data",
+        ] {
+            let url = reqwest::Url::parse(url).unwrap();
+            assert!(check_target(&url).await.is_err(), "{url} was accepted");
+        }
+
+        let url = reqwest::Url::parse("https://93.184.215.14/hook").unwrap();
+        assert!(check_target(&url).await.is_ok());
+    }
+}
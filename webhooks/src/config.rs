@@ -0,0 +1,77 @@
+use shared::log_config::LogConfig;
+
+/// One week, in seconds
+const MAX_RETRY_DELAY: u64 = 7 * 24 * 3600;
+
+#[derive(clap::Parser)]
+pub struct AppConfig {
+    #[clap(long, env)]
+    pub database_url: String,
+
+    /// Port of the api used to register webhooks
+    #[clap(long, env, default_value_t = 5002)]
+    pub port: u16,
+
+    /// Key required in the `x-api-key` header to manage webhooks
+    #[clap(long, env)]
+    pub api_key: Option<String>,
+
+    /// Serve the api without an api key, letting anyone who can reach the
+    /// port register webhooks
+    #[clap(long, env, default_value_t = false)]
+    pub allow_open_api: bool,
+
+    /// Accept webhooks targeting loopback, private or link-local addresses,
+    /// e.g. when the receivers run next to the indexer
+    #[clap(long, env, default_value_t = false)]
+    pub allow_private_targets: bool,
+
+    /// Seconds between two checks for new blocks and pending deliveries
+    #[clap(long, env, default_value_t = 5)]
+    pub poll_interval: u64,
+
+    /// Maximum number of blocks turned into events at once
+    #[clap(long, env, default_value_t = 100)]
+    pub blocks_per_poll: u32,
+
+    /// Deliveries are moved to the dead letter table after that many failed
+    /// attempts
+    #[clap(
+        long,
+        env,
+        default_value_t = 10,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub max_attempts: u32,
+
+    /// Seconds to wait before the first retry, doubled on every failure
+    #[clap(
+        long,
+        env,
+        default_value_t = 10,
+        value_parser = clap::value_parser!(u64).range(1..=MAX_RETRY_DELAY)
+    )]
+    pub retry_base_delay: u64,
+
+    /// Upper bound of the delay between two retries, in seconds
+    #[clap(
+        long,
+        env,
+        default_value_t = 3600,
+        value_parser = clap::value_parser!(u64).range(1..=MAX_RETRY_DELAY)
+    )]
+    pub retry_max_delay: u64,
+
+    /// Percent of the IBC throughput limit of a token consumed in an epoch
+    /// from which ibc rate limit webhooks are notified
+    #[clap(
+        long,
+        env,
+        default_value_t = 80,
+        value_parser = clap::value_parser!(u32).range(1..=100)
+    )]
+    pub ibc_rate_limit_threshold: u32,
+
+    #[clap(flatten)]
+    pub log: LogConfig,
+}
@@ -0,0 +1,5 @@
+pub mod api;
+pub mod app_state;
+pub mod config;
+pub mod repository;
+pub mod services;
@@ -0,0 +1,474 @@
+use std::convert::identity;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use chrono::{NaiveDateTime, Utc};
+use clap::Parser;
+use diesel::PgConnection;
+use futures::StreamExt;
+use orm::governance_proposal::GovernanceProposalResultDb;
+use orm::validators::ValidatorStateDb;
+use orm::webhooks::{
+    WebhookDeliveryDb, WebhookIbcRateLimitSnapshotDb,
+    WebhookProposalSnapshotDb, WebhookValidatorSnapshotDb,
+};
+use shared::crawler_state::{BlockCrawlerState, CrawlerName};
+use shared::error::ContextDbInteractError;
+use shared::ibc::IbcRateLimitUsage;
+use shared::webhook::{Webhook, WebhookTopic};
+use tokio::net::TcpListener;
+use webhooks::api::{self, ApiState};
+use webhooks::app_state::AppState;
+use webhooks::config::AppConfig;
+use webhooks::repository;
+use webhooks::services::target::PublicResolver;
+use webhooks::services::{
+    delivery as delivery_service, events as events_service,
+};
+
+/// Maximum number of deliveries attempted in one poll
+const DELIVERY_BATCH: i64 = 50;
+/// Maximum number of deliveries in flight at once
+const DELIVERY_CONCURRENCY: usize = 10;
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time a replica has to attempt the deliveries it claimed, well above the
+/// time a batch takes when every delivery times out
+const DELIVERY_LEASE: chrono::Duration = chrono::Duration::minutes(5);
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = AppConfig::parse();
+
+    config.log.init();
+
+    let api_key = config.api_key.clone().filter(|key| !key.is_empty());
+    if api_key.is_none() && !config.allow_open_api {
+        anyhow::bail!(
+            "API_KEY must be set to protect the webhooks api, set \
+             ALLOW_OPEN_API to serve it without one"
+        );
+    }
+
+    let app_state = AppState::new(config.database_url.clone())?;
+
+    let listener = TcpListener::bind(("0.0.0.0", config.port))
+        .await
+        .context("Failed to bind the webhooks api")?;
+    tracing::info!("🚀 Webhooks api listening on port {}", config.port);
+
+    let router = api::router(ApiState {
+        app_state: app_state.clone(),
+        api_key,
+        allow_private_targets: config.allow_private_targets,
+    });
+    let server =
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+    // Targets are checked on registration, following redirects would let
+    // them point the deliveries anywhere else, and so would a proxy or a name
+    // resolving to another address by the time of the delivery
+    let mut client = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none());
+    if !config.allow_private_targets {
+        client = client.no_proxy().dns_resolver(Arc::new(PublicResolver));
+    }
+    let client = client.build().context("Failed to build the http client")?;
+    let delivery_app_state = app_state.clone();
+    let retry_policy = RetryPolicy {
+        max_attempts: config.max_attempts,
+        base_delay: Duration::from_secs(config.retry_base_delay),
+        max_delay: Duration::from_secs(config.retry_max_delay),
+    };
+    let poll_interval = Duration::from_secs(config.poll_interval);
+    let deliveries = tokio::spawn(async move {
+        loop {
+            if let Err(e) =
+                deliver_due(&delivery_app_state, &client, &retry_policy).await
+            {
+                tracing::error!("Failed to deliver webhooks: {:?}", e);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    });
+
+    let events = tokio::spawn(async move {
+        loop {
+            if let Err(e) = enqueue_events(
+                &app_state,
+                config.blocks_per_poll,
+                config.ibc_rate_limit_threshold,
+            )
+            .await
+            {
+                tracing::error!("Failed to enqueue webhook events: {:?}", e);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    });
+
+    tokio::select! {
+        result = server => result
+            .context("Webhooks api task failed")?
+            .context("Webhooks api stopped"),
+        result = deliveries => result.context("Delivery task failed"),
+        result = events => result.context("Event task failed"),
+    }
+}
+
+struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+/// Attempt every due delivery, deleting the successful ones and rescheduling
+/// or dead lettering the others
+async fn deliver_due(
+    app_state: &AppState,
+    client: &reqwest::Client,
+    retry_policy: &RetryPolicy,
+) -> anyhow::Result<()> {
+    let conn = app_state.get_db_connection().await?;
+    let now = Utc::now().naive_utc();
+
+    let due = conn
+        .interact(move |conn| {
+            repository::deliveries::claim_due_deliveries(
+                conn,
+                now,
+                now + DELIVERY_LEASE,
+                DELIVERY_BATCH,
+            )
+        })
+        .await
+        .context_db_interact_error()
+        .and_then(identity)?;
+
+    if due.is_empty() {
+        return anyhow::Ok(());
+    }
+
+    tracing::debug!("Attempting {} webhook deliveries", due.len());
+
+    let outcomes = futures::stream::iter(due)
+        .map(|(delivery, url, secret)| async move {
+            let result = delivery_service::deliver(
+                client,
+                &url,
+                &secret,
+                &delivery.event_id,
+                &delivery.payload,
+            )
+            .await;
+            (delivery, result)
+        })
+        .buffer_unordered(DELIVERY_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    let failed_at = Utc::now().naive_utc();
+    let mut delivered = vec![];
+    let mut retried = vec![];
+    let mut dead = vec![];
+
+    for (delivery, result) in outcomes {
+        let Err(error) = result else {
+            delivered.push(delivery.id);
+            continue;
+        };
+
+        let attempts = delivery.attempts.saturating_add(1);
+        let delivery = WebhookDeliveryDb {
+            attempts,
+            last_error: Some(format!("{:#}", error)),
+            next_attempt_at: retry_policy
+                .next_attempt_at(failed_at, attempts.unsigned_abs()),
+            ..delivery
+        };
+
+        if attempts.unsigned_abs() >= retry_policy.max_attempts {
+            tracing::warn!(
+                "Giving up on {} after {} attempts: {:#}",
+                delivery.event_id,
+                attempts,
+                error
+            );
+            dead.push(delivery);
+        } else {
+            tracing::debug!(
+                "Retrying {} later: {:#}",
+                delivery.event_id,
+                error
+            );
+            retried.push(delivery);
+        }
+    }
+
+    tracing::info!(
+        "Delivered {} webhooks, {} to retry, {} dead lettered",
+        delivered.len(),
+        retried.len(),
+        dead.len()
+    );
+
+    conn.interact(move |conn| {
+        for delivery_id in delivered {
+            repository::deliveries::delete_delivery(conn, delivery_id)?;
+        }
+        for delivery in &retried {
+            repository::deliveries::reschedule_delivery(conn, delivery)?;
+        }
+        for delivery in dead {
+            repository::deliveries::dead_letter_delivery(
+                conn, delivery, failed_at,
+            )?;
+        }
+
+        anyhow::Ok(())
+    })
+    .await
+    .context_db_interact_error()
+    .and_then(identity)
+}
+
+impl RetryPolicy {
+    /// Delays are bounded by the config, the max delay is only a fallback
+    /// should the date arithmetic overflow anyway
+    fn next_attempt_at(
+        &self,
+        failed_at: NaiveDateTime,
+        attempts: u32,
+    ) -> NaiveDateTime {
+        let delay = delivery_service::retry_delay(
+            attempts,
+            self.base_delay,
+            self.max_delay,
+        );
+        let max_delay = chrono::Duration::from_std(self.max_delay)
+            .unwrap_or(chrono::Duration::zero());
+
+        chrono::Duration::from_std(delay)
+            .ok()
+            .and_then(|delay| failed_at.checked_add_signed(delay))
+            .or_else(|| failed_at.checked_add_signed(max_delay))
+            .unwrap_or(failed_at)
+    }
+}
+
+/// Turn the blocks indexed since the last run into deliveries. On the very
+/// first run only the current proposal results, validator states and rate
+/// limit usages are recorded, so that webhooks are not flooded with the whole
+/// chain history.
+async fn enqueue_events(
+    app_state: &AppState,
+    blocks_per_poll: u32,
+    ibc_rate_limit_threshold: u32,
+) -> anyhow::Result<()> {
+    let conn = app_state.get_db_connection().await?;
+
+    conn.interact(move |conn| {
+        let Some(tip) = repository::crawler_state::get_last_processed_block(
+            conn,
+            CrawlerName::Transactions,
+        )?
+        else {
+            tracing::debug!("No transactions indexed yet, waiting...");
+            return anyhow::Ok(());
+        };
+
+        let cursor = repository::crawler_state::get_last_processed_block(
+            conn,
+            CrawlerName::Webhooks,
+        )?;
+
+        let Some(cursor) = cursor else {
+            seed(conn, tip, ibc_rate_limit_threshold)?;
+            tracing::info!("Following blocks from {}", tip);
+            return anyhow::Ok(());
+        };
+
+        if cursor >= tip {
+            tracing::trace!("No new block since {}, waiting...", cursor);
+            return anyhow::Ok(());
+        }
+
+        let from = cursor + 1;
+        let to = tip.min(cursor.saturating_add_unsigned(blocks_per_poll));
+
+        let webhooks = repository::webhooks::get_webhooks(conn)?
+            .into_iter()
+            .map(Webhook::from)
+            .collect::<Vec<_>>();
+
+        let addresses = webhooks
+            .iter()
+            .filter(|webhook| webhook.topic == WebhookTopic::AddressActivity)
+            .filter_map(|webhook| webhook.filter.clone())
+            .collect::<Vec<_>>();
+
+        let mut events = vec![];
+
+        if !addresses.is_empty() {
+            events.extend(events_service::address_events(
+                repository::events::get_transaction_history(
+                    conn, addresses, from, to,
+                )?,
+            ));
+        }
+
+        if webhooks
+            .iter()
+            .any(|webhook| webhook.topic == WebhookTopic::TxKind)
+        {
+            events.extend(events_service::tx_kind_events(
+                repository::events::get_inner_transactions(conn, from, to)?,
+            ));
+        }
+
+        let proposal_results = repository::events::get_proposal_results(conn)?;
+        events.extend(events_service::proposal_events(
+            repository::events::get_proposal_snapshots(conn)?,
+            &proposal_results,
+            to as u32,
+        ));
+
+        let validator_states = repository::events::get_validator_states(conn)?;
+        events.extend(events_service::validator_events(
+            repository::events::get_validator_snapshots(conn)?,
+            &validator_states,
+            to as u32,
+        ));
+
+        let rate_limit_usages = get_ibc_rate_limit_usages(conn, from, to)?;
+        events.extend(events_service::ibc_rate_limit_events(
+            repository::events::get_ibc_rate_limit_snapshots(conn)?,
+            &rate_limit_usages,
+            ibc_rate_limit_threshold,
+            to as u32,
+        ));
+        let rate_limit_snapshots = events_service::ibc_rate_limit_snapshots(
+            &rate_limit_usages,
+            ibc_rate_limit_threshold,
+        );
+
+        let deliveries = events_service::deliveries(
+            &events,
+            &webhooks,
+            Utc::now().naive_utc(),
+        );
+
+        tracing::info!(
+            "Blocks {} to {}: {} events, {} deliveries",
+            from,
+            to,
+            events.len(),
+            deliveries.len()
+        );
+
+        conn.build_transaction()
+            .read_write()
+            .run(|transaction_conn| {
+                repository::deliveries::insert_deliveries(
+                    transaction_conn,
+                    deliveries,
+                )?;
+
+                update_snapshots(
+                    transaction_conn,
+                    proposal_results,
+                    validator_states,
+                    rate_limit_snapshots,
+                    to,
+                )
+            })
+    })
+    .await
+    .context_db_interact_error()
+    .and_then(identity)
+}
+
+fn seed(
+    conn: &mut PgConnection,
+    tip: i32,
+    ibc_rate_limit_threshold: u32,
+) -> anyhow::Result<()> {
+    let proposal_results = repository::events::get_proposal_results(conn)?;
+    let validator_states = repository::events::get_validator_states(conn)?;
+    let rate_limit_snapshots = events_service::ibc_rate_limit_snapshots(
+        &get_ibc_rate_limit_usages(conn, tip, tip)?,
+        ibc_rate_limit_threshold,
+    );
+
+    conn.build_transaction()
+        .read_write()
+        .run(|transaction_conn| {
+            update_snapshots(
+                transaction_conn,
+                proposal_results,
+                validator_states,
+                rate_limit_snapshots,
+                tip,
+            )
+        })
+}
+
+/// Rate limit usages of the epochs spanned by the blocks in `from..=to`
+fn get_ibc_rate_limit_usages(
+    conn: &mut PgConnection,
+    from: i32,
+    to: i32,
+) -> anyhow::Result<Vec<IbcRateLimitUsage>> {
+    let mut usages = vec![];
+    for epoch in repository::events::get_block_epochs(conn, from, to)? {
+        usages.extend(repository::events::get_ibc_rate_limit_usages(
+            conn, epoch,
+        )?);
+    }
+
+    anyhow::Ok(usages)
+}
+
+fn update_snapshots(
+    transaction_conn: &mut PgConnection,
+    proposal_results: Vec<(i32, GovernanceProposalResultDb)>,
+    validator_states: Vec<(i32, String, ValidatorStateDb)>,
+    rate_limit_snapshots: Vec<WebhookIbcRateLimitSnapshotDb>,
+    last_processed_block: i32,
+) -> anyhow::Result<()> {
+    repository::events::upsert_proposal_snapshots(
+        transaction_conn,
+        proposal_results
+            .into_iter()
+            .map(|(proposal_id, result)| WebhookProposalSnapshotDb {
+                proposal_id,
+                result,
+            })
+            .collect(),
+    )?;
+
+    repository::events::upsert_validator_snapshots(
+        transaction_conn,
+        validator_states
+            .into_iter()
+            .map(|(validator_id, _, state)| WebhookValidatorSnapshotDb {
+                validator_id,
+                state,
+            })
+            .collect(),
+    )?;
+
+    repository::events::upsert_ibc_rate_limit_snapshots(
+        transaction_conn,
+        rate_limit_snapshots,
+    )?;
+
+    repository::crawler_state::upsert_crawler_state(
+        transaction_conn,
+        BlockCrawlerState {
+            last_processed_block: last_processed_block as u32,
+            timestamp: Utc::now().timestamp(),
+        },
+    )
+}
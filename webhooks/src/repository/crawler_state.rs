@@ -0,0 +1,43 @@
+use anyhow::Context;
+use diesel::upsert::excluded;
+use diesel::{
+    ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+};
+use orm::crawler_state::{BlockStateInsertDb, CrawlerNameDb};
+use orm::schema::crawler_state;
+use shared::crawler_state::{BlockCrawlerState, CrawlerName};
+
+/// Last block processed by the crawler, if it ran at all
+pub fn get_last_processed_block(
+    conn: &mut PgConnection,
+    name: CrawlerName,
+) -> anyhow::Result<Option<i32>> {
+    crawler_state::table
+        .filter(crawler_state::name.eq(CrawlerNameDb::from(name)))
+        .select(crawler_state::last_processed_block)
+        .first::<Option<i32>>(conn)
+        .optional()
+        .map(Option::flatten)
+        .context("Failed to read crawler state from the db")
+}
+
+pub fn upsert_crawler_state(
+    transaction_conn: &mut PgConnection,
+    crawler_state: BlockCrawlerState,
+) -> anyhow::Result<()> {
+    diesel::insert_into(crawler_state::table)
+        .values::<&BlockStateInsertDb>(
+            &(CrawlerName::Webhooks, crawler_state).into(),
+        )
+        .on_conflict(crawler_state::name)
+        .do_update()
+        .set((
+            crawler_state::timestamp.eq(excluded(crawler_state::timestamp)),
+            crawler_state::last_processed_block
+                .eq(excluded(crawler_state::last_processed_block)),
+        ))
+        .execute(transaction_conn)
+        .context("Failed to update crawler state in db")?;
+
+    anyhow::Ok(())
+}
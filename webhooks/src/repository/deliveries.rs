@@ -0,0 +1,124 @@
+use anyhow::Context;
+use chrono::NaiveDateTime;
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use orm::schema::{webhook_dead_letters, webhook_deliveries, webhooks};
+use orm::webhooks::{
+    WebhookDeadLetterInsertDb, WebhookDeliveryDb, WebhookDeliveryInsertDb,
+};
+use shared::tuple_len::TupleLen;
+
+use super::utils::MAX_PARAM_SIZE;
+
+/// Events already queued for a webhook are skipped
+pub fn insert_deliveries(
+    transaction_conn: &mut PgConnection,
+    deliveries: Vec<WebhookDeliveryInsertDb>,
+) -> anyhow::Result<()> {
+    let deliveries_col_count = webhook_deliveries::all_columns.len() as i64;
+
+    for chunk in deliveries
+        .chunks((MAX_PARAM_SIZE as i64 / deliveries_col_count) as usize)
+    {
+        diesel::insert_into(webhook_deliveries::table)
+            .values(chunk)
+            .on_conflict_do_nothing()
+            .execute(transaction_conn)
+            .context("Failed to insert webhook deliveries in db")?;
+    }
+
+    anyhow::Ok(())
+}
+
+/// Claim the deliveries whose next attempt is due, oldest first, along with
+/// the url and secret of their webhook. Claimed deliveries are pushed back to
+/// `lease_until`, so that other replicas skip them while they are attempted,
+/// and they are attempted again from then on if this one stops midway.
+pub fn claim_due_deliveries(
+    conn: &mut PgConnection,
+    now: NaiveDateTime,
+    lease_until: NaiveDateTime,
+    limit: i64,
+) -> anyhow::Result<Vec<(WebhookDeliveryDb, String, String)>> {
+    conn.transaction(|transaction_conn| {
+        let delivery_ids = webhook_deliveries::table
+            .filter(webhook_deliveries::next_attempt_at.le(now))
+            .order(webhook_deliveries::next_attempt_at.asc())
+            .limit(limit)
+            .select(webhook_deliveries::id)
+            .for_update()
+            .skip_locked()
+            .load::<i32>(transaction_conn)?;
+
+        diesel::update(
+            webhook_deliveries::table
+                .filter(webhook_deliveries::id.eq_any(&delivery_ids)),
+        )
+        .set(webhook_deliveries::next_attempt_at.eq(lease_until))
+        .execute(transaction_conn)?;
+
+        webhook_deliveries::table
+            .inner_join(webhooks::table)
+            .filter(webhook_deliveries::id.eq_any(delivery_ids))
+            .order(webhook_deliveries::id.asc())
+            .select((
+                WebhookDeliveryDb::as_select(),
+                webhooks::url,
+                webhooks::secret,
+            ))
+            .load(transaction_conn)
+    })
+    .context("Failed to claim due webhook deliveries in the db")
+}
+
+pub fn delete_delivery(
+    conn: &mut PgConnection,
+    delivery_id: i32,
+) -> anyhow::Result<()> {
+    diesel::delete(webhook_deliveries::table.find(delivery_id))
+        .execute(conn)
+        .context("Failed to delete webhook delivery from db")?;
+
+    anyhow::Ok(())
+}
+
+pub fn reschedule_delivery(
+    conn: &mut PgConnection,
+    delivery: &WebhookDeliveryDb,
+) -> anyhow::Result<()> {
+    diesel::update(webhook_deliveries::table.find(delivery.id))
+        .set((
+            webhook_deliveries::attempts.eq(delivery.attempts),
+            webhook_deliveries::last_error.eq(&delivery.last_error),
+            webhook_deliveries::next_attempt_at.eq(delivery.next_attempt_at),
+        ))
+        .execute(conn)
+        .context("Failed to reschedule webhook delivery in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Move a delivery which ran out of attempts to the dead letter table
+pub fn dead_letter_delivery(
+    conn: &mut PgConnection,
+    delivery: WebhookDeliveryDb,
+    failed_at: NaiveDateTime,
+) -> anyhow::Result<()> {
+    conn.transaction(|transaction_conn| {
+        diesel::delete(webhook_deliveries::table.find(delivery.id))
+            .execute(transaction_conn)?;
+
+        diesel::insert_into(webhook_dead_letters::table)
+            .values(WebhookDeadLetterInsertDb::from_delivery(
+                delivery, failed_at,
+            ))
+            .execute(transaction_conn)?;
+
+        diesel::QueryResult::Ok(())
+    })
+    .context("Failed to move webhook delivery to the dead letters in db")?;
+
+    anyhow::Ok(())
+}
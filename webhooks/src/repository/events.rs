@@ -0,0 +1,249 @@
+use anyhow::Context;
+use bigdecimal::BigDecimal;
+use diesel::upsert::excluded;
+use diesel::{
+    ExpressionMethods, JoinOnDsl, NullableExpressionMethods, PgConnection,
+    QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use orm::governance_proposal::GovernanceProposalResultDb;
+use orm::schema::{
+    blocks, governance_proposals, ibc_rate_limits, ibc_token_flows,
+    inner_transactions, transaction_history, validators,
+    webhook_ibc_rate_limit_snapshots, webhook_proposal_snapshots,
+    webhook_validator_snapshots, wrapper_transactions,
+};
+use orm::transactions::{InnerTransactionDb, TransactionHistoryDb};
+use orm::validators::ValidatorStateDb;
+use orm::webhooks::{
+    WebhookIbcRateLimitSnapshotDb, WebhookProposalSnapshotDb,
+    WebhookValidatorSnapshotDb,
+};
+use shared::ibc::IbcRateLimitUsage;
+
+/// Inner transactions of the blocks in `from..=to`, along with their block
+/// height
+pub fn get_inner_transactions(
+    conn: &mut PgConnection,
+    from: i32,
+    to: i32,
+) -> anyhow::Result<Vec<(InnerTransactionDb, i32)>> {
+    inner_transactions::table
+        .inner_join(
+            wrapper_transactions::table
+                .on(inner_transactions::wrapper_id.eq(wrapper_transactions::id)),
+        )
+        .filter(wrapper_transactions::block_height.between(from, to))
+        .order((
+            wrapper_transactions::block_height.asc(),
+            inner_transactions::id.asc(),
+        ))
+        .select((
+            InnerTransactionDb::as_select(),
+            wrapper_transactions::block_height,
+        ))
+        .load(conn)
+        .context("Failed to read inner transactions from the db")
+}
+
+/// History entries of the addresses in the blocks in `from..=to`, along with
+/// their transaction and block height
+pub fn get_transaction_history(
+    conn: &mut PgConnection,
+    addresses: Vec<String>,
+    from: i32,
+    to: i32,
+) -> anyhow::Result<Vec<(TransactionHistoryDb, InnerTransactionDb, i32)>> {
+    transaction_history::table
+        .inner_join(
+            inner_transactions::table
+                .on(transaction_history::inner_tx_id.eq(inner_transactions::id)),
+        )
+        .inner_join(
+            wrapper_transactions::table
+                .on(inner_transactions::wrapper_id.eq(wrapper_transactions::id)),
+        )
+        .filter(transaction_history::target.eq_any(addresses))
+        .filter(wrapper_transactions::block_height.between(from, to))
+        .order((
+            wrapper_transactions::block_height.asc(),
+            transaction_history::id.asc(),
+        ))
+        .select((
+            TransactionHistoryDb::as_select(),
+            InnerTransactionDb::as_select(),
+            wrapper_transactions::block_height,
+        ))
+        .load(conn)
+        .context("Failed to read transaction history from the db")
+}
+
+pub fn get_proposal_results(
+    conn: &mut PgConnection,
+) -> anyhow::Result<Vec<(i32, GovernanceProposalResultDb)>> {
+    governance_proposals::table
+        .select((governance_proposals::id, governance_proposals::result))
+        .load(conn)
+        .context("Failed to read proposal results from the db")
+}
+
+pub fn get_proposal_snapshots(
+    conn: &mut PgConnection,
+) -> anyhow::Result<Vec<WebhookProposalSnapshotDb>> {
+    webhook_proposal_snapshots::table
+        .select(WebhookProposalSnapshotDb::as_select())
+        .load(conn)
+        .context("Failed to read proposal snapshots from the db")
+}
+
+pub fn upsert_proposal_snapshots(
+    transaction_conn: &mut PgConnection,
+    snapshots: Vec<WebhookProposalSnapshotDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(webhook_proposal_snapshots::table)
+        .values(snapshots)
+        .on_conflict(webhook_proposal_snapshots::proposal_id)
+        .do_update()
+        .set(
+            webhook_proposal_snapshots::result
+                .eq(excluded(webhook_proposal_snapshots::result)),
+        )
+        .execute(transaction_conn)
+        .context("Failed to update proposal snapshots in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Id, address and state of every validator
+pub fn get_validator_states(
+    conn: &mut PgConnection,
+) -> anyhow::Result<Vec<(i32, String, ValidatorStateDb)>> {
+    validators::table
+        .select((
+            validators::id,
+            validators::namada_address,
+            validators::state,
+        ))
+        .load(conn)
+        .context("Failed to read validator states from the db")
+}
+
+pub fn get_validator_snapshots(
+    conn: &mut PgConnection,
+) -> anyhow::Result<Vec<WebhookValidatorSnapshotDb>> {
+    webhook_validator_snapshots::table
+        .select(WebhookValidatorSnapshotDb::as_select())
+        .load(conn)
+        .context("Failed to read validator snapshots from the db")
+}
+
+pub fn upsert_validator_snapshots(
+    transaction_conn: &mut PgConnection,
+    snapshots: Vec<WebhookValidatorSnapshotDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(webhook_validator_snapshots::table)
+        .values(snapshots)
+        .on_conflict(webhook_validator_snapshots::validator_id)
+        .do_update()
+        .set(
+            webhook_validator_snapshots::state
+                .eq(excluded(webhook_validator_snapshots::state)),
+        )
+        .execute(transaction_conn)
+        .context("Failed to update validator snapshots in db")?;
+
+    anyhow::Ok(())
+}
+
+/// Epochs of the blocks in `from..=to`, in ascending order
+pub fn get_block_epochs(
+    conn: &mut PgConnection,
+    from: i32,
+    to: i32,
+) -> anyhow::Result<Vec<i32>> {
+    blocks::table
+        .filter(blocks::height.between(from, to))
+        .select(blocks::epoch.assume_not_null())
+        .filter(blocks::epoch.is_not_null())
+        .distinct()
+        .order(blocks::epoch.asc())
+        .load(conn)
+        .context("Failed to read block epochs from the db")
+}
+
+/// Flows of every token during the epoch, against the latest rate limits
+/// known at that epoch
+pub fn get_ibc_rate_limit_usages(
+    conn: &mut PgConnection,
+    epoch: i32,
+) -> anyhow::Result<Vec<IbcRateLimitUsage>> {
+    diesel::alias!(ibc_rate_limits as ibc_rate_limits_alias: IbcRateLimitsAlias);
+
+    let limits_epoch = ibc_rate_limits_alias
+        .select(diesel::dsl::max(
+            ibc_rate_limits_alias.field(ibc_rate_limits::epoch),
+        ))
+        .filter(
+            ibc_rate_limits_alias
+                .field(ibc_rate_limits::epoch)
+                .le(epoch),
+        )
+        .single_value();
+
+    let usages = ibc_token_flows::table
+        .inner_join(
+            ibc_rate_limits::table
+                .on(ibc_rate_limits::address.eq(ibc_token_flows::address)),
+        )
+        .filter(ibc_token_flows::epoch.eq(epoch))
+        .filter(ibc_rate_limits::epoch.nullable().eq(limits_epoch))
+        .select((
+            ibc_token_flows::address,
+            ibc_token_flows::deposit,
+            ibc_token_flows::withdraw,
+            ibc_rate_limits::throughput_limit,
+        ))
+        .load::<(String, BigDecimal, BigDecimal, BigDecimal)>(conn)
+        .context("Failed to read ibc rate limit usage from the db")?;
+
+    anyhow::Ok(
+        usages
+            .into_iter()
+            .map(|(address, deposit, withdraw, throughput_limit)| {
+                IbcRateLimitUsage {
+                    address,
+                    epoch: epoch as u32,
+                    deposit,
+                    withdraw,
+                    throughput_limit,
+                }
+            })
+            .collect(),
+    )
+}
+
+pub fn get_ibc_rate_limit_snapshots(
+    conn: &mut PgConnection,
+) -> anyhow::Result<Vec<WebhookIbcRateLimitSnapshotDb>> {
+    webhook_ibc_rate_limit_snapshots::table
+        .select(WebhookIbcRateLimitSnapshotDb::as_select())
+        .load(conn)
+        .context("Failed to read ibc rate limit snapshots from the db")
+}
+
+pub fn upsert_ibc_rate_limit_snapshots(
+    transaction_conn: &mut PgConnection,
+    snapshots: Vec<WebhookIbcRateLimitSnapshotDb>,
+) -> anyhow::Result<()> {
+    diesel::insert_into(webhook_ibc_rate_limit_snapshots::table)
+        .values(snapshots)
+        .on_conflict(webhook_ibc_rate_limit_snapshots::address)
+        .do_update()
+        .set(
+            webhook_ibc_rate_limit_snapshots::epoch
+                .eq(excluded(webhook_ibc_rate_limit_snapshots::epoch)),
+        )
+        .execute(transaction_conn)
+        .context("Failed to update ibc rate limit snapshots in db")?;
+
+    anyhow::Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod crawler_state;
+pub mod deliveries;
+pub mod events;
+mod utils;
+pub mod webhooks;
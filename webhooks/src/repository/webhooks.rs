@@ -0,0 +1,65 @@
+use anyhow::Context;
+use diesel::{
+    ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use orm::schema::{webhook_dead_letters, webhooks};
+use orm::webhooks::{WebhookDb, WebhookDeadLetterDb, WebhookInsertDb};
+
+pub fn insert_webhook(
+    conn: &mut PgConnection,
+    webhook: WebhookInsertDb,
+) -> anyhow::Result<WebhookDb> {
+    diesel::insert_into(webhooks::table)
+        .values(webhook)
+        .returning(WebhookDb::as_returning())
+        .get_result(conn)
+        .context("Failed to insert webhook in db")
+}
+
+pub fn get_webhooks(conn: &mut PgConnection) -> anyhow::Result<Vec<WebhookDb>> {
+    webhooks::table
+        .order(webhooks::id.asc())
+        .select(WebhookDb::as_select())
+        .load(conn)
+        .context("Failed to read webhooks from the db")
+}
+
+pub fn get_webhook(
+    conn: &mut PgConnection,
+    id: i32,
+) -> anyhow::Result<Option<WebhookDb>> {
+    webhooks::table
+        .find(id)
+        .select(WebhookDb::as_select())
+        .first(conn)
+        .optional()
+        .context("Failed to read webhook from the db")
+}
+
+/// Pending deliveries and dead letters of the webhook are deleted along with
+/// it. Returns whether the webhook existed.
+pub fn delete_webhook(
+    conn: &mut PgConnection,
+    id: i32,
+) -> anyhow::Result<bool> {
+    diesel::delete(webhooks::table.find(id))
+        .execute(conn)
+        .map(|deleted| deleted > 0)
+        .context("Failed to delete webhook from db")
+}
+
+/// Latest deliveries of the webhook which ran out of attempts
+pub fn get_dead_letters(
+    conn: &mut PgConnection,
+    webhook_id: i32,
+    limit: i64,
+) -> anyhow::Result<Vec<WebhookDeadLetterDb>> {
+    webhook_dead_letters::table
+        .filter(webhook_dead_letters::webhook_id.eq(webhook_id))
+        .order(webhook_dead_letters::failed_at.desc())
+        .limit(limit)
+        .select(WebhookDeadLetterDb::as_select())
+        .load(conn)
+        .context("Failed to read webhook dead letters from the db")
+}
@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Random secret used to sign the payloads sent to a webhook
+pub fn generate_secret() -> String {
+    hex::encode(rand::random::<[u8; 32]>())
+}
+
+/// Signature of a payload sent at `timestamp`, an HMAC-SHA256 of
+/// `{timestamp}.{body}` keyed with the webhook secret
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Delay before retrying a delivery which failed `attempts` times, doubled on
+/// every failure
+pub fn retry_delay(attempts: u32, base: Duration, max: Duration) -> Duration {
+    base.saturating_mul(2_u32.saturating_pow(attempts.saturating_sub(1)))
+        .min(max)
+}
+
+/// Post a signed payload, any non 2xx answer counting as a failure
+pub async fn deliver(
+    client: &reqwest::Client,
+    url: &str,
+    secret: &str,
+    event_id: &str,
+    payload: &Value,
+) -> anyhow::Result<()> {
+    let body = payload.to_string();
+    let timestamp = chrono::Utc::now().timestamp();
+
+    client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("x-webhook-id", event_id)
+        .header("x-webhook-timestamp", timestamp)
+        .header("x-webhook-signature", sign(secret, timestamp, &body))
+        .body(body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| {
+            format!("Failed to deliver {} to {}", event_id, url)
+        })?;
+
+    anyhow::Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payloads_are_signed_with_the_timestamp() {
+        assert_eq!(
+            sign("secret", 1700000000, r#"{"id":"tx-kind/abc"}"#),
+            "sha256=\
+             9de4aa92d24373eaa0e0fbfd9ffdd27c688e3fab47578f6a9327ffa397268f02"
+        );
+    }
+
+    #[test]
+    fn retries_back_off_exponentially() {
+        let base = Duration::from_secs(10);
+        let max = Duration::from_secs(3600);
+
+        assert_eq!(retry_delay(1, base, max), Duration::from_secs(10));
+        assert_eq!(retry_delay(2, base, max), Duration::from_secs(20));
+        assert_eq!(retry_delay(5, base, max), Duration::from_secs(160));
+        assert_eq!(retry_delay(10, base, max), max);
+        assert_eq!(retry_delay(100, base, max), max);
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, HashMap};
+
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::NaiveDateTime;
+use orm::governance_proposal::GovernanceProposalResultDb;
+use orm::transactions::{
+    InnerTransactionDb, TransactionHistoryDb, TransactionHistoryKindDb,
+    TransactionKindDb,
+};
+use orm::validators::ValidatorStateDb;
+use orm::webhooks::{
+    WebhookDeliveryInsertDb, WebhookIbcRateLimitSnapshotDb,
+    WebhookProposalSnapshotDb, WebhookValidatorSnapshotDb,
+};
+use serde::Serialize;
+use serde_json::{Value, json};
+use shared::ibc::IbcRateLimitUsage;
+use shared::webhook::{Webhook, WebhookEvent, WebhookTopic};
+
+/// Name of a db enum variant as the rest api spells it, e.g.
+/// `transparentTransfer`
+pub fn camel_case<T: Serialize>(value: &T) -> String {
+    let name = match serde_json::to_value(value) {
+        Ok(Value::String(name)) => name,
+        _ => return String::new(),
+    };
+
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+/// Whether `kind` is the camel case name of a transaction kind
+pub fn is_transaction_kind(kind: &str) -> bool {
+    let mut chars = kind.chars();
+    let pascal_case = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+        None => return false,
+    };
+
+    serde_json::from_value::<TransactionKindDb>(Value::String(pascal_case))
+        .is_ok()
+}
+
+pub fn address_events(
+    history: Vec<(TransactionHistoryDb, InnerTransactionDb, i32)>,
+) -> Vec<WebhookEvent> {
+    history
+        .into_iter()
+        .map(|(entry, tx, block_height)| {
+            let direction = match entry.kind {
+                TransactionHistoryKindDb::Sent => "sent",
+                TransactionHistoryKindDb::Received => "received",
+            };
+
+            WebhookEvent {
+                id: format!(
+                    "address-activity/{}/{}/{}",
+                    entry.target, tx.id, direction
+                ),
+                topic: WebhookTopic::AddressActivity,
+                subject: entry.target.clone(),
+                block_height: Some(block_height as u32),
+                data: json!({
+                    "address": entry.target,
+                    "direction": direction,
+                    "txId": tx.id,
+                    "wrapperId": tx.wrapper_id,
+                    "kind": camel_case(&tx.kind),
+                    "exitCode": camel_case(&tx.exit_code),
+                }),
+            }
+        })
+        .collect()
+}
+
+pub fn tx_kind_events(
+    txs: Vec<(InnerTransactionDb, i32)>,
+) -> Vec<WebhookEvent> {
+    txs.into_iter()
+        .map(|(tx, block_height)| {
+            let kind = camel_case(&tx.kind);
+
+            WebhookEvent {
+                id: format!("tx-kind/{}", tx.id),
+                topic: WebhookTopic::TxKind,
+                subject: kind.clone(),
+                block_height: Some(block_height as u32),
+                data: json!({
+                    "txId": tx.id,
+                    "wrapperId": tx.wrapper_id,
+                    "kind": kind,
+                    "exitCode": camel_case(&tx.exit_code),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Proposals whose result differs from the snapshot, observed at
+/// `block_height`. New proposals have no previous result.
+pub fn proposal_events(
+    snapshots: Vec<WebhookProposalSnapshotDb>,
+    results: &[(i32, GovernanceProposalResultDb)],
+    block_height: u32,
+) -> Vec<WebhookEvent> {
+    let previous = snapshots
+        .into_iter()
+        .map(|snapshot| (snapshot.proposal_id, camel_case(&snapshot.result)))
+        .collect::<HashMap<_, _>>();
+
+    results
+        .iter()
+        .filter_map(|(proposal_id, result)| {
+            let result = camel_case(result);
+            let previous = previous.get(proposal_id);
+            if previous == Some(&result) {
+                return None;
+            }
+
+            Some(WebhookEvent {
+                id: format!(
+                    "proposal-status/{}/{}/{}",
+                    proposal_id, result, block_height
+                ),
+                topic: WebhookTopic::ProposalStatus,
+                subject: proposal_id.to_string(),
+                block_height: Some(block_height),
+                data: json!({
+                    "proposalId": proposal_id,
+                    "previousResult": previous,
+                    "result": result,
+                }),
+            })
+        })
+        .collect()
+}
+
+/// Validators whose state differs from the snapshot, observed at
+/// `block_height`. New validators have no previous state.
+pub fn validator_events(
+    snapshots: Vec<WebhookValidatorSnapshotDb>,
+    states: &[(i32, String, ValidatorStateDb)],
+    block_height: u32,
+) -> Vec<WebhookEvent> {
+    let previous = snapshots
+        .into_iter()
+        .map(|snapshot| (snapshot.validator_id, camel_case(&snapshot.state)))
+        .collect::<HashMap<_, _>>();
+
+    states
+        .iter()
+        .filter_map(|(validator_id, address, state)| {
+            let state = camel_case(state);
+            let previous = previous.get(validator_id);
+            if previous == Some(&state) {
+                return None;
+            }
+
+            Some(WebhookEvent {
+                id: format!(
+                    "validator-state/{}/{}/{}",
+                    address, state, block_height
+                ),
+                topic: WebhookTopic::ValidatorState,
+                subject: address.clone(),
+                block_height: Some(block_height),
+                data: json!({
+                    "validator": address,
+                    "previousState": previous,
+                    "state": state,
+                }),
+            })
+        })
+        .collect()
+}
+
+/// Tokens which reached `threshold` percent of their rate limit in an epoch
+/// more recent than their snapshot, observed at `block_height`
+pub fn ibc_rate_limit_events(
+    snapshots: Vec<WebhookIbcRateLimitSnapshotDb>,
+    usages: &[IbcRateLimitUsage],
+    threshold: u32,
+    block_height: u32,
+) -> Vec<WebhookEvent> {
+    let previous = snapshots
+        .into_iter()
+        .map(|snapshot| (snapshot.address, snapshot.epoch))
+        .collect::<HashMap<_, _>>();
+
+    ibc_rate_limit_snapshots(usages, threshold)
+        .into_iter()
+        .filter(|snapshot| {
+            previous
+                .get(&snapshot.address)
+                .is_none_or(|epoch| *epoch < snapshot.epoch)
+        })
+        .filter_map(|snapshot| {
+            usages.iter().find(|usage| {
+                usage.address == snapshot.address
+                    && usage.epoch as i32 == snapshot.epoch
+            })
+        })
+        .map(|usage| WebhookEvent {
+            id: format!("ibc-rate-limit/{}/{}", usage.address, usage.epoch),
+            topic: WebhookTopic::IbcRateLimit,
+            subject: usage.address.clone(),
+            block_height: Some(block_height),
+            data: json!({
+                "tokenAddress": usage.address,
+                "epoch": usage.epoch,
+                "throughput": usage.throughput().normalized().to_plain_string(),
+                "throughputLimit":
+                    usage.throughput_limit.normalized().to_plain_string(),
+                "utilization": usage
+                    .utilization()
+                    .and_then(|utilization| utilization.to_f64()),
+                "threshold": threshold,
+            }),
+        })
+        .collect()
+}
+
+/// Latest epoch in which each token reached `threshold` percent of its rate
+/// limit
+pub fn ibc_rate_limit_snapshots(
+    usages: &[IbcRateLimitUsage],
+    threshold: u32,
+) -> Vec<WebhookIbcRateLimitSnapshotDb> {
+    let threshold = BigDecimal::from(threshold);
+
+    usages
+        .iter()
+        .filter(|usage| usage.reached(&threshold))
+        .fold(BTreeMap::<&str, i32>::new(), |mut epochs, usage| {
+            let epoch = epochs.entry(&usage.address).or_default();
+            *epoch = (*epoch).max(usage.epoch as i32);
+            epochs
+        })
+        .into_iter()
+        .map(|(address, epoch)| WebhookIbcRateLimitSnapshotDb {
+            address: address.to_owned(),
+            epoch,
+        })
+        .collect()
+}
+
+/// One delivery per event and matching webhook, due right away
+pub fn deliveries(
+    events: &[WebhookEvent],
+    webhooks: &[Webhook],
+    now: NaiveDateTime,
+) -> Vec<WebhookDeliveryInsertDb> {
+    events
+        .iter()
+        .flat_map(|event| {
+            webhooks
+                .iter()
+                .filter(|webhook| event.matches(webhook))
+                .map(|webhook| WebhookDeliveryInsertDb {
+                    webhook_id: webhook.id,
+                    event_id: event.id.clone(),
+                    payload: event.payload(),
+                    next_attempt_at: now,
+                    created_at: now,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(id: i32, topic: WebhookTopic, filter: Option<&str>) -> Webhook {
+        Webhook {
+            id,
+            url: "http://localhost:8080".to_owned(),
+            secret: "secret".to_owned(),
+            topic,
+            filter: filter.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn names_are_camel_cased() {
+        assert_eq!(
+            camel_case(&TransactionKindDb::IbcTransparentTransfer),
+            "ibcTransparentTransfer"
+        );
+        assert_eq!(
+            camel_case(&ValidatorStateDb::BelowCapacity),
+            "belowCapacity"
+        );
+        assert!(is_transaction_kind("transparentTransfer"));
+        assert!(!is_transaction_kind("transparent_transfer"));
+        assert!(!is_transaction_kind(""));
+    }
+
+    #[test]
+    fn only_changed_states_are_events() {
+        let snapshots = vec![
+            WebhookValidatorSnapshotDb {
+                validator_id: 1,
+                state: ValidatorStateDb::Consensus,
+            },
+            WebhookValidatorSnapshotDb {
+                validator_id: 2,
+                state: ValidatorStateDb::Consensus,
+            },
+        ];
+        let states = vec![
+            (1, "tnam1v1".to_owned(), ValidatorStateDb::Consensus),
+            (2, "tnam1v2".to_owned(), ValidatorStateDb::Jailed),
+            (3, "tnam1v3".to_owned(), ValidatorStateDb::BelowCapacity),
+        ];
+
+        let events = validator_events(snapshots, &states, 42);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].id, "validator-state/tnam1v2/jailed/42");
+        assert_eq!(events[0].data["previousState"], "consensus");
+        assert_eq!(events[1].subject, "tnam1v3");
+        assert_eq!(events[1].data["previousState"], Value::Null);
+    }
+
+    #[test]
+    fn events_are_delivered_to_matching_webhooks() {
+        let events = proposal_events(
+            vec![],
+            &[
+                (1, GovernanceProposalResultDb::Passed),
+                (2, GovernanceProposalResultDb::Rejected),
+            ],
+            42,
+        );
+        let webhooks = vec![
+            webhook(1, WebhookTopic::ProposalStatus, None),
+            webhook(2, WebhookTopic::ProposalStatus, Some("2")),
+            webhook(3, WebhookTopic::ValidatorState, None),
+        ];
+
+        let deliveries =
+            deliveries(&events, &webhooks, NaiveDateTime::default());
+
+        assert_eq!(
+            deliveries
+                .iter()
+                .map(|delivery| (
+                    delivery.webhook_id,
+                    delivery.event_id.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "proposal-status/1/passed/42"),
+                (1, "proposal-status/2/rejected/42"),
+                (2, "proposal-status/2/rejected/42"),
+            ]
+        );
+        assert_eq!(deliveries[0].payload["topic"], "proposalStatus");
+        assert_eq!(deliveries[0].payload["blockHeight"], 42);
+    }
+
+    fn usage(address: &str, epoch: u32, withdraw: u64) -> IbcRateLimitUsage {
+        IbcRateLimitUsage {
+            address: address.to_owned(),
+            epoch,
+            deposit: BigDecimal::from(0),
+            withdraw: BigDecimal::from(withdraw),
+            throughput_limit: BigDecimal::from(400),
+        }
+    }
+
+    #[test]
+    fn rate_limits_are_reported_once_per_epoch() {
+        let snapshots = vec![WebhookIbcRateLimitSnapshotDb {
+            address: "tnam1a".to_owned(),
+            epoch: 7,
+        }];
+        let usages = vec![
+            usage("tnam1a", 7, 400),
+            usage("tnam1b", 7, 300),
+            usage("tnam1c", 7, 320),
+            usage("tnam1a", 8, 350),
+        ];
+
+        let events = ibc_rate_limit_events(snapshots, &usages, 80, 42);
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["ibc-rate-limit/tnam1a/8", "ibc-rate-limit/tnam1c/7"]
+        );
+        assert_eq!(events[0].topic, WebhookTopic::IbcRateLimit);
+        assert_eq!(events[0].subject, "tnam1a");
+        assert_eq!(events[0].data["throughput"], "350");
+        assert_eq!(events[0].data["utilization"], 87.5);
+        assert_eq!(events[1].data["threshold"], 80);
+
+        let snapshots = ibc_rate_limit_snapshots(&usages, 80);
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|snapshot| (snapshot.address.as_str(), snapshot.epoch))
+                .collect::<Vec<_>>(),
+            vec![("tnam1a", 8), ("tnam1c", 7)]
+        );
+        assert!(ibc_rate_limit_events(snapshots, &usages, 80, 43).is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod delivery;
+pub mod events;
+pub mod target;
@@ -0,0 +1,131 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+
+/// Resolver of the delivery client, which only connects to public addresses.
+/// Targets are checked on registration, but the name of a target can resolve
+/// to another address by the time a delivery is made. Ip literals are not
+/// resolved, so they are only checked on registration.
+pub struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addresses = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|address| is_public(address.ip()))
+                .collect::<Vec<_>>();
+
+            if addresses.is_empty() {
+                return Err(format!(
+                    "{} does not resolve to a public address",
+                    name.as_str()
+                )
+                .into());
+            }
+
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    // 100.64.0.0/10, shared by carrier grade NATs
+    let is_shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64;
+
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || is_shared)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+
+    // NAT64 (64:ff9b::/96) and 6to4 (2002::/16) addresses reach the IPv4
+    // address they embed, 64:ff9b:1::/48 is only used by local translators
+    let embedded_v4 = match segments {
+        [0x64, 0xff9b, 0, 0, 0, 0, high, low] => Some((high, low)),
+        [0x2002, high, low, ..] => Some((high, low)),
+        _ => None,
+    };
+    let is_local_nat64 = segments[..3] == [0x64, 0xff9b, 1];
+
+    let is_private_embedded = embedded_v4.is_some_and(|(high, low)| {
+        !is_public_v4(Ipv4Addr::from((u32::from(high) << 16) | u32::from(low)))
+    });
+
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        || is_local_nat64
+        || is_private_embedded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::7f00:1",
+            "64:ff9b::a9fe:a9fe",
+            "64:ff9b:1::808:808",
+            "2002:7f00:1::",
+            "2002:c0a8:101::1",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{ip} should be private");
+        }
+
+        for ip in [
+            "8.8.8.8",
+            "100.128.0.1",
+            "2606:4700::1111",
+            "64:ff9b::808:808",
+            "2002:808:808::1",
+        ] {
+            assert!(is_public(ip.parse().unwrap()), "{ip} should be public");
+        }
+    }
+    #[tokio::test]
+    async fn only_public_addresses_are_resolved() {
+        // Literals resolve to themselves without a dns lookup
+        let private = "169.254.169.254".parse::<Name>().unwrap();
+        assert!(PublicResolver.resolve(private).await.is_err());
+
+        let public = "93.184.215.14".parse::<Name>().unwrap();
+        let addresses = PublicResolver.resolve(public).await.unwrap();
+        assert_eq!(
+            addresses.map(|address| address.ip()).collect::<Vec<_>>(),
+            vec!["93.184.215.14".parse::<IpAddr>().unwrap()]
+        );
+    }
+}
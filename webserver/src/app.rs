@@ -12,24 +12,28 @@ use serde_json::json;
 use shared::client::Client;
 use tower::ServiceBuilder;
 use tower::buffer::BufferLayer;
-use tower::limit::RateLimitLayer;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
 use crate::appstate::AppState;
 use crate::config::AppConfig;
 use crate::handler::{
-    balance as balance_handlers, block as block_handlers,
-    chain as chain_handlers, crawler_state as crawler_state_handlers,
-    gas as gas_handlers, governance as gov_handlers, ibc as ibc_handler,
-    masp as masp_handlers, pgf as pgf_service, pk as pk_handlers,
-    pos as pos_handlers, transaction as transaction_handlers,
+    account as account_handlers, balance as balance_handlers,
+    block as block_handlers, chain as chain_handlers,
+    crawler_state as crawler_state_handlers, gas as gas_handlers,
+    governance as gov_handlers, graphql as graphql_handlers,
+    ibc as ibc_handler, masp as masp_handlers, openapi as openapi_handlers,
+    pgf as pgf_service, pk as pk_handlers, pos as pos_handlers,
+    search as search_handlers, subscription as subscription_handlers,
+    transaction as transaction_handlers,
 };
+use crate::middleware::cache as cache_middleware;
+use crate::middleware::rate_limit::{self, RateLimiter};
+use crate::service::cache;
 use crate::state::common::CommonState;
 
 lazy_static! {
     static ref HTTP_TIMEOUT: u64 = 60;
-    static ref REQ_PER_SEC: u64 = u64::MAX;
 }
 
 pub struct ApplicationServer;
@@ -43,19 +47,48 @@ impl ApplicationServer {
 
         let app_state = AppState::new(db_url, cache_url);
         let client = Client::new(&config.tendermint_url);
+        let rate_limiter = RateLimiter::new(&config)?;
 
         let routes = {
             let common_state = CommonState::new(
                 client.get(),
                 config.clone(),
                 app_state.clone(),
-            );
+            )?;
+            let cached = |policy| {
+                axum::middleware::from_fn_with_state(
+                    (common_state.cache_service.clone(), policy),
+                    cache_middleware::cache,
+                )
+            };
 
             Router::new()
-                .route("/pos/validator", get(pos_handlers::get_validators))
+                .route(
+                    "/pos/validator",
+                    get(pos_handlers::get_validators)
+                        .route_layer(cached(cache::VALIDATORS)),
+                )
                 .route(
                     "/pos/validator/all",
-                    get(pos_handlers::get_all_validators),
+                    get(pos_handlers::get_all_validators)
+                        .route_layer(cached(cache::ALL_VALIDATORS)),
+                )
+                .route(
+                    "/pos/validator/{address}/history",
+                    get(pos_handlers::get_validator_history),
+                )
+                .route(
+                    "/pos/validator/{address}/uptime",
+                    get(pos_handlers::get_validator_uptime),
+                )
+                .route(
+                    "/pos/validator/{address}/slashes",
+                    get(pos_handlers::get_validator_slashes),
+                )
+                .route("/pos/slashes", get(pos_handlers::get_slashes))
+                .route(
+                    "/pos/delegator/{address}/summary",
+                    get(pos_handlers::get_delegator_summary),
                 )
                 .route("/pos/bond/{address}", get(pos_handlers::get_bonds))
                 .route(
@@ -72,6 +105,18 @@ impl ApplicationServer {
                     get(pos_handlers::get_withdraws),
                 )
                 .route("/pos/reward/{address}", get(pos_handlers::get_rewards))
+                .route(
+                    "/pos/reward/{address}/paginated",
+                    get(pos_handlers::get_rewards_paginated),
+                )
+                .route(
+                    "/pos/reward/{address}/export",
+                    get(pos_handlers::export_rewards),
+                )
+                .route(
+                    "/pos/reward/{address}/history",
+                    get(pos_handlers::get_reward_history),
+                )
                 .route(
                     "/pos/reward/{delegator}/{validator}/{epoch}",
                     get(pos_handlers::get_rewards_by_delegator_and_validator_and_epoch),
@@ -82,7 +127,8 @@ impl ApplicationServer {
                 )
                 .route(
                     "/gov/proposal",
-                    get(gov_handlers::get_governance_proposals),
+                    get(gov_handlers::get_governance_proposals)
+                        .route_layer(cached(cache::GOVERNANCE_PROPOSALS)),
                 )
                 .route(
                     "/gov/proposal/{id}",
@@ -108,6 +154,14 @@ impl ApplicationServer {
                     "/account/{address}",
                     get(balance_handlers::get_address_balance),
                 )
+                .route(
+                    "/account/{address}/history",
+                    get(balance_handlers::get_address_balance_history),
+                )
+                .route(
+                    "/account/{address}/info",
+                    get(account_handlers::get_account_info),
+                )
                 .route(
                     "/revealed-public-key/{address}",
                     get(pk_handlers::get_revealed_pk),
@@ -115,9 +169,14 @@ impl ApplicationServer {
                 .route("/gas/estimate", get(gas_handlers::get_gas_estimate))
                 .route(
                     "/gas-price/{token}",
-                    get(gas_handlers::get_gas_price_by_token),
+                    get(gas_handlers::get_gas_price_by_token)
+                        .route_layer(cached(cache::GAS_PRICES)),
+                )
+                .route(
+                    "/gas-price",
+                    get(gas_handlers::get_all_gas_prices)
+                        .route_layer(cached(cache::GAS_PRICES)),
                 )
-                .route("/gas-price", get(gas_handlers::get_all_gas_prices))
                 .route(
                     "/chain/wrapper/{id}",
                     get(transaction_handlers::get_wrapper_tx),
@@ -130,16 +189,29 @@ impl ApplicationServer {
                     "/chain/inner/{id}",
                     get(transaction_handlers::get_inner_tx),
                 )
+                .route(
+                    "/chain/memo",
+                    get(transaction_handlers::get_inner_txs_by_memo),
+                )
                 .route(
                     "/chain/history",
                     get(transaction_handlers::get_transaction_history),
                 )
-                .route("/chain/parameters", get(chain_handlers::get_parameters))
+                .route(
+                    "/chain/history/export",
+                    get(transaction_handlers::export_transaction_history),
+                )
+                .route(
+                    "/chain/parameters",
+                    get(chain_handlers::get_parameters)
+                        .route_layer(cached(cache::CHAIN_PARAMETERS)),
+                )
                 .route("/chain/rpc-url", get(chain_handlers::get_rpc_url))
                 .route("/chain/token", get(chain_handlers::get_tokens))
                 .route(
                     "/chain/token-supply",
-                    get(chain_handlers::get_token_supply),
+                    get(chain_handlers::get_token_supply)
+                        .route_layer(cached(cache::TOKEN_SUPPLY)),
                 )
                 .route(
                     "/chain/circulating-supply",
@@ -153,11 +225,19 @@ impl ApplicationServer {
                     "/chain/epoch/latest",
                     get(chain_handlers::get_last_processed_epoch),
                 )
+                .route(
+                    "/chain/epoch/{epoch}/estimate",
+                    get(chain_handlers::get_epoch_estimate),
+                )
                 .route("/ibc/{tx_id}/status", get(ibc_handler::get_ibc_status))
                 .route(
                     "/ibc/rate-limits",
                     get(ibc_handler::get_ibc_rate_limits),
                 )
+                .route(
+                    "/ibc/rate-limits/utilization",
+                    get(ibc_handler::get_ibc_rate_limit_utilization),
+                )
                 .route(
                     "/ibc/token-flows",
                     get(ibc_handler::get_ibc_token_flows),
@@ -166,16 +246,42 @@ impl ApplicationServer {
                     "/ibc/token-throughput/{token}",
                     get(ibc_handler::get_ibc_token_throughput),
                 )
+                .route("/ibc/channels", get(ibc_handler::get_ibc_channels))
+                .route(
+                    "/ibc/channels/{channel}/stats",
+                    get(ibc_handler::get_ibc_channel_stats),
+                )
+                .route(
+                    "/ibc/packet/{channel}/{sequence}",
+                    get(ibc_handler::get_ibc_packet),
+                )
+                .route(
+                    "/ibc/packets/{address}",
+                    get(ibc_handler::get_ibc_packets_by_address),
+                )
+                .route(
+                    "/ibc/transfers/{address}",
+                    get(ibc_handler::get_ibc_transfers_by_address),
+                )
                 .route(
                     "/pgf/payments/{proposal_id}",
                     get(pgf_service::get_pgf_payment_by_proposal_id),
                 )
+                .route("/search", get(search_handlers::search))
                 .route(
                     "/crawlers/timestamps",
                     get(crawler_state_handlers::get_crawlers_timestamps),
                 )
                 // Server sent events endpoints
                 .route("/chain/status", get(chain_handlers::chain_status))
+                // Websocket endpoints
+                .route("/ws", get(subscription_handlers::subscribe))
+                // GraphQL endpoint, with a playground on GET
+                .route(
+                    "/graphql",
+                    get(graphql_handlers::playground)
+                        .post(graphql_handlers::graphql),
+                )
                 .route(
                     "/block/height/{value}",
                     get(block_handlers::get_block_by_height),
@@ -188,14 +294,24 @@ impl ApplicationServer {
                     "/block/hash/{value}",
                     get(block_handlers::get_block_by_hash),
                 )
+                .route(
+                    "/block/{height}/transactions",
+                    get(block_handlers::get_block_transactions),
+                )
+                .route("/block", get(block_handlers::get_blocks))
+                .route("/block/latest", get(block_handlers::get_latest_blocks))
                 .route(
                     "/masp/aggregates",
-                    get(masp_handlers::get_masp_aggregates),
+                    get(masp_handlers::get_masp_aggregates)
+                        .route_layer(cached(cache::MASP_AGGREGATES)),
                 )
                 .route(
                     "/masp/rates",
                     get(masp_handlers::get_masp_rates),
                 )
+                // OpenAPI document, generated from the handlers
+                .route("/openapi.json", get(openapi_handlers::openapi))
+                .route("/docs", get(openapi_handlers::docs))
                 .route(
                     "/metrics",
                     get(|| async move { metric_handle.render() }),
@@ -222,9 +338,9 @@ impl ApplicationServer {
                     .timeout(Duration::from_secs(*HTTP_TIMEOUT))
                     .layer(cors)
                     .layer(BufferLayer::new(4096))
-                    .layer(RateLimitLayer::new(
-                        *REQ_PER_SEC,
-                        Duration::from_secs(1),
+                    .layer(axum::middleware::from_fn_with_state(
+                        rate_limiter,
+                        rate_limit::rate_limit,
                     )),
             );
 
@@ -237,10 +353,13 @@ impl ApplicationServer {
 
         let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
 
-        axum::serve(listener, router.into_make_service())
-            .with_graceful_shutdown(Self::shutdown_signal())
-            .await
-            .unwrap_or_else(|e| panic!("Server error: {}", e));
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(Self::shutdown_signal())
+        .await
+        .unwrap_or_else(|e| panic!("Server error: {}", e));
 
         Ok(())
     }
@@ -61,7 +61,13 @@ impl AppState {
     pub async fn get_cache_connection(&self) -> Option<Connection> {
         match &self.cache {
             None => None,
-            Some(cache) => Some(cache.get().await.unwrap()),
+            Some(cache) => cache
+                .get()
+                .await
+                .inspect_err(|e| {
+                    tracing::warn!("Error getting redis connection: {}", e)
+                })
+                .ok(),
         }
     }
 }
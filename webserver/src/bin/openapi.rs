@@ -0,0 +1,9 @@
+use utoipa::OpenApi;
+use webserver::openapi::ApiDoc;
+
+/// Print the OpenAPI document of the webserver, as written to `swagger.yml`
+fn main() -> anyhow::Result<()> {
+    print!("{}", ApiDoc::openapi().to_yaml()?);
+
+    Ok(())
+}
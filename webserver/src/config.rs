@@ -11,9 +11,33 @@ pub struct AppConfig {
     #[clap(long, env)]
     pub database_url: String,
 
-    #[clap(long, env)]
+    /// Requests per second allowed for each client, unlimited if unset
+    #[clap(long, env, value_parser = clap::value_parser!(u64).range(1..))]
     pub rps: Option<u64>,
 
+    /// Requests per second allowed for each client on expensive endpoints,
+    /// defaults to a tenth of `rps`
+    #[clap(long, env, value_parser = clap::value_parser!(u64).range(1..))]
+    pub expensive_rps: Option<u64>,
+
+    /// Path to a json file listing the api keys and their quotas
+    #[clap(long, env)]
+    pub api_keys_path: Option<String>,
+
+    /// Identify clients by the last X-Forwarded-For entry instead of the peer
+    /// address, when running behind a reverse proxy which appends it
+    #[clap(long, env, default_value_t = false)]
+    pub trust_forwarded_for: bool,
+
+    /// Maximum nesting depth of a GraphQL query
+    #[clap(long, env, default_value_t = 10)]
+    pub graphql_max_depth: usize,
+
+    /// Maximum complexity of a GraphQL query, each field counting for one and
+    /// paginated fields for their limit times their selection
+    #[clap(long, env, default_value_t = 5000)]
+    pub graphql_max_complexity: usize,
+
     #[clap(long, env)]
     pub tendermint_url: String,
 
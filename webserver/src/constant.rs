@@ -1 +1,18 @@
 pub const ITEM_PER_PAGE: u64 = 30;
+pub const MAX_CURSOR_LIMIT: u64 = 100;
+/// Number of rows read from the database at once when streaming exports
+pub const EXPORT_BATCH_SIZE: u64 = 500;
+/// Number of epochs the validator uptime is computed over by default
+pub const DEFAULT_UPTIME_WINDOW: u64 = 10;
+pub const MAX_UPTIME_WINDOW: u64 = 100;
+/// Number of past epochs whose observed length is used to estimate when
+/// future epochs start
+pub const EPOCH_ESTIMATE_WINDOW: i32 = 10;
+/// Number of search results returned by default
+pub const DEFAULT_SEARCH_LIMIT: u64 = 10;
+pub const MAX_SEARCH_LIMIT: u64 = 50;
+/// Minimum pg_trgm word similarity for a fuzzy search match
+pub const SEARCH_SIMILARITY_THRESHOLD: f32 = 0.3;
+/// Maximum number of blocks returned by the block list endpoints
+pub const MAX_BLOCK_RANGE: u64 = 100;
+pub const DEFAULT_LATEST_BLOCKS: u64 = 10;
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct BalanceQueryParams {
+    #[validate(range(min = 1))]
+    pub height: Option<u64>,
+    #[validate(range(min = 0))]
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct BalanceHistoryQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub token: String,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct BlockRangeQueryParams {
+    pub from_height: u64,
+    /// At most 100 blocks after `fromHeight`
+    pub to_height: u64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct LatestBlocksQueryParams {
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
+}
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
 use validator::Validate;
 
 use crate::error::gas::GasError;
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct GasEstimateQuery {
     #[validate(range(min = 1, max = 100))]
     pub bond: Option<u64>,
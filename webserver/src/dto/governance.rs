@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ProposalStatus {
     Pending,
@@ -12,7 +13,7 @@ pub enum ProposalStatus {
     ExecutedRejected,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ProposalKind {
     Default,
@@ -21,7 +22,8 @@ pub enum ProposalKind {
     PgfFunding,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ProposalQueryParams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
@@ -30,8 +32,14 @@ pub struct ProposalQueryParams {
     pub pattern: Option<String>,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ProposalVotesQueryparams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
+    /// Opaque cursor returned by a previous request, an empty value starts
+    /// cursor based pagination from the beginning
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
 }
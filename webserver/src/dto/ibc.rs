@@ -1,14 +1,45 @@
 use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct IbcRateLimit {
     pub token_address: Option<String>,
     pub throughput_limit: Option<u64>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct IbcTokenFlow {
     pub token_address: Option<String>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct IbcPacketsQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct IbcTransfersQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct IbcRateLimitUtilizationQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    pub token_address: Option<String>,
+    pub from_epoch: Option<u64>,
+    pub to_epoch: Option<u64>,
+}
@@ -1,9 +1,14 @@
+pub mod balance;
+pub mod block;
 pub mod chain;
 pub mod crawler_state;
+pub mod export;
 pub mod gas;
 pub mod governance;
 pub mod ibc;
 pub mod masp;
 pub mod pgf;
 pub mod pos;
+pub mod search;
+pub mod subscription;
 pub mod transaction;
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ValidatorStateDto {
     Consensus,
@@ -26,14 +27,14 @@ impl ValidatorStateDto {
     }
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum OrderByDto {
     Asc,
     Desc,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ValidatorSortFieldDto {
     VotingPower,
@@ -41,8 +42,9 @@ pub enum ValidatorSortFieldDto {
     Rank,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct ValidatorQueryParams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
@@ -51,37 +53,76 @@ pub struct ValidatorQueryParams {
     pub sort_order: Option<OrderByDto>,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct AllValidatorsQueryParams {
     pub state: Option<Vec<ValidatorStateDto>>,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SlashesQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ValidatorHistoryQueryParams {
+    #[validate(range(min = 0))]
+    pub from_epoch: Option<u64>,
+    #[validate(range(min = 0))]
+    pub to_epoch: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UptimeQueryParams {
+    /// Number of epochs the uptime is computed over
+    #[validate(range(min = 1, max = 100))]
+    pub window: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum MyValidatorKindDto {
     WithBonds,
     WithUnbonds,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct BondsDto {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
     #[validate(range(min = 0))]
     pub active_at: Option<i32>,
+    /// Opaque cursor returned by a previous request, an empty value starts
+    /// cursor based pagination from the beginning
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct UnbondsDto {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
     #[validate(range(min = 0))]
     pub active_at: Option<i32>,
+    /// Opaque cursor returned by a previous request, an empty value starts
+    /// cursor based pagination from the beginning
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct WithdrawsDto {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
@@ -89,8 +130,33 @@ pub struct WithdrawsDto {
     pub epoch: Option<u64>,
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct RewardHistoryDto {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    #[validate(range(min = 0))]
+    pub from_epoch: Option<u64>,
+    #[validate(range(min = 0))]
+    pub to_epoch: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct RewardsDto {
     #[validate(range(min = 1, max = 10000))]
     pub epoch: Option<u64>,
 }
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RewardsCursorDto {
+    #[validate(range(min = 1, max = 10000))]
+    pub epoch: Option<u64>,
+    /// Opaque cursor returned by a previous request, no cursor starts from
+    /// the beginning
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
+}
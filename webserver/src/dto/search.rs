@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQueryParams {
+    /// Address, transaction or block hash, block height, proposal id or
+    /// free text
+    #[validate(length(min = 1, max = 256))]
+    pub q: String,
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<u64>,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::entity::subscription::Topic;
+use crate::error::subscription::SubscriptionError;
+
+pub const MAX_TOPICS: usize = 50;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionAction {
+    Subscribe,
+    Unsubscribe,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionMessage {
+    pub action: SubscriptionAction,
+    #[validate(length(min = 1, max = "MAX_TOPICS"))]
+    pub topics: Vec<String>,
+}
+
+impl SubscriptionMessage {
+    pub fn parse(message: &str) -> Result<Self, SubscriptionError> {
+        let message = serde_json::from_str::<Self>(message)
+            .map_err(|e| SubscriptionError::InvalidMessage(e.to_string()))?;
+
+        message
+            .validate()
+            .map_err(|e| SubscriptionError::InvalidMessage(e.to_string()))?;
+
+        Ok(message)
+    }
+
+    pub fn topics(&self) -> Result<Vec<Topic>, SubscriptionError> {
+        self.topics.iter().map(|topic| topic.parse()).collect()
+    }
+}
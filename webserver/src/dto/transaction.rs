@@ -1,17 +1,54 @@
 use serde::{Deserialize, Serialize};
 use subtle_encoding::hex;
+use utoipa::IntoParams;
 use validator::Validate;
 
-use crate::entity::transaction::TransactionKind;
+use crate::entity::transaction::{
+    MemoMatch, TransactionExitStatus, TransactionHistoryFilter,
+    TransactionHistoryKind, TransactionKind,
+};
 use crate::error::transaction::TransactionError;
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct TransactionHistoryQueryParams {
     #[validate(range(min = 1, max = 10000))]
     pub page: Option<u64>,
     #[validate(length(min = 1, max = 10))]
     pub addresses: Vec<String>,
+    #[serde(default)]
+    pub kind: Vec<TransactionKind>,
+    #[serde(default)]
+    pub token: Vec<String>,
+    pub from_height: Option<u64>,
+    pub to_height: Option<u64>,
+    #[validate(range(min = 0))]
+    pub from_timestamp: Option<i64>,
+    #[validate(range(min = 0))]
+    pub to_timestamp: Option<i64>,
+    pub exit_code: Option<TransactionExitStatus>,
+    pub direction: Option<TransactionHistoryKind>,
+    /// Opaque cursor returned by a previous request, an empty value starts
+    /// cursor based pagination from the beginning
+    pub cursor: Option<String>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct MemoQueryParams {
+    #[validate(range(min = 1, max = 10000))]
+    pub page: Option<u64>,
+    /// Memo text to look for
+    #[validate(length(min = 1, max = 256))]
+    pub value: String,
+    /// Whether the memo must be equal to, or start with, the value
+    pub matching: Option<MemoMatch>,
+    /// Only return transactions recorded in the history of this address
+    pub address: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -34,8 +71,9 @@ impl TransactionIdParam {
     }
 }
 
-#[derive(Clone, Serialize, Deserialize, Validate)]
+#[derive(Clone, Serialize, Deserialize, Validate, IntoParams)]
 #[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
 pub struct TransactionMostRecentQueryParams {
     #[validate(range(min = 0, max = 1000000))]
     pub offset: Option<u64>,
@@ -46,3 +84,35 @@ pub struct TransactionMostRecentQueryParams {
     #[serde(default)]
     pub token: Vec<String>,
 }
+
+impl TransactionHistoryQueryParams {
+    pub fn filter(&self) -> Result<TransactionHistoryFilter, TransactionError> {
+        if let (Some(from), Some(to)) = (self.from_height, self.to_height) {
+            if from > to {
+                return Err(TransactionError::InvalidFilter(
+                    "fromHeight must be lower than toHeight".to_string(),
+                ));
+            }
+        }
+
+        if let (Some(from), Some(to)) = (self.from_timestamp, self.to_timestamp)
+        {
+            if from > to {
+                return Err(TransactionError::InvalidFilter(
+                    "fromTimestamp must be lower than toTimestamp".to_string(),
+                ));
+            }
+        }
+
+        Ok(TransactionHistoryFilter {
+            kinds: self.kind.clone(),
+            tokens: self.token.clone(),
+            from_height: self.from_height,
+            to_height: self.to_height,
+            from_timestamp: self.from_timestamp,
+            to_timestamp: self.to_timestamp,
+            exit_code: self.exit_code.clone(),
+            direction: self.direction.clone(),
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use orm::accounts::{AccountDb, AccountPublicKeyDb};
+use shared::id::Id;
+
+#[derive(Clone, Debug)]
+pub enum AccountKind {
+    Implicit,
+    Established,
+    Internal,
+}
+
+#[derive(Clone, Debug)]
+pub struct AccountInfo {
+    pub address: Id,
+    pub kind: AccountKind,
+    pub public_keys: Vec<String>,
+    pub threshold: Option<u64>,
+    pub vp_code_hash: Option<String>,
+    pub creation_height: Option<u64>,
+    pub last_update_height: Option<u64>,
+}
+
+impl AccountInfo {
+    pub fn established(
+        db_account: AccountDb,
+        db_public_keys: Vec<AccountPublicKeyDb>,
+    ) -> Self {
+        Self {
+            address: Id::Account(db_account.address),
+            kind: AccountKind::Established,
+            public_keys: db_public_keys
+                .into_iter()
+                .map(|public_key| public_key.public_key)
+                .collect(),
+            threshold: Some(db_account.threshold as u64),
+            vp_code_hash: db_account.vp_code_hash,
+            creation_height: db_account
+                .creation_height
+                .map(|height| height as u64),
+            last_update_height: Some(db_account.last_update_height as u64),
+        }
+    }
+
+    /// Implicit accounts are controlled by the single key they are derived
+    /// from, known once it is revealed
+    pub fn implicit(address: String, public_key: Option<String>) -> Self {
+        Self {
+            address: Id::Account(address),
+            kind: AccountKind::Implicit,
+            public_keys: public_key.into_iter().collect(),
+            threshold: Some(1),
+            vp_code_hash: None,
+            creation_height: None,
+            last_update_height: None,
+        }
+    }
+
+    pub fn internal(address: String) -> Self {
+        Self {
+            address: Id::Account(address),
+            kind: AccountKind::Internal,
+            public_keys: vec![],
+            threshold: None,
+            vp_code_hash: None,
+            creation_height: None,
+            last_update_height: None,
+        }
+    }
+}
@@ -1,10 +1,114 @@
+use chrono::{DateTime, NaiveDateTime};
 use shared::balance::Amount;
 use shared::id::Id;
 use shared::token::Token;
 
+use crate::error::balance::BalanceError;
+
 #[derive(Debug, Clone)]
 pub struct Balance {
     pub owner: Id,
     pub token: Token,
     pub amount: Amount,
 }
+
+#[derive(Debug, Clone)]
+pub struct BalanceChange {
+    pub owner: Id,
+    pub token: Token,
+    pub amount: Amount,
+    pub height: u64,
+    pub timestamp: Option<chrono::NaiveDateTime>,
+}
+
+/// Point in time at which balances are queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceAt {
+    Latest,
+    Height(i32),
+    Timestamp(NaiveDateTime),
+}
+
+impl BalanceAt {
+    pub fn new(
+        height: Option<u64>,
+        timestamp: Option<i64>,
+    ) -> Result<Self, BalanceError> {
+        match (height, timestamp) {
+            (Some(_), Some(_)) => Err(BalanceError::InvalidQuery(
+                "height and timestamp are mutually exclusive".to_string(),
+            )),
+            (Some(height), None) => {
+                i32::try_from(height).map(Self::Height).map_err(|_| {
+                    BalanceError::InvalidQuery(format!(
+                        "height {} is out of range",
+                        height
+                    ))
+                })
+            }
+            (None, Some(timestamp)) => DateTime::from_timestamp(timestamp, 0)
+                .map(|timestamp| Self::Timestamp(timestamp.naive_utc()))
+                .ok_or_else(|| {
+                    BalanceError::InvalidQuery(format!(
+                        "timestamp {} is out of range",
+                        timestamp
+                    ))
+                }),
+            (None, None) => Ok(Self::Latest),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latest_without_height_or_timestamp() {
+        assert_eq!(BalanceAt::new(None, None).unwrap(), BalanceAt::Latest);
+    }
+
+    #[test]
+    fn height_and_timestamp_are_exclusive() {
+        assert!(matches!(
+            BalanceAt::new(Some(10), Some(1_700_000_000)),
+            Err(BalanceError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn height_is_bounded() {
+        assert_eq!(
+            BalanceAt::new(Some(10), None).unwrap(),
+            BalanceAt::Height(10)
+        );
+        assert_eq!(
+            BalanceAt::new(Some(i32::MAX as u64), None).unwrap(),
+            BalanceAt::Height(i32::MAX)
+        );
+        assert!(matches!(
+            BalanceAt::new(Some(i32::MAX as u64 + 1), None),
+            Err(BalanceError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            BalanceAt::new(Some(u64::MAX), None),
+            Err(BalanceError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn timestamp_is_bounded() {
+        assert_eq!(
+            BalanceAt::new(None, Some(1_700_000_000)).unwrap(),
+            BalanceAt::Timestamp(
+                DateTime::from_timestamp(1_700_000_000, 0)
+                    .unwrap()
+                    .naive_utc()
+            )
+        );
+        assert!(matches!(
+            BalanceAt::new(None, Some(i64::MAX)),
+            Err(BalanceError::InvalidQuery(_))
+        ));
+    }
+}
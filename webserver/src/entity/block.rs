@@ -1,6 +1,12 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
 use orm::blocks::BlockDb;
+use orm::transactions::WrapperTransactionDb;
 use shared::id::Id;
 
+use crate::entity::transaction::{WrapperTransaction, wrapper_fee};
+
 #[derive(Debug, Clone)]
 pub struct Block {
     pub height: u64,
@@ -23,3 +29,40 @@ impl Block {
         }
     }
 }
+
+/// Totals over the wrapper transactions of a block
+#[derive(Debug, Clone, Default)]
+pub struct BlockAggregates {
+    pub tx_count: u64,
+    pub gas_used: u64,
+    /// Fees paid, in raw units, by fee token address
+    pub fees: BTreeMap<String, BigDecimal>,
+}
+
+impl BlockAggregates {
+    pub fn from_db(wrappers: &[WrapperTransactionDb]) -> Self {
+        wrappers
+            .iter()
+            .fold(Self::default(), |mut aggregates, wrapper| {
+                aggregates.tx_count += 1;
+                aggregates.gas_used +=
+                    wrapper.gas_used.unwrap_or_default() as u64;
+                if let Some(fee) = wrapper_fee(wrapper) {
+                    *aggregates
+                        .fees
+                        .entry(wrapper.fee_token.clone())
+                        .or_default() += fee;
+                }
+                aggregates
+            })
+    }
+}
+
+/// A block along with its parent, wrapper transactions and their totals
+#[derive(Debug, Clone)]
+pub struct BlockDetails {
+    pub block: Block,
+    pub prev_block: Option<Block>,
+    pub transactions: Vec<WrapperTransaction>,
+    pub aggregates: BlockAggregates,
+}
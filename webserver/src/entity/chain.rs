@@ -1,4 +1,8 @@
+use std::collections::BTreeMap;
+
 use bigdecimal::ToPrimitive;
+use chrono::NaiveDateTime;
+use orm::crawler_state::ChainCrawlerStateDb;
 use orm::parameters::ParametersDb;
 use serde_json::Value as SerdeJSONValue;
 use shared::id::Id;
@@ -64,3 +68,263 @@ pub struct TokenSupply {
 pub struct CirculatingSupply {
     pub circulating_supply: String,
 }
+
+#[derive(Clone, Debug)]
+pub struct EpochEstimate {
+    pub epoch: u64,
+    /// Unix timestamp at which the epoch starts
+    pub start_time: i64,
+    /// False when the epoch already started and its first block is indexed
+    pub estimated: bool,
+}
+
+/// Estimates when epochs start. Epoch lengths are averaged from the epochs
+/// already indexed, falling back to the chain parameters when not enough
+/// epochs were observed.
+#[derive(Clone, Debug)]
+pub struct EpochTimeEstimator {
+    pub current_epoch: i32,
+    /// Unix timestamp of the last processed block
+    pub now: i64,
+    /// Expected duration of an epoch, in seconds
+    pub epoch_duration: i64,
+    current_epoch_start: i64,
+    observed_starts: BTreeMap<i32, i64>,
+}
+
+impl EpochTimeEstimator {
+    /// `epoch_starts` are the timestamps of the first indexed block of the
+    /// most recent epochs. The earliest one is ignored as the indexer might
+    /// have started in the middle of it.
+    pub fn new(
+        chain_state: &ChainCrawlerStateDb,
+        parameters: &ParametersDb,
+        epoch_starts: Vec<(i32, NaiveDateTime)>,
+    ) -> Self {
+        let mut observed_starts = epoch_starts
+            .into_iter()
+            .map(|(epoch, timestamp)| (epoch, timestamp.and_utc().timestamp()))
+            .collect::<BTreeMap<_, _>>();
+        observed_starts.pop_first();
+
+        let observed_durations = observed_starts
+            .iter()
+            .zip(observed_starts.iter().skip(1))
+            .filter(|((epoch, _), (next_epoch, _))| *next_epoch - *epoch == 1)
+            .map(|((_, start), (_, next_start))| next_start - start)
+            .collect::<Vec<_>>();
+
+        let epoch_duration = if observed_durations.is_empty() {
+            Self::parameters_epoch_duration(parameters)
+        } else {
+            observed_durations.iter().sum::<i64>()
+                / observed_durations.len() as i64
+        };
+
+        let now = chain_state.timestamp.and_utc().timestamp();
+        let current_epoch = chain_state.last_processed_epoch;
+        let current_epoch_start = observed_starts
+            .get(&current_epoch)
+            .copied()
+            .unwrap_or_else(|| {
+                let blocks_in_epoch = chain_state.last_processed_block
+                    - chain_state.first_block_in_epoch;
+                now - i64::from(blocks_in_epoch)
+                    * i64::from(parameters.max_block_time)
+            });
+
+        Self {
+            current_epoch,
+            now,
+            epoch_duration,
+            current_epoch_start,
+            observed_starts,
+        }
+    }
+
+    /// An epoch ends once both its minimum duration and minimum number of
+    /// blocks are reached, the switch then happens after a few more blocks
+    fn parameters_epoch_duration(parameters: &ParametersDb) -> i64 {
+        let block_time = i64::from(parameters.max_block_time);
+        let min_duration = i64::from(parameters.min_duration)
+            .max(i64::from(parameters.min_num_of_blocks) * block_time);
+
+        min_duration
+            + i64::from(parameters.epoch_switch_blocks_delay) * block_time
+    }
+
+    pub fn estimate(&self, epoch: i32) -> EpochEstimate {
+        if let Some(start_time) = self.observed_starts.get(&epoch) {
+            return EpochEstimate {
+                epoch: epoch as u64,
+                start_time: *start_time,
+                estimated: false,
+            };
+        }
+
+        let epochs_ahead = i64::from(epoch) - i64::from(self.current_epoch);
+        let start_time = if epochs_ahead > 0 {
+            // The next epoch can not start before the last processed block,
+            // even when the current one is running late
+            let next_epoch_start =
+                (self.current_epoch_start + self.epoch_duration).max(self.now);
+            next_epoch_start + (epochs_ahead - 1) * self.epoch_duration
+        } else {
+            self.current_epoch_start + epochs_ahead * self.epoch_duration
+        };
+
+        EpochEstimate {
+            epoch: epoch as u64,
+            start_time,
+            estimated: true,
+        }
+    }
+
+    pub fn start_time(&self, epoch: i32) -> i64 {
+        self.estimate(epoch).start_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bigdecimal::BigDecimal;
+    use chrono::DateTime;
+
+    use super::*;
+
+    const EPOCH_DURATION: i64 = 600;
+
+    fn timestamp(seconds: i64) -> NaiveDateTime {
+        DateTime::from_timestamp(seconds, 0).unwrap().naive_utc()
+    }
+
+    fn parameters() -> ParametersDb {
+        ParametersDb {
+            id: 0,
+            unbonding_length: 2,
+            pipeline_length: 2,
+            epochs_per_year: 52560,
+            min_num_of_blocks: 4,
+            min_duration: 60,
+            max_block_time: 10,
+            apr: "0".to_string(),
+            native_token_address: "tnam".to_string(),
+            chain_id: "chain".to_string(),
+            genesis_time: 0,
+            checksums: serde_json::Value::Null,
+            epoch_switch_blocks_delay: 2,
+            cubic_slashing_window_length: 1,
+            duplicate_vote_min_slash_rate: BigDecimal::from(0),
+            light_client_attack_min_slash_rate: BigDecimal::from(0),
+        }
+    }
+
+    /// Epochs 10 to 13 started every `EPOCH_DURATION` seconds, the last
+    /// processed block is 100 seconds into epoch 13
+    fn estimator() -> EpochTimeEstimator {
+        let chain_state = ChainCrawlerStateDb {
+            last_processed_block: 110,
+            last_processed_epoch: 13,
+            first_block_in_epoch: 100,
+            timestamp: timestamp(3 * EPOCH_DURATION + 100),
+        };
+        let epoch_starts = (10..=13)
+            .map(|epoch| {
+                (epoch, timestamp(i64::from(epoch - 10) * EPOCH_DURATION))
+            })
+            .collect();
+
+        EpochTimeEstimator::new(&chain_state, &parameters(), epoch_starts)
+    }
+
+    #[test]
+    fn past_epochs_use_observed_starts() {
+        let estimator = estimator();
+
+        let estimate = estimator.estimate(12);
+        assert!(!estimate.estimated);
+        assert_eq!(estimate.start_time, 2 * EPOCH_DURATION);
+        assert_eq!(estimator.epoch_duration, EPOCH_DURATION);
+
+        // Epochs before the indexed window are extrapolated backwards
+        let estimate = estimator.estimate(3);
+        assert!(estimate.estimated);
+        assert_eq!(estimate.start_time, -7 * EPOCH_DURATION);
+    }
+
+    #[test]
+    fn current_epoch_uses_its_first_block() {
+        let estimate = estimator().estimate(13);
+
+        assert!(!estimate.estimated);
+        assert_eq!(estimate.epoch, 13);
+        assert_eq!(estimate.start_time, 3 * EPOCH_DURATION);
+    }
+
+    #[test]
+    fn future_epochs_are_extrapolated() {
+        let estimator = estimator();
+
+        let estimate = estimator.estimate(14);
+        assert!(estimate.estimated);
+        assert_eq!(estimate.start_time, 4 * EPOCH_DURATION);
+
+        let estimate = estimator.estimate(20);
+        assert_eq!(estimate.start_time, 10 * EPOCH_DURATION);
+    }
+
+    #[test]
+    fn late_epochs_do_not_start_in_the_past() {
+        let chain_state = ChainCrawlerStateDb {
+            last_processed_block: 200,
+            last_processed_epoch: 13,
+            first_block_in_epoch: 100,
+            timestamp: timestamp(3 * EPOCH_DURATION + 1000),
+        };
+        let epoch_starts = (10..=13)
+            .map(|epoch| {
+                (epoch, timestamp(i64::from(epoch - 10) * EPOCH_DURATION))
+            })
+            .collect();
+        let estimator =
+            EpochTimeEstimator::new(&chain_state, &parameters(), epoch_starts);
+
+        let estimate = estimator.estimate(15);
+        assert_eq!(estimate.start_time, estimator.now + EPOCH_DURATION);
+    }
+
+    #[test]
+    fn extreme_epochs_do_not_overflow() {
+        let estimator = estimator();
+
+        let estimate = estimator.estimate(i32::MAX);
+        assert_eq!(
+            estimate.start_time,
+            4 * EPOCH_DURATION + i64::from(i32::MAX - 14) * EPOCH_DURATION
+        );
+
+        let estimate = estimator.estimate(i32::MIN);
+        assert_eq!(
+            estimate.start_time,
+            3 * EPOCH_DURATION + (i64::from(i32::MIN) - 13) * EPOCH_DURATION
+        );
+    }
+
+    #[test]
+    fn parameters_are_used_without_observed_epochs() {
+        let chain_state = ChainCrawlerStateDb {
+            last_processed_block: 103,
+            last_processed_epoch: 13,
+            first_block_in_epoch: 100,
+            timestamp: timestamp(1000),
+        };
+        let estimator =
+            EpochTimeEstimator::new(&chain_state, &parameters(), vec![]);
+
+        // max(60, 4 * 10) + 2 * 10
+        assert_eq!(estimator.epoch_duration, 80);
+        let estimate = estimator.estimate(13);
+        assert!(estimate.estimated);
+        assert_eq!(estimate.start_time, 1000 - 3 * 10);
+    }
+}
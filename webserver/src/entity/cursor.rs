@@ -0,0 +1,142 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use subtle_encoding::hex;
+
+use crate::constant::{ITEM_PER_PAGE, MAX_CURSOR_LIMIT};
+
+/// Position of the last item of a page in a keyset paginated list, handed to
+/// clients as an opaque string.
+pub trait Cursor: Serialize + DeserializeOwned {
+    fn encode(&self) -> String {
+        let json =
+            serde_json::to_vec(self).expect("Cursor should serialize to json");
+
+        String::from_utf8(hex::encode(json)).expect("Hex should be utf8")
+    }
+
+    /// Decode a cursor received from a client. An empty cursor starts from
+    /// the beginning of the list. Only cursors exactly as encoded by
+    /// `encode` are accepted.
+    fn decode(value: &str) -> Result<Option<Self>, String> {
+        if value.is_empty() {
+            return Ok(None);
+        }
+
+        hex::decode(value)
+            .ok()
+            .and_then(|json| serde_json::from_slice::<Self>(&json).ok())
+            .filter(|cursor| cursor.encode() == value)
+            .map(Some)
+            .ok_or_else(|| format!("Invalid cursor: {}", value))
+    }
+}
+
+/// Cursor for lists ordered by primary key
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IdCursor {
+    pub id: i32,
+}
+
+impl Cursor for IdCursor {}
+
+/// Cursor for the rewards of an epoch, which pins the epoch the first page was
+/// read at
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RewardCursor {
+    pub epoch: i32,
+    pub id: i32,
+}
+
+impl Cursor for RewardCursor {}
+
+/// Cursor for the transaction history, ordered by most recent block first
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HistoryCursor {
+    pub height: i32,
+    pub inner_tx_id: String,
+    pub id: i32,
+}
+
+impl Cursor for HistoryCursor {}
+
+/// Number of items per page requested by a client, within allowed bounds
+pub fn cursor_limit(limit: Option<u64>) -> u64 {
+    limit.unwrap_or(ITEM_PER_PAGE).clamp(1, MAX_CURSOR_LIMIT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history_cursor() -> HistoryCursor {
+        HistoryCursor {
+            height: 1200,
+            inner_tx_id: "6f0a6cb0e12a5dd2b1e4d7e8cc6b8a8d".to_string(),
+            id: 42,
+        }
+    }
+
+    fn hex_json(json: &str) -> String {
+        String::from_utf8(hex::encode(json)).unwrap()
+    }
+
+    #[test]
+    fn cursors_round_trip() {
+        let cursor = history_cursor();
+        assert_eq!(
+            HistoryCursor::decode(&cursor.encode()).unwrap(),
+            Some(cursor)
+        );
+
+        let cursor = RewardCursor { epoch: 7, id: 3 };
+        assert_eq!(
+            RewardCursor::decode(&cursor.encode()).unwrap(),
+            Some(cursor)
+        );
+
+        let cursor = IdCursor { id: i32::MAX };
+        assert_eq!(IdCursor::decode(&cursor.encode()).unwrap(), Some(cursor));
+    }
+
+    #[test]
+    fn empty_cursors_start_from_the_beginning() {
+        assert_eq!(HistoryCursor::decode("").unwrap(), None);
+    }
+
+    #[test]
+    fn tampered_cursors_are_rejected() {
+        let encoded = history_cursor().encode();
+
+        // Not hex, truncated, or hex of something else than json
+        assert!(HistoryCursor::decode("not a cursor").is_err());
+        assert!(HistoryCursor::decode(&encoded[..encoded.len() - 1]).is_err());
+        assert!(HistoryCursor::decode(&encoded[..encoded.len() - 2]).is_err());
+        assert!(HistoryCursor::decode(&hex_json("[1, 2, 3]")).is_err());
+
+        // Valid json which was not produced by `encode`
+        assert!(HistoryCursor::decode(&encoded.to_uppercase()).is_err());
+        assert!(
+            IdCursor::decode(&hex_json(r#"{ "id": 42 }"#)).is_err(),
+            "non canonical json"
+        );
+        assert!(IdCursor::decode(&hex_json(r#"{"id":"42"}"#)).is_err());
+        assert!(IdCursor::decode(&hex_json(r#"{"id":4294967296}"#)).is_err());
+
+        // Cursors of another list
+        assert!(
+            IdCursor::decode(&RewardCursor { epoch: 7, id: 3 }.encode())
+                .is_err()
+        );
+        assert!(HistoryCursor::decode(&IdCursor { id: 3 }.encode()).is_err());
+    }
+
+    #[test]
+    fn limits_are_bounded() {
+        assert_eq!(cursor_limit(None), ITEM_PER_PAGE);
+        assert_eq!(cursor_limit(Some(0)), 1);
+        assert_eq!(cursor_limit(Some(u64::MAX)), MAX_CURSOR_LIMIT);
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use orm::pos_rewards::PosRewardAccrualDb;
+use orm::transactions::{
+    InnerTransactionDb, TransactionHistoryDb, TransactionHistoryKindDb,
+    WrapperTransactionDb,
+};
+use orm::validators::ValidatorDb;
+use serde_json::Value;
+use shared::balance::{Amount, DenominatedAmount, Denomination};
+
+use crate::entity::transaction::{TransactionKind, wrapper_fee};
+
+pub const REWARD_KIND: &str = "reward";
+
+/// A single line of an address statement, either an inner transaction
+/// touching the address or a reward accrued by it
+#[derive(Clone, Debug)]
+pub struct ExportRow {
+    pub timestamp: Option<NaiveDateTime>,
+    pub height: Option<u64>,
+    pub tx_id: Option<String>,
+    pub kind: String,
+    pub token: Option<String>,
+    /// Amount denominated with the token denomination
+    pub amount: Option<String>,
+    /// Fee of the wrapper transaction, only set when paid by the address
+    pub fee: Option<String>,
+    pub fee_token: Option<String>,
+    /// Other parties of the transaction, separated by `;`
+    pub counterparty: Option<String>,
+}
+
+impl ExportRow {
+    /// The amount is left in base units, see [`ExportRow::denominate`]
+    pub fn from_history(
+        history: TransactionHistoryDb,
+        inner: InnerTransactionDb,
+        wrapper: WrapperTransactionDb,
+        timestamp: Option<NaiveDateTime>,
+        native_token: &str,
+    ) -> Self {
+        let kind = serde_json::to_value(TransactionKind::from(inner.kind))
+            .ok()
+            .and_then(|kind| kind.as_str().map(str::to_string))
+            .unwrap_or_default();
+
+        let data = inner
+            .data
+            .as_deref()
+            .and_then(|data| serde_json::from_str::<Value>(data).ok());
+
+        let (token, amount, counterparty) = match data {
+            Some(data) => {
+                movement(&data, &history.target, &history.kind, native_token)
+            }
+            None => (None, None, None),
+        };
+
+        let (fee, fee_token) = if wrapper.fee_payer == history.target {
+            (fee(&wrapper), Some(wrapper.fee_token))
+        } else {
+            (None, None)
+        };
+
+        Self {
+            timestamp,
+            height: Some(wrapper.block_height as u64),
+            tx_id: Some(inner.id),
+            kind,
+            token,
+            amount,
+            fee,
+            fee_token,
+            counterparty,
+        }
+    }
+
+    /// Rewards are dated with the first block of the epoch they were
+    /// computed at
+    pub fn from_accrual(
+        accrual: PosRewardAccrualDb,
+        validator: ValidatorDb,
+        epoch_start: Option<(i32, Option<NaiveDateTime>)>,
+        native_token: &str,
+    ) -> Self {
+        let (height, timestamp) = epoch_start
+            .map(|(height, timestamp)| (Some(height as u64), timestamp))
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            height,
+            tx_id: None,
+            kind: REWARD_KIND.to_string(),
+            token: Some(native_token.to_string()),
+            amount: Some(
+                DenominatedAmount::native(Amount::from(accrual.raw_amount))
+                    .to_string_precise(),
+            ),
+            fee: None,
+            fee_token: None,
+            counterparty: Some(validator.namada_address),
+        }
+    }
+
+    /// Tokens moved in `rows`
+    pub fn tokens(rows: &[Self]) -> HashSet<String> {
+        rows.iter().filter_map(|row| row.token.clone()).collect()
+    }
+
+    /// Denominate the amount with the denomination of its token, amounts of
+    /// tokens without a known denomination are left in base units
+    pub fn denominate(
+        mut self,
+        denominations: &HashMap<String, Option<Denomination>>,
+    ) -> Self {
+        let denomination = self
+            .token
+            .as_ref()
+            .and_then(|token| denominations.get(token).copied().flatten());
+
+        if let (Some(amount), Some(denomination)) =
+            (self.amount.as_ref(), denomination)
+        {
+            if let Ok(amount) = BigDecimal::from_str(amount) {
+                self.amount = Some(
+                    DenominatedAmount::from((
+                        Amount::from(amount),
+                        denomination,
+                    ))
+                    .to_string_precise(),
+                );
+            }
+        }
+
+        self
+    }
+}
+
+/// Extract the token, raw amount and counterparties of the funds moved by
+/// `address` in a transfer, bond or unbond
+fn movement(
+    data: &Value,
+    address: &str,
+    kind: &TransactionHistoryKindDb,
+    native_token: &str,
+) -> (Option<String>, Option<String>, Option<String>) {
+    if let Some(movement) = transfer_movement(data, address, kind) {
+        return movement;
+    }
+
+    match (data.get("validator"), data.get("amount")) {
+        (Some(validator), Some(amount)) => (
+            Some(native_token.to_string()),
+            string(Some(amount)),
+            string(Some(validator)),
+        ),
+        _ => (None, None, None),
+    }
+}
+
+/// Extract the token, raw amount and counterparties of the funds moved by
+/// `address` in a transfer, if `data` is one
+pub fn transfer_movement(
+    data: &Value,
+    address: &str,
+    kind: &TransactionHistoryKindDb,
+) -> Option<(Option<String>, Option<String>, Option<String>)> {
+    // IBC transfers are stored as a (token, transfer) tuple
+    let transfer = match data {
+        Value::Array(values) => values.get(1).unwrap_or(data),
+        _ => data,
+    };
+
+    let (Some(Value::Array(sources)), Some(Value::Array(targets))) =
+        (transfer.get("sources"), transfer.get("targets"))
+    else {
+        return None;
+    };
+
+    let (own, others) = match kind {
+        TransactionHistoryKindDb::Sent => (sources, targets),
+        TransactionHistoryKindDb::Received => (targets, sources),
+    };
+
+    let entry = own
+        .iter()
+        .find(|entry| {
+            entry.get("owner").and_then(Value::as_str) == Some(address)
+        })
+        .or_else(|| own.first());
+
+    let counterparties = others
+        .iter()
+        .filter_map(|entry| entry.get("owner").and_then(Value::as_str))
+        .filter(|owner| *owner != address)
+        .collect::<BTreeSet<_>>();
+
+    Some((
+        entry.and_then(|entry| string(entry.get("token"))),
+        entry.and_then(|entry| string(entry.get("amount"))),
+        (!counterparties.is_empty())
+            .then(|| counterparties.into_iter().collect::<Vec<_>>().join(";")),
+    ))
+}
+
+fn fee(wrapper: &WrapperTransactionDb) -> Option<String> {
+    wrapper_fee(wrapper).map(|fee| fee.normalized().to_plain_string())
+}
+
+fn string(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
@@ -1,4 +1,3 @@
-use orm::crawler_state::ChainCrawlerStateDb;
 use orm::governance_proposal::{
     GovernanceProposalKindDb, GovernanceProposalNoDataDb,
     GovernanceProposalResultDb, GovernanceProposalTallyTypeDb,
@@ -6,7 +5,7 @@ use orm::governance_proposal::{
 use orm::governance_votes::{GovernanceProposalVoteDb, GovernanceVoteKindDb};
 use shared::id::Id;
 
-use crate::response::utils::{epoch_progress, time_between_epochs};
+use crate::entity::chain::EpochTimeEstimator;
 
 #[derive(Clone, Debug)]
 pub enum ProposalType {
@@ -124,46 +123,13 @@ pub struct ProposalVote {
 impl Proposal {
     pub fn from_db(
         value: GovernanceProposalNoDataDb,
-        chain_state: &ChainCrawlerStateDb,
-        max_block_time: i32,
-        min_duration: i32,
+        epoch_estimator: &EpochTimeEstimator,
     ) -> Self {
-        let blocks_per_epoch = min_duration / max_block_time;
-
-        let epoch_progress = epoch_progress(
-            chain_state.last_processed_block,
-            chain_state.first_block_in_epoch,
-            blocks_per_epoch,
-        );
-
-        let to_start = time_between_epochs(
-            blocks_per_epoch,
-            epoch_progress,
-            chain_state.last_processed_epoch,
-            value.start_epoch,
-            min_duration,
-        );
-
-        let to_end = time_between_epochs(
-            blocks_per_epoch,
-            epoch_progress,
-            chain_state.last_processed_epoch,
-            value.end_epoch,
-            min_duration,
-        );
-
-        let to_activation = time_between_epochs(
-            blocks_per_epoch,
-            epoch_progress,
-            chain_state.last_processed_epoch,
-            value.activation_epoch,
-            min_duration,
-        );
-
-        let time_now = chain_state.timestamp.and_utc().timestamp();
-        let start_time = time_now + i64::from(to_start);
-        let end_time = time_now + i64::from(to_end);
-        let activation_time = time_now + i64::from(to_activation);
+        let time_now = epoch_estimator.now;
+        let start_time = epoch_estimator.start_time(value.start_epoch);
+        let end_time = epoch_estimator.start_time(value.end_epoch);
+        let activation_time =
+            epoch_estimator.start_time(value.activation_epoch);
 
         Self {
             id: value.id as u64,
@@ -1,5 +1,18 @@
+use bigdecimal::BigDecimal;
+use orm::ibc::{
+    IbcAckStatusDb, IbcChannelDb, IbcChannelStateDb, IbcClientDb,
+    IbcConnectionDb, IbcPacketDb, IbcPacketDirectionDb,
+};
+use orm::transactions::{
+    InnerTransactionDb, TransactionHistoryDb, TransactionHistoryKindDb,
+};
+use serde_json::Value;
+use shared::ibc::{IbcRateLimitUsage, split_denom_trace};
 use shared::id::Id;
 
+use crate::entity::export::transfer_movement;
+use crate::entity::transaction::{TransactionExitStatus, TransactionKind};
+
 #[derive(Clone, Debug)]
 pub enum IbcAckStatus {
     Success,
@@ -8,6 +21,17 @@ pub enum IbcAckStatus {
     Unknown,
 }
 
+impl From<IbcAckStatusDb> for IbcAckStatus {
+    fn from(value: IbcAckStatusDb) -> Self {
+        match value {
+            IbcAckStatusDb::Unknown => Self::Unknown,
+            IbcAckStatusDb::Timeout => Self::Timeout,
+            IbcAckStatusDb::Fail => Self::Fail,
+            IbcAckStatusDb::Success => Self::Success,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IbcAck {
     pub status: IbcAckStatus,
@@ -32,3 +56,498 @@ pub struct IbcTokenThroughput {
     pub throughput: u64,
     pub limit: u64,
 }
+
+#[derive(Clone, Debug)]
+pub enum IbcChannelState {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcChannel {
+    pub channel_id: String,
+    pub port_id: String,
+    pub counterparty_channel_id: Option<String>,
+    pub counterparty_port_id: String,
+    pub connection_id: String,
+    pub client_id: Option<String>,
+    pub counterparty_chain_id: Option<String>,
+    pub state: IbcChannelState,
+    pub last_update_height: u64,
+    /// Height at which the light client of the counterparty was last updated
+    pub client_update_height: Option<u64>,
+    /// Latest counterparty height known to the light client
+    pub client_consensus_height: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IbcPacketCounts {
+    pub success: u64,
+    pub fail: u64,
+    pub timeout: u64,
+    /// Sent packets that were neither acknowledged nor timed out yet
+    pub pending: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcChannelTokenVolume {
+    pub token_address: Id,
+    pub trace: String,
+    pub deposit: BigDecimal,
+    pub withdraw: BigDecimal,
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcChannelStats {
+    pub channel: IbcChannel,
+    pub packets: IbcPacketCounts,
+    pub volume: Vec<IbcChannelTokenVolume>,
+}
+
+impl IbcChannel {
+    pub fn from_db(
+        channel: IbcChannelDb,
+        connection: Option<IbcConnectionDb>,
+        client: Option<IbcClientDb>,
+    ) -> Self {
+        Self {
+            channel_id: channel.channel_id,
+            port_id: channel.port_id,
+            counterparty_channel_id: channel.counterparty_channel_id,
+            counterparty_port_id: channel.counterparty_port_id,
+            connection_id: channel.connection_id,
+            client_id: connection.map(|connection| connection.client_id),
+            counterparty_chain_id: client
+                .as_ref()
+                .and_then(|client| client.counterparty_chain_id.clone()),
+            state: match channel.state {
+                IbcChannelStateDb::Init => IbcChannelState::Init,
+                IbcChannelStateDb::TryOpen => IbcChannelState::TryOpen,
+                IbcChannelStateDb::Open => IbcChannelState::Open,
+                IbcChannelStateDb::Closed => IbcChannelState::Closed,
+            },
+            last_update_height: channel.last_update_height as u64,
+            client_update_height: client
+                .as_ref()
+                .map(|client| client.last_update_height as u64),
+            client_consensus_height: client
+                .map(|client| client.consensus_height),
+        }
+    }
+}
+
+impl IbcPacketCounts {
+    pub fn from_db(counts: Vec<(IbcAckStatusDb, i64)>) -> Self {
+        counts
+            .into_iter()
+            .fold(Self::default(), |mut acc, (status, count)| {
+                let count = count as u64;
+                match status {
+                    IbcAckStatusDb::Success => acc.success += count,
+                    IbcAckStatusDb::Fail => acc.fail += count,
+                    IbcAckStatusDb::Timeout => acc.timeout += count,
+                    IbcAckStatusDb::Unknown => acc.pending += count,
+                }
+                acc
+            })
+    }
+
+    pub fn total(&self) -> u64 {
+        self.success + self.fail + self.timeout + self.pending
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum IbcPacketDirection {
+    Sent,
+    Received,
+}
+
+#[derive(Clone, Debug)]
+pub enum IbcRefundStatus {
+    /// The packet timed out, but nobody relayed the timeout yet, so the
+    /// tokens are still escrowed
+    AwaitingTimeout,
+    Refunded,
+}
+
+/// A transaction which moved the packet forward
+#[derive(Clone, Debug)]
+pub struct IbcPacketStep {
+    pub tx_hash: Option<String>,
+    pub block_height: u64,
+}
+
+impl IbcPacketStep {
+    fn from_db(
+        tx_hash: Option<String>,
+        block_height: Option<i32>,
+    ) -> Option<Self> {
+        block_height.map(|block_height| Self {
+            tx_hash,
+            block_height: block_height as u64,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IbcPacket {
+    pub direction: IbcPacketDirection,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: u64,
+    pub denom: Option<String>,
+    pub amount: Option<BigDecimal>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    /// Nanoseconds since the unix epoch, 0 when the packet has no timeout
+    /// timestamp
+    pub timeout_timestamp: u64,
+    pub timeout_height: String,
+    pub status: IbcAckStatus,
+    pub refund_status: Option<IbcRefundStatus>,
+    pub send: Option<IbcPacketStep>,
+    pub recv: Option<IbcPacketStep>,
+    pub ack: Option<IbcPacketStep>,
+    pub timeout: Option<IbcPacketStep>,
+}
+
+impl IbcPacket {
+    /// Build the packet as of `now`, in nanoseconds since the unix epoch
+    pub fn from_db(packet: IbcPacketDb, now: u64) -> Self {
+        let timeout_timestamp = packet.timeout_timestamp as u64;
+        let refund_status = match (&packet.direction, &packet.status) {
+            (IbcPacketDirectionDb::Sent, _) if packet.refunded => {
+                Some(IbcRefundStatus::Refunded)
+            }
+            (IbcPacketDirectionDb::Sent, IbcAckStatusDb::Unknown)
+                if timeout_timestamp != 0 && timeout_timestamp < now =>
+            {
+                Some(IbcRefundStatus::AwaitingTimeout)
+            }
+            _ => None,
+        };
+
+        Self {
+            direction: match packet.direction {
+                IbcPacketDirectionDb::Sent => IbcPacketDirection::Sent,
+                IbcPacketDirectionDb::Received => IbcPacketDirection::Received,
+            },
+            source_port: packet.source_port,
+            source_channel: packet.source_channel,
+            dest_port: packet.dest_port,
+            dest_channel: packet.dest_channel,
+            sequence: packet.sequence as u64,
+            denom: packet.denom,
+            amount: packet.amount,
+            sender: packet.sender,
+            receiver: packet.receiver,
+            memo: packet.memo,
+            timeout_timestamp,
+            timeout_height: packet.timeout_height,
+            status: IbcAckStatus::from(packet.status),
+            refund_status,
+            send: IbcPacketStep::from_db(
+                packet.send_tx_hash,
+                packet.send_block_height,
+            ),
+            recv: IbcPacketStep::from_db(
+                packet.recv_tx_hash,
+                packet.recv_block_height,
+            ),
+            ack: IbcPacketStep::from_db(
+                packet.ack_tx_hash,
+                packet.ack_block_height,
+            ),
+            timeout: IbcPacketStep::from_db(
+                packet.timeout_tx_hash,
+                packet.timeout_block_height,
+            ),
+        }
+    }
+}
+
+/// An IBC transfer in the history of an address
+#[derive(Clone, Debug)]
+pub struct IbcTransfer {
+    pub tx_id: Id,
+    pub wrapper_id: Id,
+    pub kind: TransactionKind,
+    pub exit_code: TransactionExitStatus,
+    pub block_height: u64,
+    pub direction: IbcPacketDirection,
+    pub token: Option<String>,
+    /// Denom on the chain the token originates from
+    pub base_denom: Option<String>,
+    /// Channels the token went through, empty for tokens native to this
+    /// chain
+    pub trace_path: String,
+    /// Raw amount moved by the address
+    pub amount: Option<String>,
+    /// Other parties of the transfer, separated by `;`
+    pub counterparty: Option<String>,
+    pub status: IbcAckStatus,
+    /// Channel on this chain end, when the packet is indexed
+    pub channel: Option<String>,
+    pub sequence: Option<u64>,
+}
+
+impl IbcTransfer {
+    pub fn from_db(
+        history: TransactionHistoryDb,
+        inner: InnerTransactionDb,
+        block_height: i32,
+        packet: Option<IbcPacketDb>,
+        ibc_trace: Option<String>,
+    ) -> Self {
+        let movement = inner
+            .data
+            .as_deref()
+            .and_then(|data| serde_json::from_str::<Value>(data).ok())
+            .and_then(|data| {
+                transfer_movement(&data, &history.target, &history.kind)
+            });
+        let (token, amount, counterparty) = movement.unwrap_or_default();
+
+        let direction = match history.kind {
+            TransactionHistoryKindDb::Sent => IbcPacketDirection::Sent,
+            TransactionHistoryKindDb::Received => IbcPacketDirection::Received,
+        };
+
+        let (trace_path, base_denom) = match ibc_trace {
+            Some(trace) => {
+                let (trace_path, base_denom) = split_denom_trace(&trace);
+                (trace_path, Some(base_denom))
+            }
+            None => (String::new(), token.clone()),
+        };
+
+        // The packet fills in what the transaction data leaves out, e.g. the
+        // receiver of tokens sent to another chain
+        let (counterparty, amount) = match &packet {
+            Some(packet) => (
+                counterparty.or_else(|| match direction {
+                    IbcPacketDirection::Sent => packet.receiver.clone(),
+                    IbcPacketDirection::Received => packet.sender.clone(),
+                }),
+                amount.or_else(|| {
+                    packet
+                        .amount
+                        .as_ref()
+                        .map(|amount| amount.normalized().to_plain_string())
+                }),
+            ),
+            None => (counterparty, amount),
+        };
+
+        let (status, channel, sequence) = match packet {
+            Some(packet) => (
+                IbcAckStatus::from(packet.status),
+                Some(match direction {
+                    IbcPacketDirection::Sent => packet.source_channel,
+                    IbcPacketDirection::Received => packet.dest_channel,
+                }),
+                Some(packet.sequence as u64),
+            ),
+            None => (IbcAckStatus::Unknown, None, None),
+        };
+
+        Self {
+            tx_id: Id::Hash(inner.id),
+            wrapper_id: Id::Hash(inner.wrapper_id),
+            kind: TransactionKind::from(inner.kind),
+            exit_code: TransactionExitStatus::from(inner.exit_code),
+            block_height: block_height as u64,
+            direction,
+            token,
+            base_denom,
+            trace_path,
+            amount,
+            counterparty,
+            status,
+            channel,
+            sequence,
+        }
+    }
+}
+
+/// Share of the throughput limit of a token consumed during an epoch
+#[derive(Clone, Debug)]
+pub struct IbcRateLimitUtilization {
+    pub token_address: Id,
+    pub epoch: u64,
+    pub throughput: BigDecimal,
+    pub throughput_limit: BigDecimal,
+    /// Percent of the limit consumed, `None` when the token has no limit
+    pub utilization: Option<BigDecimal>,
+}
+
+impl IbcRateLimitUtilization {
+    pub fn from_db(
+        token_address: String,
+        epoch: i32,
+        deposit: BigDecimal,
+        withdraw: BigDecimal,
+        throughput_limit: BigDecimal,
+    ) -> Self {
+        let usage = IbcRateLimitUsage {
+            address: token_address,
+            epoch: epoch as u32,
+            deposit,
+            withdraw,
+            throughput_limit,
+        };
+
+        Self {
+            throughput: usage.throughput(),
+            utilization: usage.utilization(),
+            token_address: Id::Account(usage.address),
+            epoch: usage.epoch as u64,
+            throughput_limit: usage.throughput_limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use orm::transactions::{TransactionKindDb, TransactionResultDb};
+
+    use super::*;
+
+    const ADDRESS: &str = "tnam1address";
+
+    fn history(kind: TransactionHistoryKindDb) -> TransactionHistoryDb {
+        TransactionHistoryDb {
+            id: 1,
+            inner_tx_id: "inner".to_string(),
+            target: ADDRESS.to_string(),
+            kind,
+            block_height: 10,
+        }
+    }
+
+    fn inner(data: Option<Value>) -> InnerTransactionDb {
+        InnerTransactionDb {
+            id: "inner".to_string(),
+            wrapper_id: "wrapper".to_string(),
+            kind: TransactionKindDb::IbcTransparentTransfer,
+            data: data.map(|data| data.to_string()),
+            memo: None,
+            exit_code: TransactionResultDb::Applied,
+            memo_text: None,
+            batch_index: Some(0),
+        }
+    }
+
+    fn packet() -> IbcPacketDb {
+        IbcPacketDb {
+            id: "packet".to_string(),
+            direction: IbcPacketDirectionDb::Sent,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            dest_port: "transfer".to_string(),
+            dest_channel: "channel-7".to_string(),
+            sequence: 12,
+            denom: Some("transfer/channel-7/uatom".to_string()),
+            amount: Some(BigDecimal::from(2500)),
+            sender: Some("cosmos1sender".to_string()),
+            receiver: Some("cosmos1receiver".to_string()),
+            memo: None,
+            timeout_timestamp: 0,
+            timeout_height: "0-0".to_string(),
+            status: IbcAckStatusDb::Success,
+            refunded: false,
+            send_tx_hash: Some("inner".to_string()),
+            send_block_height: Some(10),
+            recv_tx_hash: None,
+            recv_block_height: None,
+            ack_tx_hash: None,
+            ack_block_height: None,
+            timeout_tx_hash: None,
+            timeout_block_height: None,
+        }
+    }
+
+    /// IBC transfers are stored as a (token, transfer) tuple, the account on
+    /// the other chain is not part of the transfer
+    fn sent_data() -> Value {
+        serde_json::json!([
+            { "Native": { "Account": "tnam1token" } },
+            {
+                "sources": [
+                    { "owner": ADDRESS, "token": "tnam1token", "amount": "1000" }
+                ],
+                "targets": [
+                    { "owner": ADDRESS, "token": "tnam1token", "amount": "1000" }
+                ],
+            }
+        ])
+    }
+
+    #[test]
+    fn sent_transfer_takes_counterparty_from_packet() {
+        let transfer = IbcTransfer::from_db(
+            history(TransactionHistoryKindDb::Sent),
+            inner(Some(sent_data())),
+            10,
+            Some(packet()),
+            Some("transfer/channel-0/transfer/channel-7/uatom".to_string()),
+        );
+
+        assert!(matches!(transfer.direction, IbcPacketDirection::Sent));
+        assert_eq!(transfer.token.as_deref(), Some("tnam1token"));
+        assert_eq!(transfer.amount.as_deref(), Some("1000"));
+        assert_eq!(transfer.counterparty.as_deref(), Some("cosmos1receiver"));
+        assert!(matches!(transfer.status, IbcAckStatus::Success));
+        assert_eq!(transfer.channel.as_deref(), Some("channel-0"));
+        assert_eq!(transfer.sequence, Some(12));
+        assert_eq!(
+            transfer.trace_path,
+            "transfer/channel-0/transfer/channel-7"
+        );
+        assert_eq!(transfer.base_denom.as_deref(), Some("uatom"));
+    }
+
+    #[test]
+    fn received_transfer_falls_back_to_packet() {
+        let transfer = IbcTransfer::from_db(
+            history(TransactionHistoryKindDb::Received),
+            inner(None),
+            10,
+            Some(packet()),
+            None,
+        );
+
+        assert!(matches!(transfer.direction, IbcPacketDirection::Received));
+        assert_eq!(transfer.amount.as_deref(), Some("2500"));
+        assert_eq!(transfer.counterparty.as_deref(), Some("cosmos1sender"));
+        assert_eq!(transfer.channel.as_deref(), Some("channel-7"));
+        assert_eq!(transfer.trace_path, "");
+        assert_eq!(transfer.base_denom, None);
+    }
+
+    #[test]
+    fn transfer_without_packet() {
+        let transfer = IbcTransfer::from_db(
+            history(TransactionHistoryKindDb::Sent),
+            inner(Some(sent_data())),
+            10,
+            None,
+            None,
+        );
+
+        assert_eq!(transfer.amount.as_deref(), Some("1000"));
+        assert_eq!(transfer.counterparty, None);
+        assert!(matches!(transfer.status, IbcAckStatus::Unknown));
+        assert_eq!(transfer.channel, None);
+        assert_eq!(transfer.sequence, None);
+        // Tokens native to this chain are their own base denom
+        assert_eq!(transfer.trace_path, "");
+        assert_eq!(transfer.base_denom.as_deref(), Some("tnam1token"));
+    }
+}
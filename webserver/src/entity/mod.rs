@@ -1,7 +1,10 @@
+pub mod account;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod crawler;
+pub mod cursor;
+pub mod export;
 pub mod gas;
 pub mod governance;
 pub mod ibc;
@@ -9,4 +12,6 @@ pub mod masp;
 pub mod pgf;
 pub mod pk;
 pub mod pos;
+pub mod search;
+pub mod subscription;
 pub mod transaction;
@@ -1,15 +1,18 @@
 use bigdecimal::BigDecimal;
 use orm::bond::BondDb;
-use orm::crawler_state::{ChainCrawlerStateDb, EpochCrawlerStateDb};
-use orm::pos_rewards::PoSRewardDb;
+use orm::crawler_state::EpochCrawlerStateDb;
+use orm::pos_rewards::{PoSRewardDb, PosRewardAccrualDb};
+use orm::slashes::{SlashDb, SlashTypeDb};
 use orm::unbond::UnbondDb;
+use orm::validator_history::ValidatorHistoryDb;
 use orm::validators::{ValidatorDb, ValidatorStateDb};
 use serde::{Deserialize, Serialize};
 use shared::balance::Amount;
 use shared::crawler_state::ChainCrawlerState;
 use shared::id::Id;
 
-use crate::response::utils::{epoch_progress, time_between_epochs};
+use crate::constant::{DEFAULT_UPTIME_WINDOW, MAX_UPTIME_WINDOW};
+use crate::entity::chain::EpochTimeEstimator;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ValidatorState {
@@ -91,6 +94,7 @@ pub struct Withdraw {
     pub min_denom_amount: Amount,
     pub validator: ValidatorWithRank,
     pub withdraw_epoch: u64,
+    pub withdraw_time: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -122,6 +126,7 @@ impl From<ValidatorDb> for Validator {
 pub struct ValidatorWithRank {
     pub validator: Validator,
     pub rank: Option<u64>,
+    pub uptime: Option<f64>,
 }
 
 impl ValidatorWithRank {
@@ -129,10 +134,126 @@ impl ValidatorWithRank {
         Self {
             validator: Validator::from(db_validator),
             rank: rank.map(|r| r as u64),
+            uptime: None,
+        }
+    }
+}
+
+/// Stake of a delegator with a single validator
+#[derive(Clone, Debug)]
+pub struct DelegatorPosition {
+    pub validator: ValidatorWithRank,
+    pub bonded: Amount,
+    pub unclaimed_rewards: Amount,
+    pub estimated_apr: f64,
+}
+
+impl DelegatorPosition {
+    /// The rewards of a validator are shared with its delegators once the
+    /// validator commission is taken, so the apr of a position is the chain
+    /// apr reduced by that commission
+    pub fn estimated_apr(chain_apr: f64, commission: &str) -> f64 {
+        let commission = commission.parse::<f64>().unwrap_or_default();
+        chain_apr * (1.0 - commission)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DelegatorSummary {
+    pub total_bonded: Amount,
+    pub total_unbonding: Amount,
+    pub withdrawable: Amount,
+    pub unclaimed_rewards: Amount,
+    pub positions: Vec<DelegatorPosition>,
+    pub pending_unbonds: Vec<Unbond>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum SlashType {
+    DuplicateVote,
+    LightClientAttack,
+}
+
+impl From<SlashTypeDb> for SlashType {
+    fn from(value: SlashTypeDb) -> Self {
+        match value {
+            SlashTypeDb::DuplicateVote => Self::DuplicateVote,
+            SlashTypeDb::LightClientAttack => Self::LightClientAttack,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Slash {
+    pub validator: ValidatorWithRank,
+    pub infraction_epoch: u64,
+    pub processing_epoch: u64,
+    pub block_height: u64,
+    pub rate: BigDecimal,
+    pub kind: SlashType,
+}
+
+impl Slash {
+    pub fn from(db_slash: SlashDb, db_validator: ValidatorDb) -> Self {
+        Self {
+            validator: ValidatorWithRank::from(db_validator, None),
+            infraction_epoch: db_slash.infraction_epoch as u64,
+            processing_epoch: db_slash.processing_epoch as u64,
+            block_height: db_slash.block_height as u64,
+            rate: db_slash.rate,
+            kind: db_slash.slash_type.into(),
+        }
+    }
+}
+
+/// Voting power, commission and state of a validator at an epoch
+#[derive(Clone, Debug)]
+pub struct ValidatorHistory {
+    pub epoch: u64,
+    pub voting_power: u64,
+    pub max_commission: String,
+    pub commission: String,
+    pub state: ValidatorState,
+}
+
+impl From<ValidatorHistoryDb> for ValidatorHistory {
+    fn from(value: ValidatorHistoryDb) -> Self {
+        Self {
+            epoch: value.epoch as u64,
+            voting_power: value.voting_power as u64,
+            max_commission: value.max_commission,
+            commission: value.commission,
+            state: value.state.into(),
         }
     }
 }
 
+/// Blocks signed and missed by a validator over a window of epochs
+#[derive(Clone, Debug, Default)]
+pub struct ValidatorUptime {
+    pub window: u64,
+    pub from_epoch: u64,
+    pub to_epoch: u64,
+    pub signed_blocks: u64,
+    pub missed_blocks: u64,
+}
+
+impl ValidatorUptime {
+    /// Share of the blocks signed, none when the validator was not in
+    /// consensus during the window
+    pub fn uptime(&self) -> Option<f64> {
+        let total_blocks = self.signed_blocks + self.missed_blocks;
+        (total_blocks > 0)
+            .then(|| self.signed_blocks as f64 / total_blocks as f64)
+    }
+}
+
+pub fn uptime_window(window: Option<u64>) -> u64 {
+    window
+        .unwrap_or(DEFAULT_UPTIME_WINDOW)
+        .clamp(1, MAX_UPTIME_WINDOW)
+}
+
 impl From<(&BondDb, &EpochCrawlerStateDb)> for BondStatus {
     fn from((bond, status): (&BondDb, &EpochCrawlerStateDb)) -> Self {
         if bond.start <= status.last_processed_epoch {
@@ -186,45 +307,32 @@ impl Unbond {
         raw_amount: BigDecimal,
         withdraw_epoch: i32,
         db_validator: ValidatorDb,
-        chain_state: &ChainCrawlerStateDb,
-        max_block_time: i32,
-        min_duration: i32,
+        epoch_estimator: &EpochTimeEstimator,
     ) -> Self {
-        let blocks_per_epoch = min_duration / max_block_time;
-
-        let epoch_progress = epoch_progress(
-            chain_state.last_processed_block,
-            chain_state.first_block_in_epoch,
-            blocks_per_epoch,
-        );
-
-        let to_withdraw = time_between_epochs(
-            blocks_per_epoch,
-            epoch_progress,
-            chain_state.last_processed_epoch,
-            withdraw_epoch,
-            min_duration,
-        );
-
-        let time_now = chain_state.timestamp.and_utc().timestamp();
-        let withdraw_time = time_now + i64::from(to_withdraw);
+        let withdraw_time = epoch_estimator.start_time(withdraw_epoch);
 
         Self {
             min_denom_amount: raw_amount.into(),
             validator: ValidatorWithRank::from(db_validator, None),
             withdraw_epoch: withdraw_epoch as u64,
             withdraw_time: withdraw_time as u64,
-            can_withdraw: chain_state.last_processed_epoch >= withdraw_epoch,
+            can_withdraw: epoch_estimator.current_epoch >= withdraw_epoch,
         }
     }
 }
 
 impl Withdraw {
-    pub fn from(db_unbond: UnbondDb, db_validator: ValidatorDb) -> Self {
+    pub fn from(
+        db_unbond: UnbondDb,
+        db_validator: ValidatorDb,
+        epoch_estimator: &EpochTimeEstimator,
+    ) -> Self {
         Self {
             min_denom_amount: db_unbond.raw_amount.into(),
             validator: ValidatorWithRank::from(db_validator, None),
             withdraw_epoch: db_unbond.withdraw_epoch as u64,
+            withdraw_time: epoch_estimator.start_time(db_unbond.withdraw_epoch)
+                as u64,
         }
     }
 }
@@ -237,3 +345,25 @@ impl Reward {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct RewardAccrual {
+    pub validator: ValidatorWithRank,
+    pub epoch: u64,
+    pub min_denom_amount: Amount,
+    pub claimed_in_tx: Option<Id>,
+}
+
+impl RewardAccrual {
+    pub fn from(
+        db_accrual: PosRewardAccrualDb,
+        db_validator: ValidatorDb,
+    ) -> Self {
+        Self {
+            validator: ValidatorWithRank::from(db_validator, None),
+            epoch: db_accrual.epoch as u64,
+            min_denom_amount: db_accrual.raw_amount.into(),
+            claimed_in_tx: db_accrual.claimed_in_tx.map(Id::Hash),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use orm::validators::ValidatorDb;
+use shared::utils;
+
+/// How a search input is interpreted, before falling back to fuzzy matching
+#[derive(Clone, Debug)]
+pub enum SearchQuery {
+    Address(String),
+    Hash(String),
+    Number(u64),
+    Text(String),
+}
+
+impl SearchQuery {
+    pub fn classify(query: &str) -> Self {
+        let query = query.trim();
+
+        if utils::is_valid_bech32_address(query, "tnam") {
+            Self::Address(query.to_string())
+        } else if query.len() == 64
+            && query.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            Self::Hash(query.to_lowercase())
+        } else if let Ok(number) = query.parse::<u64>() {
+            Self::Number(number)
+        } else {
+            Self::Text(query.to_string())
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchResultKind {
+    Account,
+    Validator,
+    Block,
+    WrapperTransaction,
+    InnerTransaction,
+    Proposal,
+}
+
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    pub kind: SearchResultKind,
+    pub id: String,
+    pub label: Option<String>,
+    /// Between 0 and 1, exact matches score 1
+    pub score: f32,
+}
+
+impl SearchResult {
+    pub fn exact(
+        kind: SearchResultKind,
+        id: String,
+        label: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            id,
+            label,
+            score: 1.0,
+        }
+    }
+
+    pub fn from_validator(
+        validator: ValidatorDb,
+        similarities: [Option<f32>; 3],
+    ) -> Self {
+        Self {
+            kind: SearchResultKind::Validator,
+            id: validator.namada_address,
+            label: validator.name,
+            score: similarities.into_iter().flatten().fold(0.0, f32::max),
+        }
+    }
+
+    pub fn from_proposal(
+        id: i32,
+        content: &str,
+        similarity: Option<f32>,
+    ) -> Self {
+        Self {
+            kind: SearchResultKind::Proposal,
+            id: id.to_string(),
+            label: proposal_title(content),
+            score: similarity.unwrap_or_default(),
+        }
+    }
+
+    /// The score is the share of the memo covered by the query
+    pub fn from_memo(inner_tx_id: String, memo: String, query: &str) -> Self {
+        let score = (query.len() as f32 / memo.len().max(1) as f32).min(1.0);
+
+        Self {
+            kind: SearchResultKind::InnerTransaction,
+            id: inner_tx_id,
+            label: Some(memo),
+            score,
+        }
+    }
+}
+
+fn proposal_title(content: &str) -> Option<String> {
+    serde_json::from_str::<serde_json::Value>(content)
+        .ok()?
+        .get("title")?
+        .as_str()
+        .map(|title| title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use orm::validators::ValidatorStateDb;
+
+    use super::*;
+
+    #[test]
+    fn classify_query() {
+        let address = "tnam1qq6xmw8crfrprhlmajrq5c28p4dqy0nyjvtvupk5";
+        assert!(matches!(
+            SearchQuery::classify(&format!(" {address} ")),
+            SearchQuery::Address(query) if query == address
+        ));
+
+        let hash = "AB".repeat(32);
+        assert!(matches!(
+            SearchQuery::classify(&hash),
+            SearchQuery::Hash(query) if query == hash.to_lowercase()
+        ));
+
+        assert!(matches!(
+            SearchQuery::classify("42"),
+            SearchQuery::Number(42)
+        ));
+        assert!(matches!(
+            SearchQuery::classify("tnam1notanaddress"),
+            SearchQuery::Text(_)
+        ));
+        assert!(matches!(
+            SearchQuery::classify(&"ab".repeat(31)),
+            SearchQuery::Text(_)
+        ));
+    }
+
+    #[test]
+    fn validator_score_is_best_similarity() {
+        let validator = ValidatorDb {
+            id: 1,
+            namada_address: "tnam1validator".to_string(),
+            voting_power: 0,
+            max_commission: "0.1".to_string(),
+            commission: "0.05".to_string(),
+            name: Some("Awesome Validator".to_string()),
+            email: None,
+            website: None,
+            description: None,
+            discord_handle: None,
+            avatar: None,
+            state: ValidatorStateDb::Consensus,
+        };
+
+        let result = SearchResult::from_validator(
+            validator,
+            [Some(0.4), None, Some(0.7)],
+        );
+
+        assert_eq!(result.kind, SearchResultKind::Validator);
+        assert_eq!(result.label.as_deref(), Some("Awesome Validator"));
+        assert_eq!(result.score, 0.7);
+    }
+
+    #[test]
+    fn proposal_label_is_its_title() {
+        let result = SearchResult::from_proposal(
+            3,
+            r#"{"title": "Upgrade", "details": "..."}"#,
+            Some(0.5),
+        );
+        assert_eq!(result.id, "3");
+        assert_eq!(result.label.as_deref(), Some("Upgrade"));
+        assert_eq!(result.score, 0.5);
+
+        let result = SearchResult::from_proposal(4, "not json", None);
+        assert_eq!(result.label, None);
+        assert_eq!(result.score, 0.0);
+    }
+
+    #[test]
+    fn memo_score_is_share_covered_by_query() {
+        let result = SearchResult::from_memo(
+            "tx".to_string(),
+            "deposit-42".into(),
+            "dep",
+        );
+        assert_eq!(result.score, 0.3);
+
+        let result =
+            SearchResult::from_memo("tx".to_string(), "".into(), "dep");
+        assert_eq!(result.score, 1.0);
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use namada_sdk::address::Address as NamadaAddress;
+
+use crate::entity::transaction::TransactionKind;
+use crate::error::subscription::SubscriptionError;
+
+/// A topic websocket clients can subscribe to
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Topic {
+    /// Every newly indexed block
+    Block,
+    /// Inner transactions of the given kind
+    Tx(TransactionKind),
+    /// Wrapper transactions paid by or involving the given address
+    Address(String),
+    /// Creation of and votes on the given governance proposal
+    Proposal(u64),
+}
+
+impl FromStr for Topic {
+    type Err = SubscriptionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || SubscriptionError::InvalidTopic(value.to_string());
+
+        match value.split_once(':') {
+            None if value == "block" => Ok(Topic::Block),
+            Some(("tx", kind)) => serde_json::from_value(
+                serde_json::Value::String(kind.to_string()),
+            )
+            .map(Topic::Tx)
+            .map_err(|_| invalid()),
+            Some(("address", address)) => NamadaAddress::from_str(address)
+                .map(|_| Topic::Address(address.to_string()))
+                .map_err(|_| invalid()),
+            Some(("proposal", id)) => id
+                .parse::<u64>()
+                .map(Topic::Proposal)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Display for Topic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Topic::Block => write!(f, "block"),
+            Topic::Tx(kind) => {
+                let kind = serde_json::to_value(kind)
+                    .expect("Transaction kind should serialize to json");
+                write!(f, "tx:{}", kind.as_str().unwrap_or_default())
+            }
+            Topic::Address(address) => write!(f, "address:{}", address),
+            Topic::Proposal(id) => write!(f, "proposal:{}", id),
+        }
+    }
+}
+
+/// A payload published to every client subscribed to `topic`
+#[derive(Clone, Debug)]
+pub struct SubscriptionEvent {
+    pub topic: Topic,
+    pub data: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "tnam1qxgzrwqn9qny9fzd7xnlrdkf7hhj9ecyx5mv3sgw";
+
+    #[test]
+    fn topics_round_trip() {
+        let topics = [
+            Topic::Block,
+            Topic::Tx(TransactionKind::TransparentTransfer),
+            Topic::Address(ADDRESS.to_string()),
+            Topic::Proposal(42),
+        ];
+
+        for topic in topics {
+            assert_eq!(topic.to_string().parse::<Topic>().unwrap(), topic);
+        }
+        assert_eq!(
+            "tx:transparentTransfer".parse::<Topic>().unwrap(),
+            Topic::Tx(TransactionKind::TransparentTransfer)
+        );
+    }
+
+    #[test]
+    fn invalid_topics_are_rejected() {
+        for topic in [
+            "",
+            "blocks",
+            "block:1",
+            "tx:",
+            "tx:unknownKind",
+            "address:tnam1invalid",
+            "proposal:-1",
+            "proposal:one",
+            "validator:tnam1",
+        ] {
+            assert!(
+                matches!(
+                    topic.parse::<Topic>(),
+                    Err(SubscriptionError::InvalidTopic(_))
+                ),
+                "{} should be rejected",
+                topic
+            );
+        }
+    }
+}
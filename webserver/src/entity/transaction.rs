@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
 use orm::token::{IbcTokenDb, TokenDb};
 use orm::transactions::{
     InnerTransactionDb, TransactionHistoryDb, TransactionHistoryKindDb,
@@ -6,8 +9,11 @@ use orm::transactions::{
 use serde::{Deserialize, Serialize};
 use shared::id::Id;
 use shared::token::{IbcToken, Token};
+use utoipa::ToSchema;
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(
+    Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, ToSchema,
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionKind {
     TransparentTransfer,
@@ -35,6 +41,7 @@ pub enum TransactionKind {
     UnjailValidator,
     ChangeConsensusKey,
     InitAccount,
+    UpdateAccount,
     Unknown,
 }
 
@@ -73,6 +80,7 @@ impl From<TransactionKindDb> for TransactionKind {
             TransactionKindDb::UnjailValidator => Self::UnjailValidator,
             TransactionKindDb::ChangeConsensusKey => Self::ChangeConsensusKey,
             TransactionKindDb::InitAccount => Self::InitAccount,
+            TransactionKindDb::UpdateAccount => Self::UpdateAccount,
         }
     }
 }
@@ -110,10 +118,21 @@ impl From<TransactionKind> for TransactionKindDb {
             TransactionKind::UnjailValidator => Self::UnjailValidator,
             TransactionKind::ChangeConsensusKey => Self::ChangeConsensusKey,
             TransactionKind::InitAccount => Self::InitAccount,
+            TransactionKind::UpdateAccount => Self::UpdateAccount,
         }
     }
 }
 
+/// Fee paid by a wrapper transaction, in raw units of its fee token. Unknown
+/// when the gas used or its price were not recorded.
+pub fn wrapper_fee(wrapper: &WrapperTransactionDb) -> Option<BigDecimal> {
+    let amount_per_gas_unit =
+        BigDecimal::from_str(wrapper.amount_per_gas_unit.as_deref()?).ok()?;
+    let gas_used = BigDecimal::from(wrapper.gas_used?);
+
+    Some(amount_per_gas_unit * gas_used)
+}
+
 #[derive(Debug, Clone)]
 pub struct WrapperTransaction {
     pub id: Id,
@@ -128,12 +147,22 @@ pub struct WrapperTransaction {
     pub atomic: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub enum TransactionExitStatus {
     Applied,
     Rejected,
 }
 
+impl From<TransactionExitStatus> for TransactionResultDb {
+    fn from(value: TransactionExitStatus) -> Self {
+        match value {
+            TransactionExitStatus::Applied => Self::Applied,
+            TransactionExitStatus::Rejected => Self::Rejected,
+        }
+    }
+}
+
 impl From<TransactionResultDb> for TransactionExitStatus {
     fn from(value: TransactionResultDb) -> Self {
         match value {
@@ -192,6 +221,8 @@ pub struct InnerTransaction {
     pub kind: TransactionKind,
     pub data: Option<String>,
     pub memo: Option<String>,
+    /// Memo decoded to text, when it is valid UTF-8
+    pub memo_text: Option<String>,
     pub exit_code: TransactionExitStatus,
 }
 
@@ -203,17 +234,48 @@ impl From<InnerTransactionDb> for InnerTransaction {
             kind: TransactionKind::from(value.kind),
             data: value.data,
             memo: value.memo,
+            memo_text: value.memo_text,
             exit_code: TransactionExitStatus::from(value.exit_code),
         }
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum MemoMatch {
+    #[default]
+    Exact,
+    Prefix,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub enum TransactionHistoryKind {
     Received,
     Sent,
 }
 
+impl From<TransactionHistoryKind> for TransactionHistoryKindDb {
+    fn from(value: TransactionHistoryKind) -> Self {
+        match value {
+            TransactionHistoryKind::Received => Self::Received,
+            TransactionHistoryKind::Sent => Self::Sent,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TransactionHistoryFilter {
+    pub kinds: Vec<TransactionKind>,
+    pub tokens: Vec<String>,
+    pub from_height: Option<u64>,
+    pub to_height: Option<u64>,
+    pub from_timestamp: Option<i64>,
+    pub to_timestamp: Option<i64>,
+    pub exit_code: Option<TransactionExitStatus>,
+    pub direction: Option<TransactionHistoryKind>,
+}
+
 #[derive(Clone, Debug)]
 pub struct TransactionHistory {
     pub tx: InnerTransaction,
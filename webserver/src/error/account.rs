@@ -0,0 +1,27 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use thiserror::Error;
+
+use crate::response::api::ApiErrorResponse;
+
+#[derive(Error, Debug)]
+pub enum AccountError {
+    #[error("{0} is not a valid address")]
+    InvalidAddress(String),
+    #[error("Account {0} not found")]
+    NotFound(String),
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+impl IntoResponse for AccountError {
+    fn into_response(self) -> Response {
+        let status_code = match self {
+            AccountError::InvalidAddress(_) => StatusCode::BAD_REQUEST,
+            AccountError::NotFound(_) => StatusCode::NOT_FOUND,
+            AccountError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        ApiErrorResponse::send(status_code.as_u16(), Some(self.to_string()))
+    }
+}
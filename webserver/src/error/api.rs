@@ -1,6 +1,7 @@
 use axum::response::{IntoResponse, Response};
 use thiserror::Error;
 
+use super::account::AccountError;
 use super::balance::BalanceError;
 use super::block::BlockError;
 use super::chain::ChainError;
@@ -12,6 +13,7 @@ use super::masp::MaspError;
 use super::pgf::PgfError;
 use super::pos::PoSError;
 use super::revealed_pk::RevealedPkError;
+use super::search::SearchError;
 use super::transaction::TransactionError;
 
 #[derive(Error, Debug)]
@@ -40,6 +42,10 @@ pub enum ApiError {
     MaspError(#[from] MaspError),
     #[error(transparent)]
     CrawlerStateError(#[from] CrawlerStateError),
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+    #[error(transparent)]
+    SearchError(#[from] SearchError),
 }
 
 impl IntoResponse for ApiError {
@@ -57,6 +63,8 @@ impl IntoResponse for ApiError {
             ApiError::PgfError(error) => error.into_response(),
             ApiError::MaspError(error) => error.into_response(),
             ApiError::CrawlerStateError(error) => error.into_response(),
+            ApiError::AccountError(error) => error.into_response(),
+            ApiError::SearchError(error) => error.into_response(),
         }
     }
 }
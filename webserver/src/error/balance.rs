@@ -8,6 +8,10 @@ use crate::response::api::ApiErrorResponse;
 pub enum BalanceError {
     #[error("Proposal {0} not found")]
     NotFound(u64),
+    #[error("No block found at or before timestamp {0}")]
+    BlockNotFound(i64),
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -17,7 +21,10 @@ pub enum BalanceError {
 impl IntoResponse for BalanceError {
     fn into_response(self) -> Response {
         let status_code = match self {
-            BalanceError::NotFound(_) => StatusCode::NOT_FOUND,
+            BalanceError::NotFound(_) | BalanceError::BlockNotFound(_) => {
+                StatusCode::NOT_FOUND
+            }
+            BalanceError::InvalidQuery(_) => StatusCode::BAD_REQUEST,
             BalanceError::Unknown(_) | BalanceError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -8,6 +8,8 @@ use crate::response::api::ApiErrorResponse;
 pub enum BlockError {
     #[error("Block not found error at {0}: {1}")]
     NotFound(String, String),
+    #[error("Invalid range: {0}")]
+    InvalidRange(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -21,6 +23,7 @@ impl IntoResponse for BlockError {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
             BlockError::NotFound(_, _) => StatusCode::NOT_FOUND,
+            BlockError::InvalidRange(_) => StatusCode::BAD_REQUEST,
         };
 
         ApiErrorResponse::send(status_code.as_u16(), Some(self.to_string()))
@@ -6,6 +6,8 @@ use crate::response::api::ApiErrorResponse;
 
 #[derive(Error, Debug)]
 pub enum ChainError {
+    #[error("Invalid epoch: {0}")]
+    InvalidEpoch(u64),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -15,6 +17,7 @@ pub enum ChainError {
 impl IntoResponse for ChainError {
     fn into_response(self) -> Response {
         let status_code = match self {
+            ChainError::InvalidEpoch(_) => StatusCode::BAD_REQUEST,
             ChainError::Unknown(_) | ChainError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -8,6 +8,10 @@ use crate::response::api::ApiErrorResponse;
 pub enum IbcError {
     #[error("Revealed public key {0} not found")]
     NotFound(u64),
+    #[error("IBC channel {0} not found")]
+    ChannelNotFound(String),
+    #[error("Invalid epoch: {0}")]
+    InvalidEpoch(u64),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -17,7 +21,10 @@ pub enum IbcError {
 impl IntoResponse for IbcError {
     fn into_response(self) -> axum::response::Response {
         let status_code = match self {
-            IbcError::NotFound(_) => StatusCode::NOT_FOUND,
+            IbcError::NotFound(_) | IbcError::ChannelNotFound(_) => {
+                StatusCode::NOT_FOUND
+            }
+            IbcError::InvalidEpoch(_) => StatusCode::BAD_REQUEST,
             IbcError::Unknown(_) | IbcError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
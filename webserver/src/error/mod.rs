@@ -1,3 +1,4 @@
+pub mod account;
 pub mod api;
 pub mod balance;
 pub mod block;
@@ -9,5 +10,8 @@ pub mod ibc;
 pub mod masp;
 pub mod pgf;
 pub mod pos;
+pub mod rate_limit;
 pub mod revealed_pk;
+pub mod search;
+pub mod subscription;
 pub mod transaction;
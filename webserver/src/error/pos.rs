@@ -6,6 +6,10 @@ use crate::response::api::ApiErrorResponse;
 
 #[derive(Error, Debug)]
 pub enum PoSError {
+    #[error("{0}")]
+    InvalidCursor(String),
+    #[error("Validator {0} not found")]
+    ValidatorNotFound(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Unknown error: {0}")]
@@ -15,6 +19,8 @@ pub enum PoSError {
 impl IntoResponse for PoSError {
     fn into_response(self) -> Response {
         let status_code = match self {
+            PoSError::InvalidCursor(_) => StatusCode::BAD_REQUEST,
+            PoSError::ValidatorNotFound(_) => StatusCode::NOT_FOUND,
             PoSError::Unknown(_) | PoSError::Database(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
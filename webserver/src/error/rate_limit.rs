@@ -0,0 +1,35 @@
+use axum::http::{HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use thiserror::Error;
+
+use crate::response::api::ApiErrorResponse;
+
+#[derive(Error, Debug)]
+pub enum RateLimitError {
+    #[error("Too many requests, retry in {0} seconds")]
+    TooManyRequests(u64),
+    #[error("Invalid api key")]
+    InvalidApiKey,
+}
+
+impl IntoResponse for RateLimitError {
+    fn into_response(self) -> Response {
+        let status_code = match self {
+            RateLimitError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            RateLimitError::InvalidApiKey => StatusCode::UNAUTHORIZED,
+        };
+
+        let mut response = ApiErrorResponse::send(
+            status_code.as_u16(),
+            Some(self.to_string()),
+        );
+
+        if let RateLimitError::TooManyRequests(retry_after) = self {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+        }
+
+        response
+    }
+}
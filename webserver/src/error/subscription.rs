@@ -0,0 +1,34 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use thiserror::Error;
+
+use crate::response::api::ApiErrorResponse;
+
+#[derive(Error, Debug)]
+pub enum SubscriptionError {
+    #[error("Invalid subscription message: {0}")]
+    InvalidMessage(String),
+    #[error("Invalid topic: {0}")]
+    InvalidTopic(String),
+    #[error("Too many topics, at most {0} can be subscribed to")]
+    TooManyTopics(usize),
+    #[error("Listener error: {0}")]
+    Listener(String),
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+impl IntoResponse for SubscriptionError {
+    fn into_response(self) -> Response {
+        let status_code = match self {
+            SubscriptionError::InvalidMessage(_)
+            | SubscriptionError::InvalidTopic(_)
+            | SubscriptionError::TooManyTopics(_) => StatusCode::BAD_REQUEST,
+            SubscriptionError::Listener(_) | SubscriptionError::Database(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        ApiErrorResponse::send(status_code.as_u16(), Some(self.to_string()))
+    }
+}
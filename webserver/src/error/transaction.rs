@@ -10,6 +10,10 @@ pub enum TransactionError {
     InvalidTxId,
     #[error("The tx id {0} does not exist")]
     TxIdNotFound(String),
+    #[error("{0}")]
+    InvalidCursor(String),
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
     #[error("Database error: {0}")]
     Database(String),
     #[error("Rpc error: {0}")]
@@ -23,6 +27,8 @@ impl IntoResponse for TransactionError {
         let status_code = match self {
             TransactionError::InvalidTxId => StatusCode::BAD_REQUEST,
             TransactionError::TxIdNotFound(_) => StatusCode::NOT_FOUND,
+            TransactionError::InvalidCursor(_)
+            | TransactionError::InvalidFilter(_) => StatusCode::BAD_REQUEST,
             TransactionError::Unknown(_)
             | TransactionError::Database(_)
             | TransactionError::Rpc(_) => StatusCode::INTERNAL_SERVER_ERROR,
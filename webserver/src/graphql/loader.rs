@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use async_graphql::dataloader::{DataLoader, Loader};
+
+use crate::entity::governance::Proposal;
+use crate::entity::transaction::{InnerTransaction, WrapperTransaction};
+use crate::state::common::CommonState;
+
+/// Batches the lookups resolvers make for each item of a list into one query
+/// per field, instead of one query per item
+pub type IndexerDataLoader = DataLoader<IndexerLoader>;
+
+pub struct IndexerLoader(pub CommonState);
+
+impl IndexerLoader {
+    /// A loader caches what it loaded, so a new one is built per request
+    pub fn data_loader(state: CommonState) -> IndexerDataLoader {
+        DataLoader::new(Self(state), tokio::spawn)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WrapperId(pub String);
+
+/// Inner transactions of a wrapper transaction, by the wrapper id
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InnersOf(pub String);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProposalId(pub u64);
+
+impl Loader<WrapperId> for IndexerLoader {
+    type Error = String;
+    type Value = WrapperTransaction;
+
+    async fn load(
+        &self,
+        keys: &[WrapperId],
+    ) -> Result<HashMap<WrapperId, Self::Value>, Self::Error> {
+        let ids = keys.iter().map(|key| key.0.clone()).collect();
+        let wrappers = self
+            .0
+            .transaction_service
+            .get_wrapper_txs(ids)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(wrappers
+            .into_iter()
+            .map(|wrapper| (WrapperId(wrapper.id.to_string()), wrapper))
+            .collect())
+    }
+}
+
+impl Loader<InnersOf> for IndexerLoader {
+    type Error = String;
+    type Value = Vec<InnerTransaction>;
+
+    async fn load(
+        &self,
+        keys: &[InnersOf],
+    ) -> Result<HashMap<InnersOf, Self::Value>, Self::Error> {
+        let ids = keys.iter().map(|key| key.0.clone()).collect();
+        let inners = self
+            .0
+            .transaction_service
+            .get_inner_txs_by_wrapper_ids(ids)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Inner transactions come ordered, which grouping keeps
+        let mut by_wrapper = HashMap::<InnersOf, Self::Value>::new();
+        for inner in inners {
+            by_wrapper
+                .entry(InnersOf(inner.wrapper_id.to_string()))
+                .or_default()
+                .push(inner);
+        }
+
+        Ok(by_wrapper)
+    }
+}
+
+impl Loader<ProposalId> for IndexerLoader {
+    type Error = String;
+    type Value = Proposal;
+
+    async fn load(
+        &self,
+        keys: &[ProposalId],
+    ) -> Result<HashMap<ProposalId, Self::Value>, Self::Error> {
+        let ids = keys.iter().map(|key| key.0).collect();
+        let proposals = self
+            .0
+            .gov_service
+            .find_governance_proposals_by_ids(ids)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(proposals
+            .into_iter()
+            .map(|proposal| (ProposalId(proposal.id), proposal))
+            .collect())
+    }
+}
@@ -0,0 +1,239 @@
+use async_graphql::{
+    Context, EmptyMutation, EmptySubscription, Object, Result, Schema,
+};
+
+use crate::config::AppConfig;
+use crate::dto::pos::ValidatorStateDto;
+use crate::graphql::types::{
+    Account, Block, InnerTransaction, Proposal, Token, Validator,
+    WrapperTransaction, block_by_height, wrapper_by_id,
+};
+use crate::response::block::BlockResponse;
+use crate::response::chain::TokenResponse;
+use crate::response::transaction::InnerTransactionResponse;
+use crate::state::common::CommonState;
+
+pub type IndexerSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+pub fn build_schema(config: &AppConfig) -> IndexerSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(config.graphql_max_depth)
+        .limit_complexity(config.graphql_max_complexity)
+        .finish()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn validator(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> Result<Option<Validator>> {
+        let state = ctx.data::<CommonState>()?;
+        let validator =
+            state.pos_service.get_validator_by_address(address).await?;
+
+        Ok(validator.map(|validator| Validator(validator.into())))
+    }
+
+    /// All the validators, optionally restricted to some states
+    async fn validators(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] states: Vec<String>,
+    ) -> Result<Vec<Validator>> {
+        let state = ctx.data::<CommonState>()?;
+        let states = states
+            .into_iter()
+            .map(|state| {
+                serde_json::from_value::<ValidatorStateDto>(state.into())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let states = if states.is_empty() {
+            ValidatorStateDto::all()
+        } else {
+            states
+        };
+
+        let validators = state.pos_service.get_all_validators(states).await?;
+
+        Ok(validators
+            .into_iter()
+            .map(|validator| Validator(validator.into()))
+            .collect())
+    }
+
+    async fn account(&self, address: String) -> Account {
+        Account { address }
+    }
+
+    async fn proposal(
+        &self,
+        ctx: &Context<'_>,
+        id: u64,
+    ) -> Result<Option<Proposal>> {
+        let state = ctx.data::<CommonState>()?;
+        let proposal =
+            state.gov_service.find_governance_proposal_by_id(id).await?;
+
+        Ok(proposal.map(|proposal| Proposal(proposal.into())))
+    }
+
+    async fn proposals(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 1, validator(minimum = 1, maximum = 10000))]
+        page: u64,
+    ) -> Result<Vec<Proposal>> {
+        let state = ctx.data::<CommonState>()?;
+        let (proposals, _, _) = state
+            .gov_service
+            .find_governance_proposals(None, None, None, page)
+            .await?;
+
+        Ok(proposals
+            .into_iter()
+            .map(|proposal| Proposal(proposal.into()))
+            .collect())
+    }
+
+    async fn wrapper_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> Result<Option<WrapperTransaction>> {
+        wrapper_by_id(ctx, id.to_lowercase()).await
+    }
+
+    async fn inner_transaction(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> Result<Option<InnerTransaction>> {
+        let state = ctx.data::<CommonState>()?;
+        let inner = state
+            .transaction_service
+            .get_inner_tx(id.to_lowercase())
+            .await?;
+
+        Ok(inner.map(|inner| {
+            InnerTransaction(InnerTransactionResponse::new(inner))
+        }))
+    }
+
+    /// Block by height or by hash
+    async fn block(
+        &self,
+        ctx: &Context<'_>,
+        height: Option<i32>,
+        hash: Option<String>,
+    ) -> Result<Block> {
+        match (height, hash) {
+            (Some(height), None) => block_by_height(ctx, height).await,
+            (None, Some(hash)) => {
+                let state = ctx.data::<CommonState>()?;
+                let block = state.block_service.get_block_by_hash(hash).await?;
+
+                Ok(Block(BlockResponse::from(block)))
+            }
+            _ => Err("Exactly one of height or hash is required".into()),
+        }
+    }
+
+    async fn tokens(&self, ctx: &Context<'_>) -> Result<Vec<Token>> {
+        let state = ctx.data::<CommonState>()?;
+        let tokens = state.chain_service.find_tokens().await?;
+
+        Ok(tokens
+            .into_iter()
+            .map(|token| Token(TokenResponse::from(token)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    fn schema() -> IndexerSchema {
+        let config = AppConfig::parse_from([
+            "webserver",
+            "--database-url",
+            "postgres://localhost",
+            "--tendermint-url",
+            "http://localhost:26657",
+        ]);
+
+        build_schema(&config)
+    }
+
+    async fn errors(query: &str) -> Vec<String> {
+        schema()
+            .execute(query)
+            .await
+            .errors
+            .into_iter()
+            .map(|error| error.message)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn queries_within_limits_are_executed() {
+        let response = schema()
+            .execute(r#"{ account(address: "tnam1") { address } }"#)
+            .await;
+
+        assert!(response.errors.is_empty());
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "account": { "address": "tnam1" } })
+        );
+    }
+
+    #[tokio::test]
+    async fn deep_queries_are_rejected() {
+        let query = r#"{
+            account(address: "tnam1") {
+                votes(limit: 1) { results { proposal {
+                    votes(limit: 1) { results { proposal {
+                        votes(limit: 1) { results { proposal { id } } }
+                    } } }
+                } } }
+            }
+        }"#;
+
+        assert_eq!(errors(query).await, vec!["Query is nested too deep."]);
+    }
+
+    #[tokio::test]
+    async fn complex_queries_are_rejected() {
+        let query = r#"{
+            account(address: "tnam1") {
+                votes(limit: 100) { results { proposal {
+                    votes(limit: 100) { results { voterAddress } }
+                } } }
+            }
+        }"#;
+
+        assert_eq!(errors(query).await, vec!["Query is too complex."]);
+    }
+
+    #[tokio::test]
+    async fn proposal_pages_are_bounded() {
+        for page in [0, 10001] {
+            let query = format!("{{ proposals(page: {}) {{ id }} }}", page);
+            let errors = errors(&query).await;
+
+            assert_eq!(errors.len(), 1);
+            assert!(
+                errors[0].contains(&format!("the value is {}", page)),
+                "{}",
+                errors[0]
+            );
+        }
+    }
+}
@@ -0,0 +1,708 @@
+use async_graphql::{Context, Object, OutputType, Result, SimpleObject};
+use serde::Serialize;
+
+use crate::entity::cursor::cursor_limit;
+use crate::entity::governance::ProposalVote;
+use crate::entity::pos::{
+    Bond as EntityBond, Reward as EntityReward, Unbond as EntityUnbond,
+};
+use crate::entity::transaction::{
+    TransactionHistory, TransactionHistoryFilter,
+};
+use crate::graphql::loader::{
+    IndexerDataLoader, InnersOf, ProposalId, WrapperId,
+};
+use crate::response::balance::AddressBalanceResponse;
+use crate::response::block::BlockResponse;
+use crate::response::chain::TokenResponse;
+use crate::response::governance::{ProposalResponse, ProposalVoteResponse};
+use crate::response::pos::{
+    BondResponse, RewardResponse, UnbondResponse, ValidatorWithRankResponse,
+};
+use crate::response::transaction::{
+    InnerTransactionResponse, TransactionHistoryResponse,
+    WrapperTransactionResponse,
+};
+use crate::state::common::CommonState;
+
+/// Complexity of a paginated field, scaled by the number of items the
+/// resolver actually returns
+pub fn page_complexity(limit: Option<u64>, child_complexity: usize) -> usize {
+    (cursor_limit(limit) as usize).saturating_mul(child_complexity)
+}
+
+/// Enums are exposed with the same names as in the REST responses
+fn enum_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[derive(SimpleObject)]
+#[graphql(concrete(name = "BondPage", params(Bond)))]
+#[graphql(concrete(name = "UnbondPage", params(Unbond)))]
+#[graphql(concrete(name = "RewardPage", params(Reward)))]
+#[graphql(concrete(name = "VotePage", params(Vote)))]
+#[graphql(concrete(name = "HistoryPage", params(HistoryEntry)))]
+pub struct Page<T: OutputType> {
+    pub results: Vec<T>,
+    /// Cursor of the next page, null on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl<T: OutputType> Page<T> {
+    pub fn new<R>(results: Vec<R>, next_cursor: Option<String>) -> Self
+    where
+        T: From<R>,
+    {
+        Self {
+            results: results.into_iter().map(T::from).collect(),
+            next_cursor,
+        }
+    }
+}
+
+pub struct Validator(pub ValidatorWithRankResponse);
+
+#[Object]
+impl Validator {
+    async fn address(&self) -> &str {
+        &self.0.validator.address
+    }
+
+    async fn name(&self) -> Option<&str> {
+        self.0.validator.name.as_deref()
+    }
+
+    async fn voting_power(&self) -> u64 {
+        self.0.validator.voting_power
+    }
+
+    async fn commission(&self) -> &str {
+        &self.0.validator.commission
+    }
+
+    async fn max_commission(&self) -> &str {
+        &self.0.validator.max_commission
+    }
+
+    async fn state(&self) -> String {
+        enum_name(&self.0.validator.state)
+    }
+
+    async fn rank(&self) -> Option<u64> {
+        self.0.rank
+    }
+
+    async fn email(&self) -> Option<&str> {
+        self.0.validator.email.as_deref()
+    }
+
+    async fn website(&self) -> Option<&str> {
+        self.0.validator.website.as_deref()
+    }
+
+    async fn description(&self) -> Option<&str> {
+        self.0.validator.description.as_deref()
+    }
+
+    async fn discord_handle(&self) -> Option<&str> {
+        self.0.validator.discord_handle.as_deref()
+    }
+
+    async fn avatar(&self) -> Option<&str> {
+        self.0.validator.avatar.as_deref()
+    }
+}
+
+pub struct Bond(pub BondResponse);
+
+impl From<EntityBond> for Bond {
+    fn from(value: EntityBond) -> Self {
+        Self(value.into())
+    }
+}
+
+#[Object]
+impl Bond {
+    async fn min_denom_amount(&self) -> &str {
+        &self.0.min_denom_amount
+    }
+
+    async fn status(&self) -> String {
+        enum_name(&self.0.status)
+    }
+
+    async fn start_epoch(&self) -> u64 {
+        self.0.start_epoch
+    }
+
+    async fn validator(&self) -> Validator {
+        Validator(self.0.validator.clone())
+    }
+}
+
+pub struct Unbond(pub UnbondResponse);
+
+impl From<EntityUnbond> for Unbond {
+    fn from(value: EntityUnbond) -> Self {
+        Self(value.into())
+    }
+}
+
+#[Object]
+impl Unbond {
+    async fn min_denom_amount(&self) -> &str {
+        &self.0.min_denom_amount
+    }
+
+    async fn withdraw_epoch(&self) -> &str {
+        &self.0.withdraw_epoch
+    }
+
+    async fn withdraw_time(&self) -> &str {
+        &self.0.withdraw_time
+    }
+
+    async fn can_withdraw(&self) -> bool {
+        self.0.can_withdraw
+    }
+
+    async fn validator(&self) -> Validator {
+        Validator(self.0.validator.clone())
+    }
+}
+
+pub struct Reward(pub RewardResponse);
+
+impl From<EntityReward> for Reward {
+    fn from(value: EntityReward) -> Self {
+        Self(value.into())
+    }
+}
+
+#[Object]
+impl Reward {
+    async fn min_denom_amount(&self) -> &str {
+        &self.0.min_denom_amount
+    }
+
+    async fn validator(&self) -> Validator {
+        Validator(self.0.validator.clone())
+    }
+}
+
+pub struct Proposal(pub ProposalResponse);
+
+#[Object]
+impl Proposal {
+    async fn id(&self) -> u64 {
+        self.0.id
+    }
+
+    async fn content(&self) -> &str {
+        &self.0.content
+    }
+
+    async fn r#type(&self) -> String {
+        self.0.r#type.to_string()
+    }
+
+    async fn tally_type(&self) -> String {
+        self.0.tally_type.to_string()
+    }
+
+    async fn author(&self) -> &str {
+        &self.0.author
+    }
+
+    async fn start_epoch(&self) -> u64 {
+        self.0.start_epoch
+    }
+
+    async fn end_epoch(&self) -> u64 {
+        self.0.end_epoch
+    }
+
+    async fn activation_epoch(&self) -> u64 {
+        self.0.activation_epoch
+    }
+
+    async fn start_time(&self) -> &str {
+        &self.0.start_time
+    }
+
+    async fn end_time(&self) -> &str {
+        &self.0.end_time
+    }
+
+    async fn activation_time(&self) -> &str {
+        &self.0.activation_time
+    }
+
+    async fn status(&self) -> String {
+        self.0.status.to_string()
+    }
+
+    async fn yay_votes(&self) -> f64 {
+        self.0.yay_votes
+    }
+
+    async fn nay_votes(&self) -> f64 {
+        self.0.nay_votes
+    }
+
+    async fn abstain_votes(&self) -> f64 {
+        self.0.abstain_votes
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn votes(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+    ) -> Result<Page<Vote>> {
+        let state = ctx.data::<CommonState>()?;
+        let (votes, next_cursor) = state
+            .gov_service
+            .find_governance_proposal_votes_after(
+                self.0.id,
+                cursor.as_deref().unwrap_or_default(),
+                cursor_limit(limit),
+            )
+            .await?;
+
+        Ok(Page::new(votes, next_cursor))
+    }
+}
+
+pub struct Vote(pub ProposalVoteResponse);
+
+impl From<ProposalVote> for Vote {
+    fn from(value: ProposalVote) -> Self {
+        Self(value.into())
+    }
+}
+
+#[Object]
+impl Vote {
+    async fn proposal_id(&self) -> u64 {
+        self.0.proposal_id
+    }
+
+    async fn vote(&self) -> String {
+        self.0.vote.to_string()
+    }
+
+    async fn voter_address(&self) -> &str {
+        &self.0.voter_address
+    }
+
+    async fn proposal(&self, ctx: &Context<'_>) -> Result<Option<Proposal>> {
+        let loader = ctx.data::<IndexerDataLoader>()?;
+        let proposal = loader.load_one(ProposalId(self.0.proposal_id)).await?;
+
+        Ok(proposal.map(|proposal| Proposal(proposal.into())))
+    }
+}
+
+pub struct Token(pub TokenResponse);
+
+#[Object]
+impl Token {
+    async fn address(&self) -> &str {
+        match &self.0 {
+            TokenResponse::Native(token) => &token.address,
+            TokenResponse::Ibc(token) => &token.address,
+        }
+    }
+
+    /// IBC denomination trace, null for the native token
+    async fn trace(&self) -> Option<&str> {
+        match &self.0 {
+            TokenResponse::Native(_) => None,
+            TokenResponse::Ibc(token) => Some(&token.trace),
+        }
+    }
+}
+
+pub struct Balance(pub AddressBalanceResponse);
+
+#[Object]
+impl Balance {
+    async fn token(&self) -> Token {
+        Token(self.0.token.clone())
+    }
+
+    async fn min_denom_amount(&self) -> &str {
+        &self.0.min_denom_amount
+    }
+}
+
+pub struct WrapperTransaction(pub WrapperTransactionResponse);
+
+#[Object]
+impl WrapperTransaction {
+    async fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    async fn fee_payer(&self) -> &str {
+        &self.0.fee_payer
+    }
+
+    async fn fee_token(&self) -> Token {
+        Token(self.0.fee_token.clone())
+    }
+
+    async fn gas_limit(&self) -> &str {
+        &self.0.gas_limit
+    }
+
+    async fn gas_used(&self) -> Option<u64> {
+        self.0.gas_used
+    }
+
+    async fn amount_per_gas_unit(&self) -> Option<f64> {
+        self.0.amount_per_gas_unit
+    }
+
+    async fn masp_fee_payment(&self) -> Option<&str> {
+        self.0.masp_fee_payment.as_deref()
+    }
+
+    async fn block_height(&self) -> u64 {
+        self.0.block_height
+    }
+
+    async fn exit_code(&self) -> String {
+        enum_name(&self.0.exit_code)
+    }
+
+    async fn atomic(&self) -> bool {
+        self.0.atomic
+    }
+
+    /// First `limit` inner transactions of the wrapper, in batch order
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn inner_transactions(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<u64>,
+    ) -> Result<Vec<InnerTransaction>> {
+        let loader = ctx.data::<IndexerDataLoader>()?;
+        let inners = loader
+            .load_one(InnersOf(self.0.id.clone()))
+            .await?
+            .unwrap_or_default();
+
+        Ok(inners
+            .into_iter()
+            .take(cursor_limit(limit) as usize)
+            .map(|inner| InnerTransaction(InnerTransactionResponse::new(inner)))
+            .collect())
+    }
+
+    async fn block(&self, ctx: &Context<'_>) -> Result<Block> {
+        block_by_height(ctx, self.0.block_height as i32).await
+    }
+}
+
+pub struct InnerTransaction(pub InnerTransactionResponse);
+
+#[Object]
+impl InnerTransaction {
+    async fn id(&self) -> &str {
+        &self.0.id
+    }
+
+    async fn wrapper_id(&self) -> &str {
+        &self.0.wrapper_id
+    }
+
+    async fn kind(&self) -> String {
+        enum_name(&self.0.kind)
+    }
+
+    async fn data(&self) -> Option<&str> {
+        self.0.data.as_deref()
+    }
+
+    async fn memo(&self) -> Option<&str> {
+        self.0.memo.as_deref()
+    }
+
+    async fn memo_text(&self) -> Option<&str> {
+        self.0.memo_text.as_deref()
+    }
+
+    async fn exit_code(&self) -> String {
+        enum_name(&self.0.exit_code)
+    }
+
+    async fn wrapper(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<Option<WrapperTransaction>> {
+        wrapper_by_id(ctx, self.0.wrapper_id.clone()).await
+    }
+}
+
+pub struct HistoryEntry(pub TransactionHistoryResponse);
+
+impl From<TransactionHistory> for HistoryEntry {
+    fn from(value: TransactionHistory) -> Self {
+        Self(value.into())
+    }
+}
+
+#[Object]
+impl HistoryEntry {
+    async fn target(&self) -> &str {
+        &self.0.target
+    }
+
+    async fn kind(&self) -> String {
+        enum_name(&self.0.kind)
+    }
+
+    async fn block_height(&self) -> u64 {
+        self.0.block_height
+    }
+
+    async fn transaction(&self) -> InnerTransaction {
+        InnerTransaction(self.0.tx.clone())
+    }
+}
+
+pub struct Block(pub BlockResponse);
+
+#[Object]
+impl Block {
+    async fn height(&self) -> u64 {
+        self.0.height
+    }
+
+    async fn hash(&self) -> Option<&str> {
+        self.0.hash.as_deref()
+    }
+
+    async fn app_hash(&self) -> Option<&str> {
+        self.0.app_hash.as_deref()
+    }
+
+    async fn timestamp(&self) -> Option<&str> {
+        self.0.timestamp.as_deref()
+    }
+
+    async fn proposer(&self) -> Option<&str> {
+        self.0.proposer.as_deref()
+    }
+
+    async fn epoch(&self) -> Option<&str> {
+        self.0.epoch.as_deref()
+    }
+
+    async fn parent_hash(&self) -> Option<&str> {
+        self.0.parent_hash.as_deref()
+    }
+
+    async fn parent_app_hash(&self) -> Option<&str> {
+        self.0.parent_app_hash.as_deref()
+    }
+
+    async fn tx_count(&self) -> u64 {
+        self.0.tx_count
+    }
+
+    async fn gas_used(&self) -> u64 {
+        self.0.gas_used
+    }
+
+    /// First `limit` wrapper transactions of the block, in block order
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<u64>,
+    ) -> Result<Vec<WrapperTransaction>> {
+        let loader = ctx.data::<IndexerDataLoader>()?;
+        let ids = self
+            .0
+            .transactions
+            .iter()
+            .take(cursor_limit(limit) as usize)
+            .map(|id| WrapperId(id.clone()))
+            .collect::<Vec<_>>();
+        let mut wrappers = loader.load_many(ids.clone()).await?;
+
+        Ok(ids
+            .iter()
+            .filter_map(|id| wrappers.remove(id))
+            .map(|wrapper| {
+                WrapperTransaction(WrapperTransactionResponse::new(
+                    wrapper,
+                    vec![],
+                ))
+            })
+            .collect())
+    }
+}
+
+/// Delegator and transaction centric view of an address
+pub struct Account {
+    pub address: String,
+}
+
+#[Object]
+impl Account {
+    async fn address(&self) -> &str {
+        &self.address
+    }
+
+    async fn balances(&self, ctx: &Context<'_>) -> Result<Vec<Balance>> {
+        let state = ctx.data::<CommonState>()?;
+        let balances = state
+            .balance_service
+            .get_address_balances(self.address.clone(), None, None)
+            .await?;
+
+        Ok(balances
+            .into_iter()
+            .map(|balance| {
+                Balance(AddressBalanceResponse {
+                    token: TokenResponse::from(balance.token),
+                    min_denom_amount: balance.amount.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn bonds(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+        active_at: Option<i32>,
+    ) -> Result<Page<Bond>> {
+        let state = ctx.data::<CommonState>()?;
+        let (bonds, next_cursor) = state
+            .pos_service
+            .get_bonds_by_address_after(
+                self.address.clone(),
+                cursor.as_deref().unwrap_or_default(),
+                cursor_limit(limit),
+                active_at,
+            )
+            .await?;
+
+        Ok(Page::new(bonds, next_cursor))
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn unbonds(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+        active_at: Option<i32>,
+    ) -> Result<Page<Unbond>> {
+        let state = ctx.data::<CommonState>()?;
+        let (unbonds, next_cursor) = state
+            .pos_service
+            .get_unbonds_by_address_after(
+                self.address.clone(),
+                cursor.as_deref().unwrap_or_default(),
+                cursor_limit(limit),
+                active_at,
+            )
+            .await?;
+
+        Ok(Page::new(unbonds, next_cursor))
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn rewards(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+        epoch: Option<u64>,
+    ) -> Result<Page<Reward>> {
+        let state = ctx.data::<CommonState>()?;
+        let (rewards, next_cursor) = state
+            .pos_service
+            .get_rewards_by_address_after(
+                self.address.clone(),
+                cursor.as_deref().unwrap_or_default(),
+                cursor_limit(limit),
+                epoch,
+            )
+            .await?;
+
+        Ok(Page::new(rewards, next_cursor))
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn votes(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+    ) -> Result<Page<Vote>> {
+        let state = ctx.data::<CommonState>()?;
+        let (votes, next_cursor) = state
+            .gov_service
+            .find_governance_proposal_votes_by_voter_after(
+                self.address.clone(),
+                cursor.as_deref().unwrap_or_default(),
+                cursor_limit(limit),
+            )
+            .await?;
+
+        Ok(Page::new(votes, next_cursor))
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn history(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<String>,
+        limit: Option<u64>,
+    ) -> Result<Page<HistoryEntry>> {
+        let state = ctx.data::<CommonState>()?;
+        let (history, next_cursor) = state
+            .transaction_service
+            .get_addresses_history_after(
+                vec![self.address.clone()],
+                TransactionHistoryFilter::default(),
+                cursor.as_deref().unwrap_or_default(),
+                cursor_limit(limit),
+            )
+            .await?;
+
+        Ok(Page::new(history, next_cursor))
+    }
+}
+
+pub async fn block_by_height(ctx: &Context<'_>, height: i32) -> Result<Block> {
+    let state = ctx.data::<CommonState>()?;
+    let block = state.block_service.get_block_by_height(height).await?;
+
+    Ok(Block(BlockResponse::from(block)))
+}
+
+pub async fn wrapper_by_id(
+    ctx: &Context<'_>,
+    id: String,
+) -> Result<Option<WrapperTransaction>> {
+    let loader = ctx.data::<IndexerDataLoader>()?;
+    let wrapper = loader.load_one(WrapperId(id)).await?;
+
+    Ok(wrapper.map(|wrapper| {
+        WrapperTransaction(WrapperTransactionResponse::new(wrapper, vec![]))
+    }))
+}
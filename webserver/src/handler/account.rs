@@ -0,0 +1,36 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum_macros::debug_handler;
+
+use crate::error::api::ApiError;
+use crate::response::account::AccountInfoResponse;
+use crate::response::api::ApiErrorResponses;
+use crate::state::common::CommonState;
+
+/// Get the kind, public keys and signature threshold of an account
+///
+/// Established accounts are known once created or updated by an indexed
+/// transaction, implicit accounts list their key once it is revealed.
+#[utoipa::path(
+    get,
+    path = "/api/v1/account/{address}/info",
+    tag = "account",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+    ),
+    responses(
+        (status = 200, body = AccountInfoResponse),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_account_info(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<AccountInfoResponse>, ApiError> {
+    let account = state.account_service.get_account_info(address).await?;
+
+    Ok(Json(AccountInfoResponse::from(account)))
+}
@@ -1,20 +1,44 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::HeaderMap;
+use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
+use crate::dto::balance::{BalanceHistoryQueryParams, BalanceQueryParams};
 use crate::error::api::ApiError;
-use crate::response::balance::AddressBalanceResponse;
+use crate::response::api::ApiErrorResponses;
+use crate::response::balance::{
+    AddressBalanceHistoryResponse, AddressBalanceResponse,
+};
 use crate::response::chain::TokenResponse;
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
+/// Get all the token balances of an address
+#[utoipa::path(
+    get,
+    path = "/api/v1/account/{address}",
+    tag = "account",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        BalanceQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<AddressBalanceResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_address_balance(
     _headers: HeaderMap,
     Path(address): Path<String>,
+    Query(query): Query<BalanceQueryParams>,
     State(state): State<CommonState>,
 ) -> Result<Json<Vec<AddressBalanceResponse>>, ApiError> {
-    let balances = state.balance_service.get_address_balances(address).await?;
+    let balances = state
+        .balance_service
+        .get_address_balances(address, query.height, query.timestamp)
+        .await?;
 
     let response = balances
         .into_iter()
@@ -26,3 +50,46 @@ pub async fn get_address_balance(
 
     Ok(Json(response))
 }
+
+/// Get the balance changes of an address for a token, ordered by block
+/// height
+#[utoipa::path(
+    get,
+    path = "/api/v1/account/{address}/history",
+    tag = "account",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        BalanceHistoryQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<AddressBalanceHistoryResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_address_balance_history(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    Query(query): Query<BalanceHistoryQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<AddressBalanceHistoryResponse>>>, ApiError>
+{
+    let page = query.page.unwrap_or(1);
+
+    let (changes, total_pages, total_items) = state
+        .balance_service
+        .get_address_balance_history(address, query.token, page)
+        .await?;
+
+    let response = changes
+        .into_iter()
+        .map(AddressBalanceHistoryResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
@@ -1,54 +1,185 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::HeaderMap;
+use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
+use crate::dto::block::{BlockRangeQueryParams, LatestBlocksQueryParams};
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::block::BlockResponse;
 use crate::response::headers;
+use crate::response::transaction::WrapperTransactionResponse;
 use crate::state::common::CommonState;
 
+/// Get a block by height
+#[utoipa::path(
+    get,
+    path = "/api/v1/block/height/{value}",
+    tag = "block",
+    params(
+        ("value" = i32, Path, description = "Height of the block"),
+    ),
+    responses(
+        (status = 200, body = BlockResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_block_by_height(
     _headers: HeaderMap,
     Path(value): Path<i32>,
     State(state): State<CommonState>,
 ) -> Result<(HeaderMap, Json<BlockResponse>), ApiError> {
-    let (block, prev_block, transactions) =
-        state.block_service.get_block_by_height(value).await?;
+    let block = state.block_service.get_block_by_height(value).await?;
 
-    let response = BlockResponse::from(block, prev_block, transactions);
+    let response = BlockResponse::from(block);
     let headers = headers::with_cache();
 
     Ok((headers, Json(response)))
 }
 
+/// Get the block at a timestamp
+#[utoipa::path(
+    get,
+    path = "/api/v1/block/timestamp/{value}",
+    tag = "block",
+    params(
+        ("value" = i64, Path, description = "Unix timestamp, in seconds"),
+    ),
+    responses(
+        (status = 200, body = BlockResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_block_by_timestamp(
     _headers: HeaderMap,
     Path(value): Path<i64>,
     State(state): State<CommonState>,
 ) -> Result<(HeaderMap, Json<BlockResponse>), ApiError> {
-    let (block, prev_block, transactions) =
-        state.block_service.get_block_by_timestamp(value).await?;
+    let block = state.block_service.get_block_by_timestamp(value).await?;
 
-    let response = BlockResponse::from(block, prev_block, transactions);
+    let response = BlockResponse::from(block);
     let headers = headers::with_cache();
 
     Ok((headers, Json(response)))
 }
 
+/// Get a block by hash or app hash
+#[utoipa::path(
+    get,
+    path = "/api/v1/block/hash/{value}",
+    tag = "block",
+    params(
+        ("value" = String, Path, description = "Hash or app hash of the block"),
+    ),
+    responses(
+        (status = 200, body = BlockResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_block_by_hash(
     _headers: HeaderMap,
     Path(value): Path<String>,
     State(state): State<CommonState>,
 ) -> Result<(HeaderMap, Json<BlockResponse>), ApiError> {
-    let (block, prev_block, transactions) =
-        state.block_service.get_block_by_hash(value).await?;
+    let block = state.block_service.get_block_by_hash(value).await?;
 
-    let response = BlockResponse::from(block, prev_block, transactions);
+    let response = BlockResponse::from(block);
     let headers = headers::with_cache();
 
     Ok((headers, Json(response)))
 }
+
+/// Get the wrapper transactions of a block, with their inner transactions
+///
+/// Transactions are listed in the order they were included in the block.
+/// The order is unknown for transactions indexed before it was recorded,
+/// these come last.
+#[utoipa::path(
+    get,
+    path = "/api/v1/block/{height}/transactions",
+    tag = "block",
+    params(
+        ("height" = i32, Path, description = "Height of the block"),
+    ),
+    responses(
+        (status = 200, body = Vec<WrapperTransactionResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_block_transactions(
+    _headers: HeaderMap,
+    Path(height): Path<i32>,
+    State(state): State<CommonState>,
+) -> Result<(HeaderMap, Json<Vec<WrapperTransactionResponse>>), ApiError> {
+    let transactions =
+        state.block_service.get_block_transactions(height).await?;
+
+    let response = transactions
+        .into_iter()
+        .map(|(wrapper, inners)| {
+            WrapperTransactionResponse::new(wrapper, inners)
+        })
+        .collect();
+    let headers = headers::with_cache();
+
+    Ok((headers, Json(response)))
+}
+
+/// Get the blocks in a range of heights
+///
+/// Both heights are included, at most 100 blocks are returned, by ascending
+/// height.
+#[utoipa::path(
+    get,
+    path = "/api/v1/block",
+    tag = "block",
+    params(
+        BlockRangeQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<BlockResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_blocks(
+    _headers: HeaderMap,
+    Query(query): Query<BlockRangeQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<BlockResponse>>, ApiError> {
+    let blocks = state
+        .block_service
+        .get_blocks_by_height_range(query.from_height, query.to_height)
+        .await?;
+
+    Ok(Json(blocks.into_iter().map(BlockResponse::from).collect()))
+}
+
+/// Get the most recent blocks, by descending height
+#[utoipa::path(
+    get,
+    path = "/api/v1/block/latest",
+    tag = "block",
+    params(
+        LatestBlocksQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<BlockResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_latest_blocks(
+    _headers: HeaderMap,
+    Query(query): Query<LatestBlocksQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<BlockResponse>>, ApiError> {
+    let blocks = state.block_service.get_latest_blocks(query.limit).await?;
+
+    Ok(Json(blocks.into_iter().map(BlockResponse::from).collect()))
+}
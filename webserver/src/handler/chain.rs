@@ -2,7 +2,7 @@ use std::convert::Infallible;
 use std::time::Duration;
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::http::HeaderMap;
 use axum::response::Sse;
 use axum::response::sse::{Event, KeepAlive};
@@ -14,10 +14,12 @@ use crate::dto::chain::{
     CirculatingSupply as CirculatingSupplyDto, TokenSupply as TokenSupplyDto,
 };
 use crate::error::api::ApiError;
+use crate::error::chain::ChainError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::chain::{
-    CirculatingSupplyResponse, LastProcessedBlockResponse,
-    LastProcessedEpochResponse, ParametersResponse, RpcUrlResponse,
-    TokenResponse, TokenSupplyResponse,
+    CirculatingSupplyResponse, EpochEstimateResponse,
+    LastProcessedBlockResponse, LastProcessedEpochResponse, ParametersResponse,
+    RpcUrlResponse, TokenResponse, TokenSupplyResponse,
 };
 use crate::state::common::CommonState;
 
@@ -27,6 +29,15 @@ struct ChainStatusEvent {
     pub epoch: i32,
 }
 
+/// Stream the latest block and epoch processed by the chain crawler
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/status",
+    tag = "chain",
+    responses(
+        (status = 200, description = "Server sent events", content_type = "text/event-stream"),
+    )
+)]
 pub async fn chain_status(
     State(state): State<CommonState>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
@@ -60,6 +71,16 @@ pub async fn chain_status(
     Sse::new(stream).keep_alive(KeepAlive::default())
 }
 
+/// Get chain parameters
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/parameters",
+    tag = "chain",
+    responses(
+        (status = 200, body = ParametersResponse),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_parameters(
     _headers: HeaderMap,
     State(state): State<CommonState>,
@@ -71,6 +92,15 @@ pub async fn get_parameters(
     Ok(Json(response))
 }
 
+/// Get the rpc url the indexer connects to
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/rpc-url",
+    tag = "chain",
+    responses(
+        (status = 200, body = RpcUrlResponse),
+    )
+)]
 pub async fn get_rpc_url(
     State(state): State<CommonState>,
 ) -> Json<RpcUrlResponse> {
@@ -79,6 +109,16 @@ pub async fn get_rpc_url(
     })
 }
 
+/// Get chain tokens
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/token",
+    tag = "chain",
+    responses(
+        (status = 200, body = Vec<TokenResponse>),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_tokens(
     State(state): State<CommonState>,
 ) -> Result<Json<Vec<TokenResponse>>, ApiError> {
@@ -88,6 +128,16 @@ pub async fn get_tokens(
     Ok(Json(res))
 }
 
+/// Get the latest block processed by the chain crawler
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/block/latest",
+    tag = "chain",
+    responses(
+        (status = 200, body = LastProcessedBlockResponse),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_last_processed_block(
     State(state): State<CommonState>,
 ) -> Result<Json<LastProcessedBlockResponse>, ApiError> {
@@ -99,6 +149,16 @@ pub async fn get_last_processed_block(
     }))
 }
 
+/// Get the latest epoch processed by the chain crawler
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/epoch/latest",
+    tag = "chain",
+    responses(
+        (status = 200, body = LastProcessedEpochResponse),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_last_processed_epoch(
     State(state): State<CommonState>,
 ) -> Result<Json<LastProcessedEpochResponse>, ApiError> {
@@ -110,6 +170,52 @@ pub async fn get_last_processed_epoch(
     }))
 }
 
+/// Get when an epoch starts
+///
+/// Future epochs are estimated from the length of the recently indexed
+/// epochs, or from the chain parameters when not enough epochs were indexed.
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/epoch/{epoch}/estimate",
+    tag = "chain",
+    params(
+        ("epoch" = u64, Path, description = "Epoch to estimate the start of"),
+    ),
+    responses(
+        (status = 200, body = EpochEstimateResponse),
+        ApiErrorResponses,
+    )
+)]
+pub async fn get_epoch_estimate(
+    Path(epoch): Path<u64>,
+    State(state): State<CommonState>,
+) -> Result<Json<EpochEstimateResponse>, ApiError> {
+    let epoch =
+        i32::try_from(epoch).map_err(|_| ChainError::InvalidEpoch(epoch))?;
+    let estimator = state.chain_service.get_epoch_time_estimator().await?;
+
+    let estimate = estimator.estimate(epoch);
+
+    Ok(Json(EpochEstimateResponse::from(
+        estimate,
+        estimator.current_epoch,
+        estimator.epoch_duration,
+    )))
+}
+
+/// Get the supply of a token at the given epoch
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/token-supply",
+    tag = "chain",
+    params(
+        TokenSupplyDto,
+    ),
+    responses(
+        (status = 200, body = Option<TokenSupplyResponse>),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_token_supply(
     Query(query): Query<TokenSupplyDto>,
     State(state): State<CommonState>,
@@ -124,6 +230,22 @@ pub async fn get_token_supply(
     Ok(Json(response))
 }
 
+/// Get the circulating supply of the native token at the given epoch
+///
+/// Computed by subtracting the amounts locked by excluded addresses from
+/// the effective supply
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/circulating-supply",
+    tag = "chain",
+    params(
+        CirculatingSupplyDto,
+    ),
+    responses(
+        (status = 200, body = CirculatingSupplyResponse),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_circulating_supply(
     Query(query): Query<CirculatingSupplyDto>,
     State(state): State<CommonState>,
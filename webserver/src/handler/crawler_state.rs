@@ -6,9 +6,23 @@ use strum::VariantArray;
 
 use crate::dto::crawler_state::{CrawlerNameDto, CrawlerStateQueryParams};
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::crawler_state::CrawlersTimestampsResponse;
 use crate::state::common::CommonState;
 
+/// Get the timestamps of the last activity of the crawlers
+#[utoipa::path(
+    get,
+    path = "/api/v1/crawlers/timestamps",
+    tag = "crawlers",
+    params(
+        CrawlerStateQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<CrawlersTimestampsResponse>),
+        ApiErrorResponses,
+    )
+)]
 pub async fn get_crawlers_timestamps(
     _headers: HeaderMap,
     Query(query): Query<CrawlerStateQueryParams>,
@@ -5,9 +5,23 @@ use axum_macros::debug_handler;
 
 use crate::dto::gas::GasEstimateQuery;
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::gas::{GasEstimateResponse, GasPriceResponse};
 use crate::state::common::CommonState;
 
+/// Get the gas price of a token
+#[utoipa::path(
+    get,
+    path = "/api/v1/gas-price/{token}",
+    tag = "gas",
+    params(
+        ("token" = String, Path, description = "Address of the token"),
+    ),
+    responses(
+        (status = 200, body = Vec<GasPriceResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_gas_price_by_token(
     _headers: HeaderMap,
@@ -24,6 +38,16 @@ pub async fn get_gas_price_by_token(
     Ok(Json(response))
 }
 
+/// Get all the gas prices
+#[utoipa::path(
+    get,
+    path = "/api/v1/gas-price",
+    tag = "gas",
+    responses(
+        (status = 200, body = Vec<GasPriceResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_all_gas_prices(
     _headers: HeaderMap,
@@ -39,6 +63,19 @@ pub async fn get_all_gas_prices(
     Ok(Json(response))
 }
 
+/// Get a gas estimate for a transaction
+#[utoipa::path(
+    get,
+    path = "/api/v1/gas/estimate",
+    tag = "gas",
+    params(
+        GasEstimateQuery,
+    ),
+    responses(
+        (status = 200, body = GasEstimateResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_gas_estimate(
     _headers: HeaderMap,
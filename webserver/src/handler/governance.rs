@@ -4,15 +4,30 @@ use axum::http::HeaderMap;
 use axum_macros::debug_handler;
 
 use crate::dto::governance::{ProposalQueryParams, ProposalVotesQueryparams};
+use crate::entity::cursor::cursor_limit;
 use crate::error::api::ApiError;
 use crate::error::governance::GovernanceError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::governance::{
     ProposalDataResponse, ProposalResponse, ProposalVoteResponse,
 };
 use crate::response::headers;
-use crate::response::utils::PaginatedResponse;
+use crate::response::utils::{CursorPaginatedResponse, PaginatedResponse};
 use crate::state::common::CommonState;
 
+/// Get a list of governance proposals
+#[utoipa::path(
+    get,
+    path = "/api/v1/gov/proposal",
+    tag = "governance",
+    params(
+        ProposalQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<ProposalResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_governance_proposals(
     _headers: HeaderMap,
@@ -43,6 +58,19 @@ pub async fn get_governance_proposals(
     )))
 }
 
+/// Get a governance proposal by id
+#[utoipa::path(
+    get,
+    path = "/api/v1/gov/proposal/{id}",
+    tag = "governance",
+    params(
+        ("id" = u64, Path, description = "Id of the proposal"),
+    ),
+    responses(
+        (status = 200, body = ProposalResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_governance_proposal_by_id(
     _headers: HeaderMap,
@@ -66,6 +94,19 @@ pub async fn get_governance_proposal_by_id(
     }
 }
 
+/// Get a governance proposal data by proposal id
+#[utoipa::path(
+    get,
+    path = "/api/v1/gov/proposal/{id}/data",
+    tag = "governance",
+    params(
+        ("id" = u64, Path, description = "Id of the proposal"),
+    ),
+    responses(
+        (status = 200, body = ProposalDataResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_proposal_data_by_proposal_id(
     _headers: HeaderMap,
@@ -80,13 +121,51 @@ pub async fn get_proposal_data_by_proposal_id(
     Ok((headers, Json(response)))
 }
 
+/// Get all the votes for a governance proposal
+///
+/// Paginated by page, or by cursor when a `cursor` is sent, in which
+/// case `pagination` holds the `nextCursor` instead of page counts, which
+/// are null
+#[utoipa::path(
+    get,
+    path = "/api/v1/gov/proposal/{id}/votes",
+    tag = "governance",
+    params(
+        ("id" = u64, Path, description = "Id of the proposal"),
+        ProposalVotesQueryparams,
+    ),
+    responses(
+        (status = 200, body = CursorPaginatedResponse<Vec<ProposalVoteResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_governance_proposal_votes(
     _headers: HeaderMap,
     Path(proposal_id): Path<u64>,
     Query(query): Query<ProposalVotesQueryparams>,
     State(state): State<CommonState>,
-) -> Result<Json<PaginatedResponse<Vec<ProposalVoteResponse>>>, ApiError> {
+) -> Result<Json<CursorPaginatedResponse<Vec<ProposalVoteResponse>>>, ApiError>
+{
+    if let Some(cursor) = &query.cursor {
+        let limit = cursor_limit(query.limit);
+        let (proposal_votes, next_cursor) = state
+            .gov_service
+            .find_governance_proposal_votes_after(proposal_id, cursor, limit)
+            .await?;
+
+        let response = proposal_votes
+            .into_iter()
+            .map(ProposalVoteResponse::from)
+            .collect::<Vec<_>>();
+
+        return Ok(Json(CursorPaginatedResponse::new(
+            response,
+            limit,
+            next_cursor,
+        )));
+    }
+
     let page = query.page.unwrap_or(1);
     let (proposal_votes, total_pages, total_votes) = state
         .gov_service
@@ -98,7 +177,7 @@ pub async fn get_governance_proposal_votes(
         .map(ProposalVoteResponse::from)
         .collect::<Vec<_>>();
 
-    Ok(Json(PaginatedResponse::new(
+    Ok(Json(CursorPaginatedResponse::from_page(
         response,
         page,
         total_pages,
@@ -106,6 +185,20 @@ pub async fn get_governance_proposal_votes(
     )))
 }
 
+/// Get all the votes for a governance proposal from an address
+#[utoipa::path(
+    get,
+    path = "/api/v1/gov/proposal/{id}/votes/{address}",
+    tag = "governance",
+    params(
+        ("id" = u64, Path, description = "Id of the proposal"),
+        ("address" = String, Path, description = "Address of the voter"),
+    ),
+    responses(
+        (status = 200, body = Vec<ProposalVoteResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_governance_proposal_votes_by_address(
     _headers: HeaderMap,
@@ -125,6 +218,19 @@ pub async fn get_governance_proposal_votes_by_address(
     Ok(Json(response))
 }
 
+/// Get all the votes from a voter
+#[utoipa::path(
+    get,
+    path = "/api/v1/gov/voter/{address}/votes",
+    tag = "governance",
+    params(
+        ("address" = String, Path, description = "Address of the voter"),
+    ),
+    responses(
+        (status = 200, body = Vec<ProposalVoteResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_governance_proposal_votes_by_voter(
     _headers: HeaderMap,
@@ -0,0 +1,50 @@
+use async_graphql::http::{GraphQLPlaygroundConfig, playground_source};
+use axum::Json;
+use axum::extract::State;
+use axum::response::Html;
+
+use crate::graphql::loader::IndexerLoader;
+use crate::state::common::CommonState;
+
+pub const GRAPHQL_PATH: &str = "/api/v1/graphql";
+
+/// Query the indexer as a graph
+///
+/// Queries deeper than `GRAPHQL_MAX_DEPTH` or more complex than
+/// `GRAPHQL_MAX_COMPLEXITY` are rejected
+#[utoipa::path(
+    post,
+    path = "/api/v1/graphql",
+    tag = "graphql",
+    request_body(
+        content = Object,
+        description = "GraphQL query, with its variables and operation name"
+    ),
+    responses(
+        (status = 200, description = "GraphQL response", body = Object),
+    )
+)]
+pub async fn graphql(
+    State(state): State<CommonState>,
+    Json(request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    let schema = state.graphql_schema.clone();
+    let loader = IndexerLoader::data_loader(state.clone());
+
+    Json(schema.execute(request.data(state).data(loader)).await)
+}
+
+/// GraphQL playground to explore the schema and build queries
+#[utoipa::path(
+    get,
+    path = "/api/v1/graphql",
+    tag = "graphql",
+    responses(
+        (status = 200, description = "GraphQL playground", content_type = "text/html"),
+    )
+)]
+pub async fn playground() -> Html<String> {
+    Html(playground_source(GraphQLPlaygroundConfig::new(
+        GRAPHQL_PATH,
+    )))
+}
@@ -6,15 +6,33 @@ use axum_macros::debug_handler;
 use bigdecimal::BigDecimal;
 
 use crate::dto::ibc::{
-    IbcRateLimit as IbcRateLimitDto, IbcTokenFlow as IbcTokenFlowDto,
+    IbcPacketsQueryParams, IbcRateLimit as IbcRateLimitDto,
+    IbcRateLimitUtilizationQueryParams, IbcTokenFlow as IbcTokenFlowDto,
+    IbcTransfersQueryParams,
 };
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::ibc::{
-    IbcAckResponse, IbcRateLimitResponse, IbcTokenFlowResponse,
-    IbcTokenThroughputResponse,
+    IbcAckResponse, IbcChannelResponse, IbcChannelStatsResponse,
+    IbcPacketResponse, IbcRateLimitResponse, IbcRateLimitUtilizationResponse,
+    IbcTokenFlowResponse, IbcTokenThroughputResponse, IbcTransferResponse,
 };
+use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
 
+/// Get the status of an IBC transfer by transaction id
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/{tx_id}/status",
+    tag = "ibc",
+    params(
+        ("tx_id" = String, Path, description = "Hash of the inner transaction"),
+    ),
+    responses(
+        (status = 200, body = IbcAckResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_ibc_status(
     _headers: HeaderMap,
@@ -28,6 +46,19 @@ pub async fn get_ibc_status(
     Ok(Json(response))
 }
 
+/// Get the rate limits of IBC tokens
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/rate-limits",
+    tag = "ibc",
+    params(
+        IbcRateLimitDto,
+    ),
+    responses(
+        (status = 200, body = Vec<IbcRateLimitResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_ibc_rate_limits(
     Query(query): Query<IbcRateLimitDto>,
@@ -49,6 +80,66 @@ pub async fn get_ibc_rate_limits(
     Ok(Json(response))
 }
 
+/// Get the share of their throughput limit IBC tokens consumed in each epoch,
+/// newest epoch first
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/rate-limits/utilization",
+    tag = "ibc",
+    params(
+        IbcRateLimitUtilizationQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<IbcRateLimitUtilizationResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_ibc_rate_limit_utilization(
+    Query(query): Query<IbcRateLimitUtilizationQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<
+    Json<PaginatedResponse<Vec<IbcRateLimitUtilizationResponse>>>,
+    ApiError,
+> {
+    let page = query.page.unwrap_or(1);
+
+    let (utilizations, total_pages, total_items) = state
+        .ibc_service
+        .get_rate_limit_utilization(
+            query.token_address,
+            query.from_epoch,
+            query.to_epoch,
+            page,
+        )
+        .await?;
+
+    let response = utilizations
+        .into_iter()
+        .map(IbcRateLimitUtilizationResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+/// Get the withdraw and deposit rates of IBC tokens
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/token-flows",
+    tag = "ibc",
+    params(
+        IbcTokenFlowDto,
+    ),
+    responses(
+        (status = 200, body = Vec<IbcTokenFlowResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_ibc_token_flows(
     Query(query): Query<IbcTokenFlowDto>,
@@ -67,6 +158,19 @@ pub async fn get_ibc_token_flows(
     Ok(Json(response))
 }
 
+/// Get the throughput of an IBC token
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/token-throughput/{token}",
+    tag = "ibc",
+    params(
+        ("token" = String, Path, description = "Address of the token"),
+    ),
+    responses(
+        (status = 200, body = IbcTokenThroughputResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_ibc_token_throughput(
     //_headers: HeaderMap,
@@ -79,3 +183,155 @@ pub async fn get_ibc_token_throughput(
 
     Ok(Json(response))
 }
+
+/// Get the IBC channels known to the chain, with their counterparty
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/channels",
+    tag = "ibc",
+    responses(
+        (status = 200, body = Vec<IbcChannelResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_ibc_channels(
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<IbcChannelResponse>>, ApiError> {
+    let channels = state.ibc_service.get_channels().await?;
+
+    let response = channels.into_iter().map(IbcChannelResponse::from).collect();
+
+    Ok(Json(response))
+}
+
+/// Get the packet outcomes and token volume of an IBC channel
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/channels/{channel}/stats",
+    tag = "ibc",
+    params(
+        ("channel" = String, Path, description = "Id of the channel on this chain"),
+    ),
+    responses(
+        (status = 200, body = IbcChannelStatsResponse),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_ibc_channel_stats(
+    Path(channel): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<IbcChannelStatsResponse>, ApiError> {
+    let stats = state.ibc_service.get_channel_stats(channel).await?;
+
+    let response = IbcChannelStatsResponse::from(stats);
+
+    Ok(Json(response))
+}
+
+/// Get the packets with a sequence, sent from or received on a channel
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/packet/{channel}/{sequence}",
+    tag = "ibc",
+    params(
+        ("channel" = String, Path, description = "Id of the channel on this chain"),
+        ("sequence" = u64, Path, description = "Sequence of the packet"),
+    ),
+    responses(
+        (status = 200, body = Vec<IbcPacketResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_ibc_packet(
+    Path((channel, sequence)): Path<(String, u64)>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<IbcPacketResponse>>, ApiError> {
+    let packets = state.ibc_service.get_packets(channel, sequence).await?;
+
+    let response = packets.into_iter().map(IbcPacketResponse::from).collect();
+
+    Ok(Json(response))
+}
+
+/// Get the packets sent or received by an address, on either chain
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/packets/{address}",
+    tag = "ibc",
+    params(
+        ("address" = String, Path, description = "Sender or receiver of the packets"),
+        IbcPacketsQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<IbcPacketResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_ibc_packets_by_address(
+    Path(address): Path<String>,
+    Query(query): Query<IbcPacketsQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcPacketResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (packets, total_pages, total_items) = state
+        .ibc_service
+        .get_packets_by_address(address, page)
+        .await?;
+
+    let response = packets
+        .into_iter()
+        .map(IbcPacketResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+/// Get the inbound and outbound IBC transfers of an address, newest first
+#[utoipa::path(
+    get,
+    path = "/api/v1/ibc/transfers/{address}",
+    tag = "ibc",
+    params(
+        ("address" = String, Path, description = "Address on this chain"),
+        IbcTransfersQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<IbcTransferResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_ibc_transfers_by_address(
+    Path(address): Path<String>,
+    Query(query): Query<IbcTransfersQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<IbcTransferResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (transfers, total_pages, total_items) = state
+        .ibc_service
+        .get_transfers_by_address(address, page)
+        .await?;
+
+    let response = transfers
+        .into_iter()
+        .map(IbcTransferResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
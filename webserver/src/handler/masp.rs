@@ -6,11 +6,25 @@ use axum_macros::debug_handler;
 
 use crate::dto::masp::MaspAggregatesQueryParams;
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::masp::{
     MaspPoolAggregateResponse, MaspRewardDataResponse,
 };
 use crate::state::common::CommonState;
 
+/// Get the inflows and outflows of the MASP pool
+#[utoipa::path(
+    get,
+    path = "/api/v1/masp/aggregates",
+    tag = "masp",
+    params(
+        MaspAggregatesQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<MaspPoolAggregateResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_masp_aggregates(
     _headers: HeaderMap,
@@ -30,6 +44,16 @@ pub async fn get_masp_aggregates(
     Ok(Json(response))
 }
 
+/// Get the MASP reward rates
+#[utoipa::path(
+    get,
+    path = "/api/v1/masp/rates",
+    tag = "masp",
+    responses(
+        (status = 200, body = Vec<MaspRewardDataResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_masp_rates(
     _headers: HeaderMap,
@@ -1,12 +1,17 @@
+pub mod account;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod crawler_state;
 pub mod gas;
 pub mod governance;
+pub mod graphql;
 pub mod ibc;
 pub mod masp;
+pub mod openapi;
 pub mod pgf;
 pub mod pk;
 pub mod pos;
+pub mod search;
+pub mod subscription;
 pub mod transaction;
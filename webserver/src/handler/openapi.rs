@@ -0,0 +1,38 @@
+use axum::Json;
+use axum::response::Html;
+use lazy_static::lazy_static;
+use utoipa::OpenApi;
+use utoipa_scalar::Scalar;
+
+use crate::openapi::ApiDoc;
+
+lazy_static! {
+    static ref OPENAPI: utoipa::openapi::OpenApi = ApiDoc::openapi();
+    static ref DOCS: String = Scalar::new(OPENAPI.clone()).to_html();
+}
+
+/// OpenAPI document of this api
+#[utoipa::path(
+    get,
+    path = "/api/v1/openapi.json",
+    tag = "status",
+    responses(
+        (status = 200, description = "OpenAPI 3.1 document", body = Object)
+    )
+)]
+pub async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(OPENAPI.clone())
+}
+
+/// Interactive documentation of this api
+#[utoipa::path(
+    get,
+    path = "/api/v1/docs",
+    tag = "status",
+    responses(
+        (status = 200, description = "Api reference", content_type = "text/html")
+    )
+)]
+pub async fn docs() -> Html<&'static str> {
+    Html(DOCS.as_str())
+}
@@ -6,6 +6,7 @@ use axum_macros::debug_handler;
 
 use crate::dto::pgf::PgfQueryParams;
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::pgf::PgfPaymentResponse;
 use crate::response::utils::PaginatedResponse;
 use crate::state::common::CommonState;
@@ -34,6 +35,19 @@ pub async fn get_pgf_continuous_payments(
     )))
 }
 
+/// Get the pgf payments of a proposal
+#[utoipa::path(
+    get,
+    path = "/api/v1/pgf/payments/{proposal_id}",
+    tag = "pgf",
+    params(
+        ("proposal_id" = u64, Path, description = "Id of the funding proposal"),
+    ),
+    responses(
+        (status = 200, body = Vec<PgfPaymentResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_pgf_payment_by_proposal_id(
     _headers: HeaderMap,
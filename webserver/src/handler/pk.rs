@@ -4,10 +4,24 @@ use axum::http::HeaderMap;
 use axum_macros::debug_handler;
 
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
 use crate::response::headers;
 use crate::response::revealed_pk::RevealedPkResponse;
 use crate::state::common::CommonState;
 
+/// Get the revealed public key of an address, if any
+#[utoipa::path(
+    get,
+    path = "/api/v1/revealed-public-key/{address}",
+    tag = "account",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+    ),
+    responses(
+        (status = 200, body = RevealedPkResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_revealed_pk(
     _headers: HeaderMap,
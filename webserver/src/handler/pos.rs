@@ -1,21 +1,44 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::HeaderMap;
+use axum::response::Response;
 use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 
+use crate::dto::export::ExportQueryParams;
 use crate::dto::pos::{
-    AllValidatorsQueryParams, BondsDto, RewardsDto, UnbondsDto,
-    ValidatorQueryParams, ValidatorStateDto, WithdrawsDto,
+    AllValidatorsQueryParams, BondsDto, RewardHistoryDto, RewardsCursorDto,
+    RewardsDto, SlashesQueryParams, UnbondsDto, UptimeQueryParams,
+    ValidatorHistoryQueryParams, ValidatorQueryParams, ValidatorStateDto,
+    WithdrawsDto,
 };
+use crate::entity::cursor::cursor_limit;
+use crate::entity::pos::uptime_window;
 use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
+use crate::response::export::export_response;
 use crate::response::pos::{
-    BondResponse, MergedBondResponse, RewardResponse, TotalVotingPowerResponse,
-    UnbondResponse, ValidatorWithRankResponse, WithdrawResponse,
+    BondResponse, DelegatorSummaryResponse, MergedBondResponse,
+    RewardAccrualResponse, RewardResponse, SlashResponse,
+    TotalVotingPowerResponse, UnbondResponse, ValidatorHistoryResponse,
+    ValidatorUptimeResponse, ValidatorWithRankResponse, WithdrawResponse,
 };
-use crate::response::utils::PaginatedResponse;
+use crate::response::utils::{CursorPaginatedResponse, PaginatedResponse};
 use crate::state::common::CommonState;
 
+/// Get all validators, paginated
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/validator",
+    tag = "pos",
+    params(
+        ValidatorQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<ValidatorWithRankResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_validators(
     _headers: HeaderMap,
@@ -42,6 +65,19 @@ pub async fn get_validators(
     )))
 }
 
+/// Get all validators, non paginated
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/validator/all",
+    tag = "pos",
+    params(
+        AllValidatorsQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<ValidatorWithRankResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_all_validators(
     _headers: HeaderMap,
@@ -59,13 +95,196 @@ pub async fn get_all_validators(
     Ok(Json(response))
 }
 
+/// Get the bonds, unbonds, withdrawable amount and unclaimed rewards of a
+/// delegator in a single response
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/delegator/{address}/summary",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the delegator"),
+    ),
+    responses(
+        (status = 200, body = DelegatorSummaryResponse),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_delegator_summary(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<DelegatorSummaryResponse>, ApiError> {
+    let summary = state.pos_service.get_delegator_summary(address).await?;
+
+    Ok(Json(DelegatorSummaryResponse::from(summary)))
+}
+
+/// Get the slashes of all validators, most recently processed first
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/slashes",
+    tag = "pos",
+    params(
+        SlashesQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<SlashResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_slashes(
+    _headers: HeaderMap,
+    Query(query): Query<SlashesQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<SlashResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (slashes, total_pages, total_slashes) =
+        state.pos_service.get_slashes(page).await?;
+
+    let response = slashes.into_iter().map(SlashResponse::from).collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_slashes,
+    )))
+}
+
+/// Get the slashes of a validator, most recently processed first
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/validator/{address}/slashes",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the validator"),
+    ),
+    responses(
+        (status = 200, body = Vec<SlashResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_validator_slashes(
+    _headers: HeaderMap,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<SlashResponse>>, ApiError> {
+    let slashes = state.pos_service.get_validator_slashes(address).await?;
+
+    let response = slashes.into_iter().map(SlashResponse::from).collect();
+
+    Ok(Json(response))
+}
+
+/// Get the voting power, commission and state of a validator at each epoch
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/validator/{address}/history",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the validator"),
+        ValidatorHistoryQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<ValidatorHistoryResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_validator_history(
+    _headers: HeaderMap,
+    Query(query): Query<ValidatorHistoryQueryParams>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<ValidatorHistoryResponse>>, ApiError> {
+    let history = state
+        .pos_service
+        .get_validator_history(address, query.from_epoch, query.to_epoch)
+        .await?;
+
+    let response = history
+        .into_iter()
+        .map(ValidatorHistoryResponse::from)
+        .collect();
+
+    Ok(Json(response))
+}
+
+/// Get the blocks signed and missed by a validator over the last `window`
+/// epochs
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/validator/{address}/uptime",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the validator"),
+        UptimeQueryParams,
+    ),
+    responses(
+        (status = 200, body = ValidatorUptimeResponse),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_validator_uptime(
+    _headers: HeaderMap,
+    Query(query): Query<UptimeQueryParams>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<ValidatorUptimeResponse>, ApiError> {
+    let uptime = state
+        .pos_service
+        .get_validator_uptime(address.clone(), uptime_window(query.window))
+        .await?;
+
+    Ok(Json(ValidatorUptimeResponse::from(address, uptime)))
+}
+
+/// Get all the bonds for an address
+///
+/// Paginated by page, or by cursor when a `cursor` is sent, in which
+/// case `pagination` holds the `nextCursor` instead of page counts, which
+/// are null
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/bond/{address}",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        BondsDto,
+    ),
+    responses(
+        (status = 200, body = CursorPaginatedResponse<Vec<BondResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_bonds(
     _headers: HeaderMap,
     query: Query<BondsDto>,
     Path(address): Path<String>,
     State(state): State<CommonState>,
-) -> Result<Json<PaginatedResponse<Vec<BondResponse>>>, ApiError> {
+) -> Result<Json<CursorPaginatedResponse<Vec<BondResponse>>>, ApiError> {
+    if let Some(cursor) = &query.cursor {
+        let limit = cursor_limit(query.limit);
+        let (bonds, next_cursor) = state
+            .pos_service
+            .get_bonds_by_address_after(address, cursor, limit, query.active_at)
+            .await?;
+
+        let response = bonds.into_iter().map(BondResponse::from).collect();
+
+        return Ok(Json(CursorPaginatedResponse::new(
+            response,
+            limit,
+            next_cursor,
+        )));
+    }
+
     let page = query.page.unwrap_or(1);
 
     let (bonds, total_pages, total_bonds) = state
@@ -75,7 +294,7 @@ pub async fn get_bonds(
 
     let response = bonds.into_iter().map(BondResponse::from).collect();
 
-    Ok(Json(PaginatedResponse::new(
+    Ok(Json(CursorPaginatedResponse::from_page(
         response,
         page,
         total_pages,
@@ -83,6 +302,21 @@ pub async fn get_bonds(
     )))
 }
 
+/// Get all the bonds for an address, with merged amounts, regardless of the
+/// status
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/merged-bonds/{address}",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        BondsDto,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<MergedBondResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_merged_bonds(
     _headers: HeaderMap,
@@ -107,13 +341,52 @@ pub async fn get_merged_bonds(
     )))
 }
 
+/// Get all the unbonds for an address
+///
+/// Paginated by page, or by cursor when a `cursor` is sent, in which
+/// case `pagination` holds the `nextCursor` instead of page counts, which
+/// are null
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/unbond/{address}",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        UnbondsDto,
+    ),
+    responses(
+        (status = 200, body = CursorPaginatedResponse<Vec<UnbondResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_unbonds(
     _headers: HeaderMap,
     query: Query<UnbondsDto>,
     Path(address): Path<String>,
     State(state): State<CommonState>,
-) -> Result<Json<PaginatedResponse<Vec<UnbondResponse>>>, ApiError> {
+) -> Result<Json<CursorPaginatedResponse<Vec<UnbondResponse>>>, ApiError> {
+    if let Some(cursor) = &query.cursor {
+        let limit = cursor_limit(query.limit);
+        let (unbonds, next_cursor) = state
+            .pos_service
+            .get_unbonds_by_address_after(
+                address,
+                cursor,
+                limit,
+                query.active_at,
+            )
+            .await?;
+
+        let response = unbonds.into_iter().map(UnbondResponse::from).collect();
+
+        return Ok(Json(CursorPaginatedResponse::new(
+            response,
+            limit,
+            next_cursor,
+        )));
+    }
+
     let page = query.page.unwrap_or(1);
 
     let (unbonds, total_pages, total_unbonds) = state
@@ -123,7 +396,7 @@ pub async fn get_unbonds(
 
     let response = unbonds.into_iter().map(UnbondResponse::from).collect();
 
-    Ok(Json(PaginatedResponse::new(
+    Ok(Json(CursorPaginatedResponse::from_page(
         response,
         page,
         total_pages,
@@ -131,6 +404,20 @@ pub async fn get_unbonds(
     )))
 }
 
+/// Get all the unbonds for an address with merged withdraw amounts
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/merged-unbonds/{address}",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        UnbondsDto,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<UnbondResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_merged_unbonds(
     _headers: HeaderMap,
@@ -155,6 +442,20 @@ pub async fn get_merged_unbonds(
     )))
 }
 
+/// Get all the withdraws for an address at a specific epoch
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/withdraw/{address}",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        WithdrawsDto,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<WithdrawResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_withdraws(
     _headers: HeaderMap,
@@ -179,6 +480,20 @@ pub async fn get_withdraws(
     )))
 }
 
+/// Get all the rewards for an address
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/reward/{address}",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        RewardsDto,
+    ),
+    responses(
+        (status = 200, body = Vec<RewardResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_rewards(
     _headers: HeaderMap,
@@ -190,11 +505,143 @@ pub async fn get_rewards(
         .pos_service
         .get_rewards_by_address(address, query.epoch)
         .await?;
-    let response = rewards.into_iter().map(RewardResponse::from).collect();
+    let response = rewards
+        .into_iter()
+        .map(RewardResponse::from)
+        .collect::<Vec<_>>();
 
     Ok(Json(response))
 }
 
+/// Get the rewards for an address, paginated by cursor
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/reward/{address}/paginated",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        RewardsCursorDto,
+    ),
+    responses(
+        (status = 200, body = CursorPaginatedResponse<Vec<RewardResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_rewards_paginated(
+    _headers: HeaderMap,
+    query: Query<RewardsCursorDto>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<CursorPaginatedResponse<Vec<RewardResponse>>>, ApiError> {
+    let limit = cursor_limit(query.limit);
+    let (rewards, next_cursor) = state
+        .pos_service
+        .get_rewards_by_address_after(
+            address,
+            query.cursor.as_deref().unwrap_or_default(),
+            limit,
+            query.epoch,
+        )
+        .await?;
+
+    let response = rewards.into_iter().map(RewardResponse::from).collect();
+
+    Ok(Json(CursorPaginatedResponse::new(
+        response,
+        limit,
+        next_cursor,
+    )))
+}
+
+/// Get the rewards an address earned at each epoch, most recent first
+///
+/// Each entry is the amount a delegation accrued during the epoch, along with
+/// the transaction that claimed it once it was claimed.
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/reward/{address}/history",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the delegator"),
+        RewardHistoryDto,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<RewardAccrualResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_reward_history(
+    _headers: HeaderMap,
+    Query(query): Query<RewardHistoryDto>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<RewardAccrualResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (accruals, total_pages, total_accruals) = state
+        .pos_service
+        .get_reward_history(address, page, query.from_epoch, query.to_epoch)
+        .await?;
+
+    let response = accruals
+        .into_iter()
+        .map(RewardAccrualResponse::from)
+        .collect();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_accruals,
+    )))
+}
+
+/// Export the rewards an address earned in every epoch
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/reward/{address}/export",
+    tag = "pos",
+    params(
+        ("address" = String, Path, description = "Address of the account"),
+        ExportQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Rewards as a csv or ndjson attachment", content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn export_rewards(
+    _headers: HeaderMap,
+    Query(export): Query<ExportQueryParams>,
+    Path(address): Path<String>,
+    State(state): State<CommonState>,
+) -> Result<Response, ApiError> {
+    let rows = state.pos_service.export_rewards(address).await?;
+
+    Ok(export_response(export.format, "rewards", rows))
+}
+
+/// Get rewards for a single delegator for a specific epoch
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/reward/{delegator}/{validator}/{epoch}",
+    tag = "pos",
+    params(
+        ("delegator" = String, Path, description = "Address of the delegator"),
+        ("validator" = String, Path, description = "Address of the validator"),
+        ("epoch" = u64, Path, description = "Epoch of the rewards"),
+    ),
+    responses(
+        (status = 200, body = Vec<RewardResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_rewards_by_delegator_and_validator_and_epoch(
     _headers: HeaderMap,
@@ -212,6 +659,16 @@ pub async fn get_rewards_by_delegator_and_validator_and_epoch(
     Ok(Json(response))
 }
 
+/// Get the total voting power
+#[utoipa::path(
+    get,
+    path = "/api/v1/pos/voting-power",
+    tag = "pos",
+    responses(
+        (status = 200, body = TotalVotingPowerResponse),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_total_voting_power(
     _headers: HeaderMap,
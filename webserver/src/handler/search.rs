@@ -0,0 +1,45 @@
+use axum::Json;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum_extra::extract::Query;
+use axum_macros::debug_handler;
+
+use crate::dto::search::SearchQueryParams;
+use crate::error::api::ApiError;
+use crate::response::api::ApiErrorResponses;
+use crate::response::search::SearchResultResponse;
+use crate::state::common::CommonState;
+
+/// Search addresses, transactions, blocks, validators and proposals
+///
+/// Addresses, 64 character hashes, block heights and proposal ids are looked
+/// up exactly. Other inputs are fuzzy matched against validator names,
+/// emails and websites, proposal contents and transaction memos. Results are
+/// ranked by score, exact matches first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/search",
+    tag = "search",
+    params(
+        SearchQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<SearchResultResponse>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn search(
+    _headers: HeaderMap,
+    Query(query): Query<SearchQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<Vec<SearchResultResponse>>, ApiError> {
+    let results = state.search_service.search(query.q, query.limit).await?;
+
+    Ok(Json(
+        results
+            .into_iter()
+            .map(SearchResultResponse::from)
+            .collect(),
+    ))
+}
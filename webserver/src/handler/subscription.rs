@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+
+use axum::extract::State;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::response::Response;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::dto::subscription::{
+    MAX_TOPICS, SubscriptionAction, SubscriptionMessage,
+};
+use crate::entity::subscription::Topic;
+use crate::error::subscription::SubscriptionError;
+use crate::response::subscription::SubscriptionResponse;
+use crate::state::common::CommonState;
+
+/// Subscribe to indexer events over a websocket
+///
+/// Clients send `{"action": "subscribe" | "unsubscribe", "topics": [...]}`
+/// messages and receive the events of the topics they subscribed to
+#[utoipa::path(
+    get,
+    path = "/api/v1/ws",
+    tag = "subscription",
+    responses(
+        (status = 101, description = "Switching to the websocket protocol"),
+    )
+)]
+pub async fn subscribe(
+    ws: WebSocketUpgrade,
+    State(state): State<CommonState>,
+) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: CommonState) {
+    let mut events = state.subscription_service.subscribe();
+    let mut topics = HashSet::<Topic>::new();
+
+    loop {
+        tokio::select! {
+            message = socket.recv() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue,
+                };
+
+                let response = match update_topics(&mut topics, &text) {
+                    Ok(response) => response,
+                    Err(e) => SubscriptionResponse::error(e),
+                };
+
+                if send(&mut socket, &response).await.is_err() {
+                    break;
+                }
+            }
+            events = events.recv() => {
+                let events = match events {
+                    Ok(events) => events,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            "Subscriber lagged behind, skipped {} notifications",
+                            skipped
+                        );
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                for event in events.iter().filter(|e| topics.contains(&e.topic)) {
+                    if send(&mut socket, &SubscriptionResponse::from(event))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn update_topics(
+    topics: &mut HashSet<Topic>,
+    message: &str,
+) -> Result<SubscriptionResponse, SubscriptionError> {
+    let message = SubscriptionMessage::parse(message)?;
+    let requested = message.topics()?;
+
+    match message.action {
+        SubscriptionAction::Subscribe => {
+            let new_topics = requested
+                .iter()
+                .filter(|topic| !topics.contains(topic))
+                .collect::<HashSet<_>>();
+
+            if topics.len() + new_topics.len() > MAX_TOPICS {
+                return Err(SubscriptionError::TooManyTopics(MAX_TOPICS));
+            }
+
+            topics.extend(requested.iter().cloned());
+            Ok(SubscriptionResponse::subscribed(&requested))
+        }
+        SubscriptionAction::Unsubscribe => {
+            for topic in &requested {
+                topics.remove(topic);
+            }
+            Ok(SubscriptionResponse::unsubscribed(&requested))
+        }
+    }
+}
+
+async fn send(
+    socket: &mut WebSocket,
+    response: &SubscriptionResponse,
+) -> Result<(), axum::Error> {
+    let message = serde_json::to_string(response)
+        .expect("Subscription response should serialize to json");
+
+    socket.send(Message::Text(message.into())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(action: &str, topics: &[String]) -> String {
+        serde_json::json!({ "action": action, "topics": topics }).to_string()
+    }
+
+    fn proposals(ids: std::ops::Range<u64>) -> Vec<String> {
+        ids.map(|id| format!("proposal:{}", id)).collect()
+    }
+
+    #[test]
+    fn subscribe_and_unsubscribe() {
+        let mut topics = HashSet::new();
+
+        let response = update_topics(
+            &mut topics,
+            &message(
+                "subscribe",
+                &["block".to_string(), "proposal:1".to_string()],
+            ),
+        )
+        .unwrap();
+        assert!(matches!(
+            response,
+            SubscriptionResponse::Subscribed { topics } if topics.len() == 2
+        ));
+        assert_eq!(topics, HashSet::from([Topic::Block, Topic::Proposal(1)]));
+
+        update_topics(
+            &mut topics,
+            &message("unsubscribe", &["block".to_string()]),
+        )
+        .unwrap();
+        assert_eq!(topics, HashSet::from([Topic::Proposal(1)]));
+    }
+
+    #[test]
+    fn invalid_messages_leave_topics_untouched() {
+        let mut topics = HashSet::from([Topic::Block]);
+
+        assert!(matches!(
+            update_topics(&mut topics, "not json"),
+            Err(SubscriptionError::InvalidMessage(_))
+        ));
+        assert!(matches!(
+            update_topics(&mut topics, &message("subscribe", &[])),
+            Err(SubscriptionError::InvalidMessage(_))
+        ));
+        assert!(matches!(
+            update_topics(
+                &mut topics,
+                &message(
+                    "subscribe",
+                    &["proposal:2".to_string(), "unknown".to_string()]
+                )
+            ),
+            Err(SubscriptionError::InvalidTopic(_))
+        ));
+        assert_eq!(topics, HashSet::from([Topic::Block]));
+    }
+
+    #[test]
+    fn topics_are_capped() {
+        let mut topics = HashSet::new();
+
+        update_topics(
+            &mut topics,
+            &message("subscribe", &proposals(0..MAX_TOPICS as u64)),
+        )
+        .unwrap();
+        assert_eq!(topics.len(), MAX_TOPICS);
+
+        // Topics already subscribed to don't count towards the cap
+        update_topics(&mut topics, &message("subscribe", &proposals(0..10)))
+            .unwrap();
+
+        assert!(matches!(
+            update_topics(
+                &mut topics,
+                &message("subscribe", &proposals(40..MAX_TOPICS as u64 + 1))
+            ),
+            Err(SubscriptionError::TooManyTopics(MAX_TOPICS))
+        ));
+        assert_eq!(topics.len(), MAX_TOPICS);
+
+        // More than the cap can't be requested in a single message either
+        let mut topics = HashSet::new();
+        assert!(matches!(
+            update_topics(
+                &mut topics,
+                &message("subscribe", &proposals(0..MAX_TOPICS as u64 + 1))
+            ),
+            Err(SubscriptionError::InvalidMessage(_))
+        ));
+        assert!(topics.is_empty());
+    }
+}
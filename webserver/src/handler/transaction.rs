@@ -1,26 +1,44 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::HeaderMap;
+use axum::response::Response;
 use axum_extra::extract::Query;
 use axum_macros::debug_handler;
 use serde_json;
 
 use crate::constant::ITEM_PER_PAGE;
+use crate::dto::export::ExportQueryParams;
 use crate::dto::transaction::{
-    TransactionHistoryQueryParams, TransactionIdParam,
+    MemoQueryParams, TransactionHistoryQueryParams, TransactionIdParam,
     TransactionMostRecentQueryParams,
 };
+use crate::entity::cursor::cursor_limit;
 use crate::entity::transaction::{InnerTransaction, TransactionKind};
 use crate::error::api::ApiError;
 use crate::error::transaction::TransactionError;
+use crate::response::api::ApiErrorResponses;
+use crate::response::export::export_response;
 use crate::response::headers;
 use crate::response::transaction::{
     InnerTransactionResponse, TransactionHistoryResponse,
     WrapperTransactionResponse,
 };
-use crate::response::utils::PaginatedResponse;
+use crate::response::utils::{CursorPaginatedResponse, PaginatedResponse};
 use crate::state::common::CommonState;
 
+/// Get the wrapper transaction by hash
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/wrapper/{id}",
+    tag = "transaction",
+    params(
+        ("id" = String, Path, description = "Hash of the transaction"),
+    ),
+    responses(
+        (status = 200, body = Option<WrapperTransactionResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_wrapper_tx(
     _headers: HeaderMap,
@@ -52,6 +70,19 @@ pub async fn get_wrapper_tx(
     Ok((headers, Json(response)))
 }
 
+/// Get the inner transaction by hash
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/inner/{id}",
+    tag = "transaction",
+    params(
+        ("id" = String, Path, description = "Hash of the transaction"),
+    ),
+    responses(
+        (status = 200, body = Option<InnerTransactionResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_inner_tx(
     _headers: HeaderMap,
@@ -73,26 +104,113 @@ pub async fn get_inner_tx(
     Ok((headers, Json(response)))
 }
 
+/// Get the inner transactions with a given memo
+///
+/// Memos are matched on their text decoded from UTF-8, either exactly or by
+/// prefix. Memos that are not valid UTF-8 are never matched.
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/memo",
+    tag = "transaction",
+    params(
+        MemoQueryParams,
+    ),
+    responses(
+        (status = 200, body = PaginatedResponse<Vec<InnerTransactionResponse>>),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn get_inner_txs_by_memo(
+    _headers: HeaderMap,
+    Query(query): Query<MemoQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Json<PaginatedResponse<Vec<InnerTransactionResponse>>>, ApiError> {
+    let page = query.page.unwrap_or(1);
+
+    let (inner_txs, total_pages, total_items) = state
+        .transaction_service
+        .get_inner_txs_by_memo(
+            query.value,
+            query.matching.unwrap_or_default(),
+            query.address,
+            page,
+        )
+        .await?;
+
+    let response = inner_txs
+        .into_iter()
+        .map(InnerTransactionResponse::new)
+        .collect::<Vec<_>>();
+
+    Ok(Json(PaginatedResponse::new(
+        response,
+        page,
+        total_pages,
+        total_items,
+    )))
+}
+
+/// Get the transactions of a list of addresses
+///
+/// Paginated by page, or by cursor when a `cursor` is sent, in which
+/// case `pagination` holds the `nextCursor` instead of page counts, which
+/// are null
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/history",
+    tag = "transaction",
+    params(
+        TransactionHistoryQueryParams,
+    ),
+    responses(
+        (status = 200, body = CursorPaginatedResponse<Vec<TransactionHistoryResponse>>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_transaction_history(
     _headers: HeaderMap,
     Query(query): Query<TransactionHistoryQueryParams>,
     State(state): State<CommonState>,
-) -> Result<Json<PaginatedResponse<Vec<TransactionHistoryResponse>>>, ApiError>
-{
+) -> Result<
+    Json<CursorPaginatedResponse<Vec<TransactionHistoryResponse>>>,
+    ApiError,
+> {
+    let filter = query.filter()?;
+
+    if let Some(cursor) = &query.cursor {
+        let limit = cursor_limit(query.limit);
+        let (transactions, next_cursor) = state
+            .transaction_service
+            .get_addresses_history_after(query.addresses, filter, cursor, limit)
+            .await?;
+
+        let response = transactions
+            .into_iter()
+            .map(TransactionHistoryResponse::from)
+            .collect::<Vec<_>>();
+
+        return Ok(Json(CursorPaginatedResponse::new(
+            response,
+            limit,
+            next_cursor,
+        )));
+    }
+
     let page = query.page.unwrap_or(1);
 
     let (transactions, total_pages, total_items) = state
         .transaction_service
-        .get_addresses_history(query.addresses, page)
+        .get_addresses_history(query.addresses, filter, page)
         .await?;
 
     let response = transactions
         .into_iter()
         .map(TransactionHistoryResponse::from)
-        .collect();
+        .collect::<Vec<_>>();
 
-    Ok(Json(PaginatedResponse::new(
+    Ok(Json(CursorPaginatedResponse::from_page(
         response,
         page,
         total_pages,
@@ -100,6 +218,54 @@ pub async fn get_transaction_history(
     )))
 }
 
+/// Export the transaction history of a list of addresses
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/history/export",
+    tag = "transaction",
+    params(
+        TransactionHistoryQueryParams,
+        ExportQueryParams,
+    ),
+    responses(
+        (status = 200, description = "Transactions as a csv or ndjson attachment", content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )),
+        ApiErrorResponses,
+    )
+)]
+#[debug_handler]
+pub async fn export_transaction_history(
+    _headers: HeaderMap,
+    Query(query): Query<TransactionHistoryQueryParams>,
+    Query(export): Query<ExportQueryParams>,
+    State(state): State<CommonState>,
+) -> Result<Response, ApiError> {
+    let filter = query.filter()?;
+
+    let rows = state
+        .transaction_service
+        .export_addresses_history(state.client.clone(), query.addresses, filter)
+        .await?;
+
+    Ok(export_response(export.format, "transactions", rows))
+}
+
+/// Get the most recent wrapper transactions with optional filtering by inner
+/// transaction kind and transfer token
+#[utoipa::path(
+    get,
+    path = "/api/v1/chain/wrapper/recent",
+    tag = "transaction",
+    params(
+        TransactionMostRecentQueryParams,
+    ),
+    responses(
+        (status = 200, body = Vec<WrapperTransactionResponse>),
+        ApiErrorResponses,
+    )
+)]
 #[debug_handler]
 pub async fn get_most_recent_transactions(
     _headers: HeaderMap,
@@ -5,7 +5,10 @@ pub mod constant;
 pub mod dto;
 pub mod entity;
 pub mod error;
+pub mod graphql;
 pub mod handler;
+pub mod middleware;
+pub mod openapi;
 pub mod repository;
 pub mod response;
 pub mod service;
@@ -0,0 +1,307 @@
+use axum::body::{Body, HttpBody, to_bytes};
+use axum::extract::{Request, State};
+use axum::http::{
+    HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header,
+};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use subtle_encoding::base64;
+
+use crate::service::cache::{CachePolicy, ResponseCache};
+
+/// Larger responses, or responses of unknown size, are served without being
+/// cached so that caching never buffers an unbounded body
+const MAX_CACHED_BODY_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Middleware serving successful responses from the cache, keyed by the
+/// request path and query under `policy`.
+pub async fn cache<C: ResponseCache>(
+    State((cache, policy)): State<(C, CachePolicy)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::GET {
+        return next.run(request).await;
+    }
+
+    let Some(key) = request
+        .uri()
+        .path_and_query()
+        .and_then(|uri| cache.key(policy, uri.as_str()))
+    else {
+        return next.run(request).await;
+    };
+
+    if let Some(cached) = cache
+        .get(&key)
+        .await
+        .and_then(|value| CachedResponse::decode(&value))
+    {
+        return cached.into_response();
+    }
+
+    let response = next.run(request).await;
+    if !is_cacheable(&response) {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    match to_bytes(body, MAX_CACHED_BODY_SIZE as usize).await {
+        Ok(bytes) => {
+            let cached = CachedResponse::new(&parts.headers, &bytes);
+            cache.set(&key, &cached.encode(), policy.ttl).await;
+            Response::from_parts(parts, Body::from(bytes))
+        }
+        Err(e) => {
+            tracing::warn!("Failed to buffer response {}: {}", key, e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+fn is_cacheable(response: &Response) -> bool {
+    response.status() == StatusCode::OK
+        && response
+            .body()
+            .size_hint()
+            .upper()
+            .is_some_and(|size| size <= MAX_CACHED_BODY_SIZE)
+}
+
+/// A response as stored in the cache, with its headers so that hits are
+/// served exactly like the response they were cached from
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResponse {
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl CachedResponse {
+    fn new(headers: &HeaderMap, body: &[u8]) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| *name != header::CONTENT_LENGTH)
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+
+        Self {
+            headers,
+            body: String::from_utf8(base64::encode(body))
+                .expect("Base64 should be valid utf8"),
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        serde_json::to_vec(self)
+            .expect("Cached response should serialize to json")
+    }
+
+    /// Values that can't be decoded are treated as a miss
+    fn decode(value: &[u8]) -> Option<Self> {
+        serde_json::from_slice(value)
+            .inspect_err(|e| tracing::warn!("Invalid cached response: {}", e))
+            .ok()
+    }
+}
+
+impl IntoResponse for CachedResponse {
+    fn into_response(self) -> Response {
+        let Ok(body) = base64::decode(self.body) else {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        };
+
+        let mut response = Response::new(Body::from(body));
+        let headers = response.headers_mut();
+        for (name, value) in self.headers {
+            if let (Ok(name), Ok(value)) =
+                (HeaderName::try_from(name), HeaderValue::try_from(value))
+            {
+                headers.append(name, value);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use axum::Router;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::service::cache::{self, ChainVersion};
+
+    /// In memory cache at a chain version that tests move forward
+    #[derive(Clone, Default)]
+    struct MemoryCache {
+        block: Arc<AtomicI32>,
+        values: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    }
+
+    #[async_trait]
+    impl ResponseCache for MemoryCache {
+        fn key(&self, policy: CachePolicy, request: &str) -> Option<String> {
+            let version = ChainVersion {
+                block: self.block.load(Ordering::SeqCst),
+                epoch: 0,
+            };
+
+            Some(version.key(policy, request))
+        }
+
+        async fn get(&self, key: &str) -> Option<Vec<u8>> {
+            self.values.lock().unwrap().get(key).cloned()
+        }
+
+        async fn set(&self, key: &str, value: &[u8], _ttl: u64) {
+            self.values
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_vec());
+        }
+    }
+
+    /// Router whose handler responds with `body` and `status`, and counts
+    /// how many times it ran
+    fn router(
+        cache: MemoryCache,
+        calls: Arc<AtomicUsize>,
+        status: StatusCode,
+        body: String,
+    ) -> Router {
+        let handler = move || {
+            let calls = calls.clone();
+            let body = body.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                (
+                    status,
+                    [
+                        (header::CONTENT_TYPE, "application/json"),
+                        (header::CACHE_CONTROL, "public, max-age=10"),
+                    ],
+                    body,
+                )
+            }
+        };
+
+        Router::new().route(
+            "/chain/parameters",
+            get(handler).route_layer(axum::middleware::from_fn_with_state(
+                (cache, cache::VALIDATORS),
+                super::cache::<MemoryCache>,
+            )),
+        )
+    }
+
+    async fn call(router: &Router) -> Response {
+        router
+            .clone()
+            .oneshot(
+                Request::get("/chain/parameters?page=1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn body(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn hits_are_served_with_the_cached_headers() {
+        let cache = MemoryCache::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = router(
+            cache.clone(),
+            calls.clone(),
+            StatusCode::OK,
+            r#"{"epoch":4}"#.to_string(),
+        );
+
+        let miss = call(&router).await;
+        assert_eq!(body(miss).await, r#"{"epoch":4}"#);
+
+        let hit = call(&router).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(hit.status(), StatusCode::OK);
+        assert_eq!(
+            hit.headers().get(header::CONTENT_TYPE),
+            Some(&HeaderValue::from_static("application/json"))
+        );
+        assert_eq!(
+            hit.headers().get(header::CACHE_CONTROL),
+            Some(&HeaderValue::from_static("public, max-age=10"))
+        );
+        assert_eq!(body(hit).await, r#"{"epoch":4}"#);
+    }
+
+    #[tokio::test]
+    async fn new_blocks_are_a_miss() {
+        let cache = MemoryCache::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = router(
+            cache.clone(),
+            calls.clone(),
+            StatusCode::OK,
+            "[]".to_string(),
+        );
+
+        call(&router).await;
+        cache.block.fetch_add(1, Ordering::SeqCst);
+        call(&router).await;
+        call(&router).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let cache = MemoryCache::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let router = router(
+            cache.clone(),
+            calls.clone(),
+            StatusCode::NOT_FOUND,
+            "{}".to_string(),
+        );
+
+        call(&router).await;
+        call(&router).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(cache.values.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn large_responses_are_not_cached() {
+        let cache = MemoryCache::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let large = "0".repeat(MAX_CACHED_BODY_SIZE as usize + 1);
+        let router =
+            router(cache.clone(), calls.clone(), StatusCode::OK, large.clone());
+
+        let response = call(&router).await;
+        assert_eq!(body(response).await.len(), large.len());
+        call(&router).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(cache.values.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, ensure};
+use axum::extract::{ConnectInfo, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum_prometheus::metrics;
+use serde::Deserialize;
+
+use crate::config::AppConfig;
+use crate::error::rate_limit::RateLimitError;
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Endpoints hitting the database hard enough to get their own, lower, quota
+const EXPENSIVE_PATHS: [&str; 3] = [
+    "/api/v1/chain/history",
+    "/api/v1/pos/validator/all",
+    "/api/v1/search",
+];
+/// Streaming exports read the whole history of an address
+const EXPENSIVE_PATH_SUFFIX: &str = "/export";
+
+/// Buckets untouched for this long are full again and can be dropped
+const IDLE_BUCKET_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quota {
+    pub rps: u64,
+    pub expensive_rps: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiKey {
+    key: String,
+    #[serde(flatten)]
+    quota: Quota,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Client {
+    Ip(IpAddr),
+    ApiKey(String),
+}
+
+impl Client {
+    fn label(&self) -> &'static str {
+        match self {
+            Client::Ip(_) => "ip",
+            Client::ApiKey(_) => "api_key",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum QuotaKind {
+    Default,
+    Expensive,
+}
+
+impl QuotaKind {
+    fn from_path(path: &str) -> Self {
+        let path = path.trim_end_matches('/');
+
+        if EXPENSIVE_PATHS.contains(&path)
+            || path.ends_with(EXPENSIVE_PATH_SUFFIX)
+        {
+            QuotaKind::Expensive
+        } else {
+            QuotaKind::Default
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            QuotaKind::Default => "default",
+            QuotaKind::Expensive => "expensive",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(rps: u64, now: Instant) -> Self {
+        Self {
+            tokens: rps as f64,
+            updated_at: now,
+        }
+    }
+
+    /// Take a token out of the bucket, refilled at `rps` tokens per second up
+    /// to a burst of `rps`. Returns the seconds to wait when empty.
+    fn take(&mut self, rps: u64, now: Instant) -> Result<(), u64> {
+        let rate = rps as f64;
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * rate).min(rate);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err((((1.0 - self.tokens) / rate).ceil() as u64).max(1))
+        }
+    }
+}
+
+#[derive(Default)]
+struct Buckets {
+    buckets: HashMap<(Client, QuotaKind), TokenBucket>,
+    cleaned_at: Option<Instant>,
+}
+
+#[derive(Clone)]
+pub struct RateLimiter {
+    default_quota: Option<Quota>,
+    api_keys: Arc<HashMap<String, Quota>>,
+    trust_forwarded_for: bool,
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &AppConfig) -> anyhow::Result<Self> {
+        let default_quota = config.rps.map(|rps| Quota {
+            rps,
+            expensive_rps: config.expensive_rps.unwrap_or((rps / 10).max(1)),
+        });
+
+        let api_keys = match &config.api_keys_path {
+            Some(path) => {
+                let file = std::fs::read_to_string(path)
+                    .with_context(|| format!("could not read {}", path))?;
+                let api_keys = serde_json::from_str::<Vec<ApiKey>>(&file)
+                    .with_context(|| format!("could not parse {}", path))?;
+
+                // Keys are secrets, so they are identified by their position
+                for (index, api_key) in api_keys.iter().enumerate() {
+                    ensure!(
+                        api_key.quota.rps > 0
+                            && api_key.quota.expensive_rps > 0,
+                        "quotas of api key #{} in {} must be positive",
+                        index,
+                        path
+                    );
+                }
+
+                api_keys
+                    .into_iter()
+                    .map(|api_key| (api_key.key, api_key.quota))
+                    .collect()
+            }
+            None => HashMap::new(),
+        };
+
+        Ok(Self {
+            default_quota,
+            api_keys: Arc::new(api_keys),
+            trust_forwarded_for: config.trust_forwarded_for,
+            buckets: Arc::new(Mutex::new(Buckets::default())),
+        })
+    }
+
+    /// Client making the request and its quota, if any
+    fn client(
+        &self,
+        request: &Request,
+    ) -> Result<(Client, Option<Quota>), RateLimitError> {
+        let api_key = request
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        match api_key {
+            Some(key) => match self.api_keys.get(key) {
+                Some(quota) => {
+                    Ok((Client::ApiKey(key.to_string()), Some(*quota)))
+                }
+                None => Err(RateLimitError::InvalidApiKey),
+            },
+            None => {
+                let ip = self
+                    .client_ip(request)
+                    .unwrap_or(IpAddr::from([0, 0, 0, 0]));
+                Ok((Client::Ip(ip), self.default_quota))
+            }
+        }
+    }
+
+    fn check(
+        &self,
+        client: Client,
+        quota: Quota,
+        kind: QuotaKind,
+        now: Instant,
+    ) -> Result<(), RateLimitError> {
+        let rps = match kind {
+            QuotaKind::Default => quota.rps,
+            QuotaKind::Expensive => quota.expensive_rps,
+        };
+
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if buckets
+            .cleaned_at
+            .is_none_or(|cleaned_at| now - cleaned_at > IDLE_BUCKET_TTL)
+        {
+            buckets
+                .buckets
+                .retain(|_, bucket| now - bucket.updated_at < IDLE_BUCKET_TTL);
+            buckets.cleaned_at = Some(now);
+        }
+
+        let label = client.label();
+        buckets
+            .buckets
+            .entry((client, kind))
+            .or_insert_with(|| TokenBucket::full(rps, now))
+            .take(rps, now)
+            .map_err(|retry_after| {
+                metrics::counter!(
+                    "rate_limit_rejected_requests_total",
+                    "client" => label,
+                    "quota" => kind.label()
+                )
+                .increment(1);
+
+                RateLimitError::TooManyRequests(retry_after)
+            })
+    }
+
+    /// Clients can send any X-Forwarded-For header, so only its last entry,
+    /// appended by the trusted reverse proxy, identifies them
+    fn client_ip(&self, request: &Request) -> Option<IpAddr> {
+        let forwarded_for = self
+            .trust_forwarded_for
+            .then(|| request.headers().get_all("x-forwarded-for").iter().last())
+            .flatten()
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse().ok());
+
+        forwarded_for.or_else(|| {
+            request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip())
+        })
+    }
+}
+
+/// Middleware enforcing a token bucket per api key, or per ip for anonymous
+/// clients, with a separate bucket for expensive endpoints.
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (client, quota) = match limiter.client(&request) {
+        Ok(client) => client,
+        Err(e) => return e.into_response(),
+    };
+
+    if let Some(quota) = quota {
+        let kind = QuotaKind::from_path(request.uri().path());
+
+        if let Err(e) = limiter.check(client, quota, kind, Instant::now()) {
+            return e.into_response();
+        }
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+
+    use super::*;
+
+    const QUOTA: Quota = Quota {
+        rps: 2,
+        expensive_rps: 1,
+    };
+
+    fn limiter() -> RateLimiter {
+        RateLimiter {
+            default_quota: Some(QUOTA),
+            api_keys: Arc::new(HashMap::from([(
+                "key".to_string(),
+                Quota {
+                    rps: 5,
+                    expensive_rps: 5,
+                },
+            )])),
+            trust_forwarded_for: false,
+            buckets: Arc::new(Mutex::new(Buckets::default())),
+        }
+    }
+
+    fn request(path: &str, api_key: Option<&str>) -> Request {
+        let mut request = Request::builder().uri(path);
+        if let Some(api_key) = api_key {
+            request = request.header(API_KEY_HEADER, api_key);
+        }
+
+        request.body(Body::empty()).unwrap()
+    }
+
+    fn ip(last: u8) -> Client {
+        Client::Ip(IpAddr::from([10, 0, 0, last]))
+    }
+
+    #[test]
+    fn requests_over_the_quota_are_rejected() {
+        let limiter = limiter();
+        let now = Instant::now();
+
+        for _ in 0..QUOTA.rps {
+            assert!(
+                limiter.check(ip(1), QUOTA, QuotaKind::Default, now).is_ok()
+            );
+        }
+
+        assert!(matches!(
+            limiter.check(ip(1), QUOTA, QuotaKind::Default, now),
+            Err(RateLimitError::TooManyRequests(1))
+        ));
+    }
+
+    #[test]
+    fn buckets_refill_over_time() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::full(2, now);
+
+        assert!(bucket.take(2, now).is_ok());
+        assert!(bucket.take(2, now).is_ok());
+        assert!(bucket.take(2, now).is_err());
+
+        let later = now + Duration::from_millis(500);
+        assert!(bucket.take(2, later).is_ok());
+        assert!(bucket.take(2, later).is_err());
+
+        // Idle buckets never hold more than a second worth of requests
+        let much_later = later + Duration::from_secs(60);
+        assert!(bucket.take(2, much_later).is_ok());
+        assert!(bucket.take(2, much_later).is_ok());
+        assert!(bucket.take(2, much_later).is_err());
+    }
+
+    #[test]
+    fn clients_and_quotas_have_separate_buckets() {
+        let limiter = limiter();
+        let now = Instant::now();
+
+        assert!(
+            limiter
+                .check(ip(1), QUOTA, QuotaKind::Expensive, now)
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .check(ip(1), QUOTA, QuotaKind::Expensive, now)
+                .is_err()
+        );
+        assert!(limiter.check(ip(1), QUOTA, QuotaKind::Default, now).is_ok());
+        assert!(
+            limiter
+                .check(ip(2), QUOTA, QuotaKind::Expensive, now)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn clients_are_identified_by_api_key() {
+        let limiter = limiter();
+
+        let (client, quota) = limiter
+            .client(&request("/api/v1/search", Some("key")))
+            .unwrap();
+        assert_eq!(client, Client::ApiKey("key".to_string()));
+        assert_eq!(quota.map(|quota| quota.rps), Some(5));
+
+        let (client, quota) =
+            limiter.client(&request("/api/v1/search", None)).unwrap();
+        assert_eq!(client, Client::Ip(IpAddr::from([0, 0, 0, 0])));
+        assert_eq!(quota.map(|quota| quota.rps), Some(QUOTA.rps));
+
+        assert!(matches!(
+            limiter.client(&request("/api/v1/search", Some("unknown"))),
+            Err(RateLimitError::InvalidApiKey)
+        ));
+    }
+
+    #[test]
+    fn clients_are_identified_by_the_last_forwarded_for_entry() {
+        let limiter = RateLimiter {
+            trust_forwarded_for: true,
+            ..limiter()
+        };
+        let forwarded = |values: &[&str]| {
+            let mut request = Request::builder().uri("/api/v1/search");
+            for value in values {
+                request = request.header("x-forwarded-for", *value);
+            }
+            let request = request.body(Body::empty()).unwrap();
+
+            limiter.client(&request).unwrap().0
+        };
+
+        assert_eq!(forwarded(&["10.0.0.1"]), ip(1));
+        // Entries prepended by the client are ignored
+        assert_eq!(forwarded(&["1.2.3.4, 5.6.7.8, 10.0.0.1"]), ip(1));
+        assert_eq!(forwarded(&["9.9.9.9, 10.0.0.1"]), ip(1));
+        assert_eq!(forwarded(&["1.2.3.4", "10.0.0.2"]), ip(2));
+
+        // The peer address is used without a valid forwarded address
+        assert_eq!(
+            forwarded(&["10.0.0.1, not-an-ip"]),
+            Client::Ip(IpAddr::from([0, 0, 0, 0]))
+        );
+    }
+
+    #[test]
+    fn expensive_paths_are_detected() {
+        assert_eq!(
+            QuotaKind::from_path("/api/v1/search/"),
+            QuotaKind::Expensive
+        );
+        assert_eq!(
+            QuotaKind::from_path("/api/v1/pos/reward/tnam1q/export"),
+            QuotaKind::Expensive
+        );
+        assert_eq!(
+            QuotaKind::from_path("/api/v1/pos/validator"),
+            QuotaKind::Default
+        );
+    }
+}
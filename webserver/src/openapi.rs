@@ -0,0 +1,212 @@
+use utoipa::OpenApi;
+
+use crate::dto::crawler_state::CrawlerNameDto;
+use crate::dto::export::ExportFormat;
+use crate::dto::governance::{ProposalKind, ProposalStatus};
+use crate::dto::pos::{OrderByDto, ValidatorSortFieldDto, ValidatorStateDto};
+use crate::entity::transaction::{
+    MemoMatch, TransactionExitStatus, TransactionHistoryKind, TransactionKind,
+};
+use crate::handler::{
+    account, balance, block, chain, crawler_state, gas, governance, graphql,
+    ibc, masp, openapi, pgf, pk, pos, search, subscription, transaction,
+};
+use crate::response::api::ApiErrorResponse;
+use crate::response::export::ExportRowResponse;
+
+const DESCRIPTION: &str =
+    "Set of API to interact with a namada indexer.
+
+Requests are rate limited per client ip, or per api key when one is sent in \
+     the `x-api-key` header. Expensive endpoints (`/api/v1/chain/history`, \
+     `/api/v1/pos/validator/all`, `/api/v1/search` and the exports) have a \
+     lower quota. Rejected requests get a `429` response with a `Retry-After` \
+     header; unknown api keys get a `401`.";
+
+/// OpenAPI document of the webserver, generated from the handlers. Every
+/// route registered in `app.rs` must be listed here.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Namada Interface Indexer REST Api",
+        description = DESCRIPTION,
+        contact(
+            email = "hello@heliax.dev",
+            url = "https://github.com/namada-net/namada-indexer"
+        )
+    ),
+    servers(
+        (url = "http://localhost:5001"),
+        (url = "https://indexer.namada.tududes.com")
+    ),
+    paths(
+        health,
+        metrics,
+        openapi::openapi,
+        openapi::docs,
+        pos::get_validators,
+        pos::get_all_validators,
+        pos::get_validator_history,
+        pos::get_validator_uptime,
+        pos::get_validator_slashes,
+        pos::get_slashes,
+        pos::get_delegator_summary,
+        pos::get_bonds,
+        pos::get_merged_bonds,
+        pos::get_unbonds,
+        pos::get_merged_unbonds,
+        pos::get_withdraws,
+        pos::get_rewards,
+        pos::get_rewards_paginated,
+        pos::export_rewards,
+        pos::get_reward_history,
+        pos::get_rewards_by_delegator_and_validator_and_epoch,
+        pos::get_total_voting_power,
+        governance::get_governance_proposals,
+        governance::get_governance_proposal_by_id,
+        governance::get_proposal_data_by_proposal_id,
+        governance::get_governance_proposal_votes,
+        governance::get_governance_proposal_votes_by_address,
+        governance::get_governance_proposal_votes_by_voter,
+        balance::get_address_balance,
+        balance::get_address_balance_history,
+        pk::get_revealed_pk,
+        account::get_account_info,
+        search::search,
+        gas::get_gas_estimate,
+        gas::get_gas_price_by_token,
+        gas::get_all_gas_prices,
+        transaction::get_wrapper_tx,
+        transaction::get_most_recent_transactions,
+        transaction::get_inner_tx,
+        transaction::get_inner_txs_by_memo,
+        transaction::get_transaction_history,
+        transaction::export_transaction_history,
+        chain::get_parameters,
+        chain::get_rpc_url,
+        chain::get_tokens,
+        chain::get_token_supply,
+        chain::get_circulating_supply,
+        chain::get_last_processed_block,
+        chain::get_last_processed_epoch,
+        chain::get_epoch_estimate,
+        chain::chain_status,
+        ibc::get_ibc_status,
+        ibc::get_ibc_rate_limits,
+        ibc::get_ibc_rate_limit_utilization,
+        ibc::get_ibc_token_flows,
+        ibc::get_ibc_token_throughput,
+        ibc::get_ibc_channels,
+        ibc::get_ibc_channel_stats,
+        ibc::get_ibc_packet,
+        ibc::get_ibc_packets_by_address,
+        ibc::get_ibc_transfers_by_address,
+        pgf::get_pgf_payment_by_proposal_id,
+        crawler_state::get_crawlers_timestamps,
+        subscription::subscribe,
+        graphql::playground,
+        graphql::graphql,
+        block::get_block_by_height,
+        block::get_block_by_timestamp,
+        block::get_block_by_hash,
+        block::get_block_transactions,
+        block::get_blocks,
+        block::get_latest_blocks,
+        masp::get_masp_aggregates,
+        masp::get_masp_rates,
+    ),
+    components(schemas(
+        ApiErrorResponse,
+        ExportRowResponse,
+        ExportFormat,
+        ValidatorStateDto,
+        ValidatorSortFieldDto,
+        OrderByDto,
+        ProposalStatus,
+        ProposalKind,
+        CrawlerNameDto,
+        TransactionKind,
+        TransactionExitStatus,
+        TransactionHistoryKind,
+        MemoMatch,
+    ))
+)]
+pub struct ApiDoc;
+
+/// Health check, with the commit and version of the running binary
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "status",
+    responses(
+        (status = 200, description = "Commit and version", body = Object,
+            example = json!({"commit": "8a32a55", "version": "4.1.3"}))
+    )
+)]
+#[allow(dead_code)]
+fn health() {}
+
+/// Prometheus metrics of the webserver
+#[utoipa::path(
+    get,
+    path = "/api/v1/metrics",
+    tag = "status",
+    responses(
+        (status = 200, description = "Metrics in the prometheus text format",
+            content_type = "text/plain")
+    )
+)]
+#[allow(dead_code)]
+fn metrics() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const API_PREFIX: &str = "/api/v1";
+
+    /// Paths of the `.route(...)` calls in `app.rs`
+    fn app_routes() -> Vec<String> {
+        include_str!("app.rs")
+            .split(".route(")
+            .skip(1)
+            .filter_map(|call| {
+                let call = call.trim_start().strip_prefix('"')?;
+                call.split('"').next().map(str::to_string)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_route_is_documented() {
+        let document = ApiDoc::openapi();
+        let routes = app_routes();
+        assert!(!routes.is_empty());
+
+        let missing = routes
+            .iter()
+            .filter(|route| {
+                let nested = format!("{API_PREFIX}{route}");
+                !document.paths.paths.contains_key(&nested)
+                    && !document.paths.paths.contains_key(*route)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            missing.is_empty(),
+            "routes missing from the OpenAPI document: {missing:?}"
+        );
+    }
+
+    #[test]
+    fn swagger_yml_is_up_to_date() {
+        let generated = ApiDoc::openapi()
+            .to_yaml()
+            .expect("OpenAPI document should serialize to yaml");
+
+        assert!(
+            generated == include_str!("../../swagger.yml"),
+            "swagger.yml is outdated, regenerate it with `just openapi`"
+        );
+    }
+}
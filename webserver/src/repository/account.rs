@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use diesel::{
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+};
+use orm::accounts::{AccountDb, AccountPublicKeyDb};
+use orm::schema::{account_public_keys, accounts};
+
+use crate::appstate::AppState;
+
+#[derive(Clone)]
+pub struct AccountRepo {
+    pub(crate) app_state: AppState,
+}
+
+#[async_trait]
+pub trait AccountRepoTrait {
+    fn new(app_state: AppState) -> Self;
+
+    async fn find_account_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<AccountDb>, String>;
+
+    async fn find_account_public_keys(
+        &self,
+        address: String,
+    ) -> Result<Vec<AccountPublicKeyDb>, String>;
+}
+
+#[async_trait]
+impl AccountRepoTrait for AccountRepo {
+    fn new(app_state: AppState) -> Self {
+        Self { app_state }
+    }
+
+    async fn find_account_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<AccountDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            accounts::table
+                .filter(accounts::dsl::address.eq(address))
+                .select(AccountDb::as_select())
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_account_public_keys(
+        &self,
+        address: String,
+    ) -> Result<Vec<AccountPublicKeyDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            account_public_keys::table
+                .filter(account_public_keys::dsl::address.eq(address))
+                .order(account_public_keys::dsl::key_index.asc())
+                .select(AccountPublicKeyDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+}
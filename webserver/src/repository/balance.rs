@@ -1,10 +1,14 @@
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
-use orm::balances::BalanceDb;
-use orm::schema::{ibc_token, token};
+use diesel::{
+    ExpressionMethods, NullableExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl, SelectableHelper,
+};
+use orm::balances::{BalanceChangeDb, BalanceDb};
+use orm::schema::{balance_changes, blocks, ibc_token, token};
 use orm::token::{IbcTokenDb, TokenDb};
 use orm::views::balances;
 
+use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
 
 #[derive(Clone)]
@@ -24,6 +28,27 @@ pub trait BalanceRepoTrait {
         &self,
         address: String,
     ) -> Result<Vec<BalanceDb>, String>;
+
+    async fn get_address_balances_at_height(
+        &self,
+        address: String,
+        height: i32,
+    ) -> Result<Vec<BalanceDb>, String>;
+
+    async fn get_address_balance_history(
+        &self,
+        address: String,
+        token: String,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(BalanceChangeDb, Option<chrono::NaiveDateTime>)>,
+        String,
+    >;
+
+    async fn find_last_height_at_timestamp(
+        &self,
+        timestamp: chrono::NaiveDateTime,
+    ) -> Result<Option<i32>, String>;
 }
 
 #[async_trait]
@@ -49,6 +74,91 @@ impl BalanceRepoTrait for BalanceRepo {
         .map_err(|e| e.to_string())
     }
 
+    /// Gets the latest balance change of each token at or before the given
+    /// height
+    async fn get_address_balances_at_height(
+        &self,
+        address: String,
+        height: i32,
+    ) -> Result<Vec<BalanceDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            balance_changes::table
+                .filter(balance_changes::dsl::owner.eq(address))
+                .filter(balance_changes::dsl::height.le(height))
+                .distinct_on(balance_changes::dsl::token)
+                .order((
+                    balance_changes::dsl::token,
+                    balance_changes::dsl::height.desc(),
+                ))
+                .select((
+                    balance_changes::dsl::owner,
+                    balance_changes::dsl::token,
+                    balance_changes::dsl::raw_amount,
+                ))
+                .load::<BalanceDb>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_address_balance_history(
+        &self,
+        address: String,
+        token: String,
+        page: i64,
+    ) -> Result<
+        PaginatedResponseDb<(BalanceChangeDb, Option<chrono::NaiveDateTime>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            balance_changes::table
+                .left_join(blocks::table)
+                .filter(balance_changes::dsl::owner.eq(address))
+                .filter(balance_changes::dsl::token.eq(token))
+                .order(balance_changes::dsl::height.asc())
+                .select((
+                    (
+                        balance_changes::dsl::owner,
+                        balance_changes::dsl::token,
+                        balance_changes::dsl::raw_amount,
+                        balance_changes::dsl::height,
+                    ),
+                    blocks::dsl::timestamp.nullable(),
+                ))
+                .paginate(page)
+                .load_and_count_pages::<(BalanceChangeDb, Option<chrono::NaiveDateTime>)>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Gets the height of the last block produced at or before the given
+    /// timestamp
+    async fn find_last_height_at_timestamp(
+        &self,
+        timestamp: chrono::NaiveDateTime,
+    ) -> Result<Option<i32>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            blocks::table
+                .filter(blocks::dsl::timestamp.le(timestamp))
+                .order(blocks::dsl::timestamp.desc())
+                .select(blocks::dsl::height)
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_all_token(
         &self,
     ) -> Result<Vec<(TokenDb, Option<IbcTokenDb>)>, String> {
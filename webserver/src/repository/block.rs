@@ -28,6 +28,18 @@ pub trait BlockRepositoryTrait {
         &self,
         hash: String,
     ) -> Result<Option<BlockDb>, String>;
+
+    /// Blocks between the two heights, both included, by ascending height
+    async fn find_blocks_by_height_range(
+        &self,
+        from_height: i32,
+        to_height: i32,
+    ) -> Result<Vec<BlockDb>, String>;
+
+    async fn find_latest_blocks(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<BlockDb>, String>;
 }
 
 #[async_trait]
@@ -96,4 +108,42 @@ impl BlockRepositoryTrait for BlockRepository {
         .await
         .map_err(|e| e.to_string())
     }
+
+    async fn find_blocks_by_height_range(
+        &self,
+        from_height: i32,
+        to_height: i32,
+    ) -> Result<Vec<BlockDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            blocks::table
+                .filter(blocks::height.ge(from_height))
+                .filter(blocks::height.le(to_height))
+                .order(blocks::height.asc())
+                .select(BlockDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_latest_blocks(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<BlockDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            blocks::table
+                .order(blocks::height.desc())
+                .limit(limit)
+                .select(BlockDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use diesel::dsl::max;
+use chrono::NaiveDateTime;
+use diesel::dsl::{max, min};
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, JoinOnDsl, OptionalExtension,
     QueryDsl, RunQueryDsl, SelectableHelper,
@@ -7,7 +8,8 @@ use diesel::{
 use orm::crawler_state::{ChainCrawlerStateDb, CrawlerNameDb};
 use orm::parameters::ParametersDb;
 use orm::schema::{
-    chain_parameters, crawler_state, ibc_token, token, token_supplies_per_epoch,
+    blocks, chain_parameters, crawler_state, ibc_token, token,
+    token_supplies_per_epoch,
 };
 use orm::token::{IbcTokenDb, TokenDb};
 use orm::token_supplies_per_epoch::TokenSuppliesDb;
@@ -31,6 +33,11 @@ pub trait ChainRepositoryTrait {
 
     async fn get_state(&self) -> Result<ChainCrawlerStateDb, String>;
 
+    async fn find_epoch_start_times(
+        &self,
+        from_epoch: i32,
+    ) -> Result<Vec<(i32, NaiveDateTime)>, String>;
+
     async fn find_tokens(
         &self,
     ) -> Result<Vec<(TokenDb, Option<IbcTokenDb>)>, String>;
@@ -110,6 +117,30 @@ impl ChainRepositoryTrait for ChainRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_epoch_start_times(
+        &self,
+        from_epoch: i32,
+    ) -> Result<Vec<(i32, NaiveDateTime)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            blocks::table
+                .filter(blocks::dsl::epoch.ge(from_epoch))
+                .group_by(blocks::dsl::epoch)
+                .select((blocks::dsl::epoch, min(blocks::dsl::timestamp)))
+                .load::<(Option<i32>, Option<NaiveDateTime>)>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+        .map(|starts| {
+            starts
+                .into_iter()
+                .filter_map(|(epoch, timestamp)| epoch.zip(timestamp))
+                .collect()
+        })
+    }
+
     async fn find_tokens(
         &self,
     ) -> Result<Vec<(TokenDb, Option<IbcTokenDb>)>, String> {
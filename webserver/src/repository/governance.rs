@@ -47,12 +47,24 @@ pub trait GovernanceRepoTrait {
         proposal_id: i32,
     ) -> Result<Option<GovernanceProposalNoDataDb>, String>;
 
+    async fn find_governance_proposals_by_ids(
+        &self,
+        proposal_ids: Vec<i32>,
+    ) -> Result<Vec<GovernanceProposalNoDataDb>, String>;
+
     async fn find_governance_proposal_votes(
         &self,
         proposal_id: i32,
         page: i64,
     ) -> Result<PaginatedResponseDb<GovernanceProposalVoteDb>, String>;
 
+    async fn find_governance_proposal_votes_after(
+        &self,
+        proposal_id: i32,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<GovernanceProposalVoteDb>, String>;
+
     async fn find_governance_proposal_votes_by_address(
         &self,
         proposal_id: i32,
@@ -63,6 +75,13 @@ pub trait GovernanceRepoTrait {
         &self,
         voter_address: String,
     ) -> Result<Vec<GovernanceProposalVoteDb>, String>;
+
+    async fn find_governance_proposal_votes_by_voter_after(
+        &self,
+        voter_address: String,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<GovernanceProposalVoteDb>, String>;
 }
 
 #[async_trait]
@@ -146,6 +165,23 @@ impl GovernanceRepoTrait for GovernanceRepo {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_governance_proposals_by_ids(
+        &self,
+        proposal_ids: Vec<i32>,
+    ) -> Result<Vec<GovernanceProposalNoDataDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            governance_proposals::table
+                .filter(governance_proposals::dsl::id.eq_any(proposal_ids))
+                .select(GovernanceProposalNoDataDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_governance_proposal_votes(
         &self,
         proposal_id: i32,
@@ -165,6 +201,34 @@ impl GovernanceRepoTrait for GovernanceRepo {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_governance_proposal_votes_after(
+        &self,
+        proposal_id: i32,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<GovernanceProposalVoteDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = governance_votes::table
+                .filter(governance_votes::dsl::proposal_id.eq(proposal_id))
+                .into_boxed();
+
+            if let Some(id) = after {
+                query = query.filter(governance_votes::dsl::id.gt(id));
+            }
+
+            query
+                .order(governance_votes::dsl::id.asc())
+                .limit(limit)
+                .select(GovernanceProposalVoteDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_governance_proposal_votes_by_address(
         &self,
         proposal_id: i32,
@@ -201,6 +265,34 @@ impl GovernanceRepoTrait for GovernanceRepo {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_governance_proposal_votes_by_voter_after(
+        &self,
+        voter_address: String,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<GovernanceProposalVoteDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = governance_votes::table
+                .filter(governance_votes::dsl::voter_address.eq(voter_address))
+                .into_boxed();
+
+            if let Some(id) = after {
+                query = query.filter(governance_votes::dsl::id.gt(id));
+            }
+
+            query
+                .order(governance_votes::dsl::id.asc())
+                .limit(limit)
+                .select(GovernanceProposalVoteDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
 
 #[allow(clippy::needless_lifetimes)]
@@ -1,14 +1,52 @@
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
+use diesel::dsl::{count_star, sum};
+use diesel::sql_types::{Integer, Nullable, Text};
 use diesel::{
-    ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
-    SelectableHelper,
+    BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
+    NullableExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+    SelectableHelper, TextExpressionMethods, define_sql_function,
+};
+use orm::ibc::{
+    IbcAckDb, IbcAckStatusDb, IbcChannelDb, IbcClientDb, IbcConnectionDb,
+    IbcPacketDb, IbcPacketDirectionDb,
+};
+use orm::schema::{
+    ibc_ack, ibc_channels, ibc_clients, ibc_connections, ibc_packets,
+    ibc_rate_limits, ibc_token, ibc_token_flows, inner_transactions,
+    transaction_history, wrapper_transactions,
+};
+use orm::transactions::{
+    InnerTransactionDb, TransactionHistoryDb, TransactionKindDb,
 };
-use orm::ibc::IbcAckDb;
-use orm::schema::{ibc_ack, ibc_rate_limits, ibc_token_flows};
 
+use super::utils::{Paginate, PaginatedResponseDb};
 use crate::appstate::AppState;
 
+define_sql_function! {
+    fn split_part(string: Text, delimiter: Text, field: Integer) -> Text;
+}
+
+pub type IbcChannelRow =
+    (IbcChannelDb, Option<IbcConnectionDb>, Option<IbcClientDb>);
+
+/// A history entry of an IBC transfer, with its block height, its packet and
+/// the trace of the transferred token, when those are indexed
+/// History entry, inner transaction, block height, packets sent and received
+/// by the transaction and IBC trace of the token
+pub type IbcTransferRow = (
+    TransactionHistoryDb,
+    InnerTransactionDb,
+    i32,
+    Option<IbcPacketDb>,
+    Option<IbcPacketDb>,
+    Option<String>,
+);
+
+/// Token address, epoch, deposit, withdraw and throughput limit
+pub type IbcRateLimitUsageRow =
+    (String, i32, BigDecimal, BigDecimal, BigDecimal);
+
 #[derive(Clone)]
 pub struct IbcRepository {
     pub(crate) app_state: AppState,
@@ -38,6 +76,64 @@ pub trait IbcRepositoryTrait {
         &self,
         token_address: String,
     ) -> Result<(String, String), String>;
+
+    /// Channels along with the connection and client they are built on, when
+    /// those are indexed
+    async fn find_channels(&self) -> Result<Vec<IbcChannelRow>, String>;
+
+    async fn find_channel(
+        &self,
+        channel_id: String,
+    ) -> Result<Option<IbcChannelRow>, String>;
+
+    /// Number of packets sent over the channel, per acknowledgement status
+    async fn get_channel_packet_counts(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<Vec<(IbcAckStatusDb, i64)>, String>;
+
+    /// Total deposits and withdrawals of the tokens whose trace goes through
+    /// the channel
+    async fn get_channel_token_flows(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<
+        Vec<(String, String, Option<BigDecimal>, Option<BigDecimal>)>,
+        String,
+    >;
+
+    /// Packets with the given sequence, sent from or received on the channel
+    async fn find_packets_by_channel_and_sequence(
+        &self,
+        channel_id: String,
+        sequence: i64,
+    ) -> Result<Vec<IbcPacketDb>, String>;
+
+    /// Packets whose sender or receiver is the address, newest first
+    async fn find_packets_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcPacketDb>, String>;
+
+    /// IBC transfers in the history of the address, newest first
+    async fn find_transfers_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcTransferRow>, String>;
+
+    /// Token flows of each epoch along with the rate limit of that epoch,
+    /// newest first
+    async fn find_rate_limit_usage(
+        &self,
+        token_address: Option<String>,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcRateLimitUsageRow>, String>;
 }
 
 #[async_trait]
@@ -221,4 +317,294 @@ impl IbcRepositoryTrait for IbcRepository {
         .await
         .map_err(|e| e.to_string())?
     }
+
+    async fn find_channels(&self) -> Result<Vec<IbcChannelRow>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_channels::table
+                .left_join(
+                    ibc_connections::table.on(ibc_connections::connection_id
+                        .eq(ibc_channels::connection_id)),
+                )
+                .left_join(
+                    ibc_clients::table.on(ibc_clients::client_id
+                        .nullable()
+                        .eq(ibc_connections::client_id.nullable())),
+                )
+                .order(ibc_channels::channel_id.asc())
+                .select((
+                    IbcChannelDb::as_select(),
+                    Option::<IbcConnectionDb>::as_select(),
+                    Option::<IbcClientDb>::as_select(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_channel(
+        &self,
+        channel_id: String,
+    ) -> Result<Option<IbcChannelRow>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_channels::table
+                .left_join(
+                    ibc_connections::table.on(ibc_connections::connection_id
+                        .eq(ibc_channels::connection_id)),
+                )
+                .left_join(
+                    ibc_clients::table.on(ibc_clients::client_id
+                        .nullable()
+                        .eq(ibc_connections::client_id.nullable())),
+                )
+                .filter(ibc_channels::channel_id.eq(channel_id))
+                .select((
+                    IbcChannelDb::as_select(),
+                    Option::<IbcConnectionDb>::as_select(),
+                    Option::<IbcClientDb>::as_select(),
+                ))
+                .first(conn)
+                .optional()
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_channel_packet_counts(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<Vec<(IbcAckStatusDb, i64)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        // Ack ids are `dest_port/dest_channel/src_port/src_channel/sequence`
+        conn.interact(move |conn| {
+            ibc_ack::table
+                .filter(split_part(ibc_ack::id, "/", 3).eq(port_id))
+                .filter(split_part(ibc_ack::id, "/", 4).eq(channel_id))
+                .group_by(ibc_ack::status)
+                .select((ibc_ack::status, count_star()))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn get_channel_token_flows(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<
+        Vec<(String, String, Option<BigDecimal>, Option<BigDecimal>)>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_token_flows::table
+                .inner_join(
+                    ibc_token::table
+                        .on(ibc_token::address.eq(ibc_token_flows::address)),
+                )
+                .filter(
+                    ibc_token::ibc_trace
+                        .like(format!("{}/{}/%", port_id, channel_id)),
+                )
+                .group_by(ibc_token::address)
+                .select((
+                    ibc_token::address,
+                    ibc_token::ibc_trace,
+                    sum(ibc_token_flows::deposit),
+                    sum(ibc_token_flows::withdraw),
+                ))
+                .order(ibc_token::address.asc())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_packets_by_channel_and_sequence(
+        &self,
+        channel_id: String,
+        sequence: i64,
+    ) -> Result<Vec<IbcPacketDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_packets::table
+                .filter(ibc_packets::sequence.eq(sequence))
+                .filter(
+                    ibc_packets::direction
+                        .eq(IbcPacketDirectionDb::Sent)
+                        .and(ibc_packets::source_channel.eq(channel_id.clone()))
+                        .or(ibc_packets::direction
+                            .eq(IbcPacketDirectionDb::Received)
+                            .and(ibc_packets::dest_channel.eq(channel_id))),
+                )
+                .select(IbcPacketDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_packets_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcPacketDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            ibc_packets::table
+                .filter(
+                    ibc_packets::sender
+                        .eq(address.clone())
+                        .or(ibc_packets::receiver.eq(address)),
+                )
+                .order((
+                    diesel::dsl::sql::<Nullable<Integer>>(
+                        "COALESCE(send_block_height, recv_block_height)",
+                    )
+                    .desc(),
+                    ibc_packets::id.asc(),
+                ))
+                .select(IbcPacketDb::as_select())
+                .paginate(page)
+                .load_and_count_pages::<IbcPacketDb>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_transfers_by_address(
+        &self,
+        address: String,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcTransferRow>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            // The token is the first element of the IBC transfer data
+            let token_address = diesel::dsl::sql::<Text>(
+                "COALESCE(inner_transactions.data::jsonb #>> \
+                 '{0,Ibc,address,Account}', inner_transactions.data::jsonb \
+                 #>> '{0,Native,Account}')",
+            );
+
+            // Joined separately so that each join can use its index
+            let (sent_packets, received_packets) = diesel::alias!(
+                ibc_packets as sent_packets,
+                ibc_packets as received_packets
+            );
+
+            transaction_history::table
+                .inner_join(inner_transactions::table.on(
+                    transaction_history::inner_tx_id.eq(inner_transactions::id),
+                ))
+                .inner_join(wrapper_transactions::table.on(
+                    inner_transactions::wrapper_id.eq(wrapper_transactions::id),
+                ))
+                .left_join(
+                    sent_packets.on(sent_packets
+                        .field(ibc_packets::send_tx_hash)
+                        .eq(inner_transactions::id.nullable())),
+                )
+                .left_join(
+                    received_packets.on(received_packets
+                        .field(ibc_packets::recv_tx_hash)
+                        .eq(inner_transactions::id.nullable())),
+                )
+                .left_join(
+                    ibc_token::table.on(ibc_token::address.eq(token_address)),
+                )
+                .filter(transaction_history::target.eq(address))
+                .filter(inner_transactions::kind.eq_any([
+                    TransactionKindDb::IbcTransparentTransfer,
+                    TransactionKindDb::IbcShieldingTransfer,
+                    TransactionKindDb::IbcUnshieldingTransfer,
+                ]))
+                .order((
+                    wrapper_transactions::block_height.desc(),
+                    inner_transactions::id.asc(),
+                ))
+                .select((
+                    transaction_history::all_columns,
+                    inner_transactions::all_columns,
+                    wrapper_transactions::block_height,
+                    sent_packets.fields(ibc_packets::all_columns).nullable(),
+                    received_packets
+                        .fields(ibc_packets::all_columns)
+                        .nullable(),
+                    ibc_token::ibc_trace.nullable(),
+                ))
+                .paginate(page)
+                .load_and_count_pages::<IbcTransferRow>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_rate_limit_usage(
+        &self,
+        token_address: Option<String>,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<IbcRateLimitUsageRow>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = ibc_token_flows::table
+                .inner_join(
+                    ibc_rate_limits::table.on(ibc_rate_limits::address
+                        .eq(ibc_token_flows::address)
+                        .and(
+                            ibc_rate_limits::epoch.eq(ibc_token_flows::epoch),
+                        )),
+                )
+                .into_boxed();
+
+            if let Some(token_address) = token_address {
+                query =
+                    query.filter(ibc_token_flows::address.eq(token_address));
+            }
+            if let Some(from_epoch) = from_epoch {
+                query = query.filter(ibc_token_flows::epoch.ge(from_epoch));
+            }
+            if let Some(to_epoch) = to_epoch {
+                query = query.filter(ibc_token_flows::epoch.le(to_epoch));
+            }
+
+            query
+                .order((
+                    ibc_token_flows::epoch.desc(),
+                    ibc_token_flows::address.asc(),
+                ))
+                .select((
+                    ibc_token_flows::address,
+                    ibc_token_flows::epoch,
+                    ibc_token_flows::deposit,
+                    ibc_token_flows::withdraw,
+                    ibc_rate_limits::throughput_limit,
+                ))
+                .paginate(page)
+                .load_and_count_pages::<IbcRateLimitUsageRow>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
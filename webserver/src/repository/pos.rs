@@ -1,6 +1,9 @@
+use std::collections::{HashMap, HashSet};
+
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
-use diesel::dsl::{sql, sum};
+use chrono::NaiveDateTime;
+use diesel::dsl::{max, min, sql, sum};
 use diesel::sql_types::Integer;
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
@@ -9,11 +12,15 @@ use diesel::{
 use orm::bond::BondDb;
 use orm::crawler_state::{CrawlerNameDb, EpochCrawlerStateDb};
 use orm::helpers::OrderByDb;
-use orm::pos_rewards::PoSRewardDb;
+use orm::pos_rewards::{PoSRewardDb, PosRewardAccrualDb};
 use orm::schema::{
-    bonds, crawler_state, pos_rewards, redelegation, unbonds, validators,
+    blocks, bonds, crawler_state, pos_reward_accruals, pos_rewards,
+    redelegation, slashes, unbonds, validator_history, validator_uptime,
+    validators,
 };
+use orm::slashes::SlashDb;
 use orm::unbond::UnbondDb;
+use orm::validator_history::ValidatorHistoryDb;
 use orm::validators::{
     ValidatorDb, ValidatorSortByDb, ValidatorStateDb, validator_sort_by,
 };
@@ -75,6 +82,14 @@ pub trait PosRepositoryTrait {
         active_at: Option<i32>,
     ) -> Result<PaginatedResponseDb<(ValidatorDb, BondDb)>, String>;
 
+    async fn find_bonds_by_address_after(
+        &self,
+        address: String,
+        active_at: Option<i32>,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<(ValidatorDb, BondDb)>, String>;
+
     async fn find_unbonds_by_address(
         &self,
         address: String,
@@ -82,6 +97,14 @@ pub trait PosRepositoryTrait {
         active_at: Option<i32>,
     ) -> Result<PaginatedResponseDb<(ValidatorDb, UnbondDb)>, String>;
 
+    async fn find_unbonds_by_address_after(
+        &self,
+        address: String,
+        active_at: Option<i32>,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<(ValidatorDb, UnbondDb)>, String>;
+
     async fn find_merged_unbonds_by_address(
         &self,
         address: String,
@@ -105,6 +128,34 @@ pub trait PosRepositoryTrait {
         epoch: Option<u64>,
     ) -> Result<Vec<(PoSRewardDb, ValidatorDb)>, String>;
 
+    /// Unclaimed rewards of `epoch`, the latest one by default. Later pages
+    /// are read at the `(epoch, id)` of the `after` cursor, so that they stay
+    /// consistent when a new epoch is indexed in between.
+    async fn find_rewards_by_address_after(
+        &self,
+        address: String,
+        epoch: Option<u64>,
+        after: Option<(i32, i32)>,
+        limit: i64,
+    ) -> Result<Vec<(i32, PoSRewardDb, ValidatorDb)>, String>;
+
+    /// Rewards earned in every epoch, along with the height and timestamp
+    /// of the first block of their epoch
+    #[allow(clippy::type_complexity)]
+    async fn find_all_reward_accruals_by_address_after(
+        &self,
+        address: String,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<
+        Vec<(
+            PosRewardAccrualDb,
+            ValidatorDb,
+            Option<(i32, Option<NaiveDateTime>)>,
+        )>,
+        String,
+    >;
+
     async fn find_rewards_by_delegator_and_validator_and_epoch(
         &self,
         delegator: String,
@@ -112,8 +163,52 @@ pub trait PosRepositoryTrait {
         epoch: u64,
     ) -> Result<Vec<PoSRewardDb>, String>;
 
+    async fn find_reward_accruals_by_address(
+        &self,
+        address: String,
+        page: i64,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<PaginatedResponseDb<(ValidatorDb, PosRewardAccrualDb)>, String>;
+
     async fn get_total_voting_power(&self) -> Result<Option<i64>, String>;
 
+    async fn find_validator_history(
+        &self,
+        validator_id: i32,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<ValidatorHistoryDb>, String>;
+
+    async fn find_slashes(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(ValidatorDb, SlashDb)>, String>;
+
+    async fn find_bonded_amounts_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(ValidatorDb, Option<BigDecimal>)>, String>;
+
+    async fn find_all_unbonds_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(ValidatorDb, UnbondDb)>, String>;
+
+    async fn find_slashes_by_validator_id(
+        &self,
+        validator_id: i32,
+    ) -> Result<Vec<SlashDb>, String>;
+
+    async fn find_last_uptime_epoch(&self) -> Result<Option<i32>, String>;
+
+    async fn find_validators_uptime(
+        &self,
+        validator_ids: Vec<i32>,
+        from_epoch: i32,
+        to_epoch: i32,
+    ) -> Result<Vec<(i32, Option<i64>, Option<i64>)>, String>;
+
     async fn get_state(&self) -> Result<EpochCrawlerStateDb, String>;
 }
 
@@ -245,6 +340,39 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_bonds_by_address_after(
+        &self,
+        address: String,
+        active_at: Option<i32>,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<(ValidatorDb, BondDb)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query =
+                validators::table.inner_join(bonds::table).into_boxed();
+
+            if let Some(at) = active_at {
+                query = query.filter(bonds::dsl::start.le(at));
+            }
+
+            if let Some(id) = after {
+                query = query.filter(bonds::dsl::id.gt(id));
+            }
+
+            query
+                .filter(bonds::dsl::address.eq(address))
+                .order(bonds::dsl::id.asc())
+                .limit(limit)
+                .select((validators::all_columns, bonds::all_columns))
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_merged_bonds_by_address(
         &self,
         address: String,
@@ -316,6 +444,39 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_unbonds_by_address_after(
+        &self,
+        address: String,
+        active_at: Option<i32>,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<Vec<(ValidatorDb, UnbondDb)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query =
+                validators::table.inner_join(unbonds::table).into_boxed();
+
+            if let Some(at) = active_at {
+                query = query.filter(unbonds::dsl::withdraw_epoch.lt(at));
+            }
+
+            if let Some(id) = after {
+                query = query.filter(unbonds::dsl::id.gt(id));
+            }
+
+            query
+                .filter(unbonds::dsl::address.eq(address))
+                .order(unbonds::dsl::id.asc())
+                .limit(limit)
+                .select((validators::all_columns, unbonds::all_columns))
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_merged_unbonds_by_address(
         &self,
         address: String,
@@ -417,6 +578,125 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_rewards_by_address_after(
+        &self,
+        address: String,
+        epoch: Option<u64>,
+        after: Option<(i32, i32)>,
+        limit: i64,
+    ) -> Result<Vec<(i32, PoSRewardDb, ValidatorDb)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let epoch = match (after, epoch) {
+                (Some((epoch, _)), _) => epoch,
+                (None, Some(e)) => e as i32,
+                (None, None) => pos_rewards::table
+                    .select(diesel::dsl::max(pos_rewards::epoch))
+                    .first::<Option<i32>>(conn)?
+                    .unwrap_or(0),
+            };
+
+            let mut query = pos_rewards::table
+                .inner_join(validators::table)
+                .filter(pos_rewards::claimed.eq(&false))
+                .filter(pos_rewards::epoch.eq(&epoch))
+                .filter(pos_rewards::dsl::owner.eq(address))
+                .into_boxed();
+
+            if let Some((_, id)) = after {
+                query = query.filter(pos_rewards::dsl::id.gt(id));
+            }
+
+            query
+                .order(pos_rewards::dsl::id.asc())
+                .limit(limit)
+                .select((
+                    pos_rewards::dsl::id,
+                    PoSRewardDb::as_select(),
+                    ValidatorDb::as_select(),
+                ))
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_all_reward_accruals_by_address_after(
+        &self,
+        address: String,
+        after: Option<i32>,
+        limit: i64,
+    ) -> Result<
+        Vec<(
+            PosRewardAccrualDb,
+            ValidatorDb,
+            Option<(i32, Option<NaiveDateTime>)>,
+        )>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = pos_reward_accruals::table
+                .inner_join(validators::table)
+                .filter(pos_reward_accruals::dsl::owner.eq(address))
+                .into_boxed();
+
+            if let Some(id) = after {
+                query = query.filter(pos_reward_accruals::dsl::id.gt(id));
+            }
+
+            let accruals: Vec<(PosRewardAccrualDb, ValidatorDb)> = query
+                .order(pos_reward_accruals::dsl::id.asc())
+                .limit(limit)
+                .select((
+                    PosRewardAccrualDb::as_select(),
+                    ValidatorDb::as_select(),
+                ))
+                .get_results(conn)?;
+
+            let epochs = accruals
+                .iter()
+                .map(|(accrual, _)| accrual.epoch)
+                .collect::<HashSet<_>>();
+
+            let epoch_starts = blocks::table
+                .filter(blocks::dsl::epoch.eq_any(epochs))
+                .group_by(blocks::dsl::epoch)
+                .select((
+                    blocks::dsl::epoch,
+                    min(blocks::dsl::height),
+                    min(blocks::dsl::timestamp),
+                ))
+                .get_results::<(
+                    Option<i32>,
+                    Option<i32>,
+                    Option<NaiveDateTime>,
+                )>(conn)?
+                .into_iter()
+                .filter_map(|(epoch, height, timestamp)| {
+                    Some((epoch?, (height?, timestamp)))
+                })
+                .collect::<HashMap<_, _>>();
+
+            Ok::<_, diesel::result::Error>(
+                accruals
+                    .into_iter()
+                    .map(|(accrual, validator)| {
+                        let epoch_start =
+                            epoch_starts.get(&accrual.epoch).copied();
+                        (accrual, validator, epoch_start)
+                    })
+                    .collect(),
+            )
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_rewards_by_delegator_and_validator_and_epoch(
         &self,
         delegator: String,
@@ -438,6 +718,206 @@ impl PosRepositoryTrait for PosRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_validator_history(
+        &self,
+        validator_id: i32,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<Vec<ValidatorHistoryDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = validator_history::table
+                .filter(validator_history::dsl::validator_id.eq(validator_id))
+                .into_boxed();
+
+            if let Some(from_epoch) = from_epoch {
+                query =
+                    query.filter(validator_history::dsl::epoch.ge(from_epoch));
+            }
+            if let Some(to_epoch) = to_epoch {
+                query =
+                    query.filter(validator_history::dsl::epoch.le(to_epoch));
+            }
+
+            query
+                .order(validator_history::dsl::epoch.asc())
+                .select(ValidatorHistoryDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the total amount bonded by `address` to each validator
+    async fn find_bonded_amounts_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(ValidatorDb, Option<BigDecimal>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validators::table
+                .inner_join(bonds::table)
+                .filter(bonds::dsl::address.eq(address))
+                .group_by(validators::id)
+                .select((validators::all_columns, sum(bonds::dsl::raw_amount)))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_all_unbonds_by_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<(ValidatorDb, UnbondDb)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validators::table
+                .inner_join(unbonds::table)
+                .filter(unbonds::dsl::address.eq(address))
+                .order(unbonds::dsl::withdraw_epoch.asc())
+                .select((validators::all_columns, unbonds::all_columns))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_reward_accruals_by_address(
+        &self,
+        address: String,
+        page: i64,
+        from_epoch: Option<i32>,
+        to_epoch: Option<i32>,
+    ) -> Result<PaginatedResponseDb<(ValidatorDb, PosRewardAccrualDb)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = validators::table
+                .inner_join(pos_reward_accruals::table)
+                .filter(pos_reward_accruals::dsl::owner.eq(address))
+                .into_boxed();
+
+            if let Some(from_epoch) = from_epoch {
+                query = query
+                    .filter(pos_reward_accruals::dsl::epoch.ge(from_epoch));
+            }
+            if let Some(to_epoch) = to_epoch {
+                query =
+                    query.filter(pos_reward_accruals::dsl::epoch.le(to_epoch));
+            }
+
+            query
+                .order((
+                    pos_reward_accruals::dsl::epoch.desc(),
+                    pos_reward_accruals::dsl::id.desc(),
+                ))
+                .select((
+                    validators::all_columns,
+                    pos_reward_accruals::all_columns,
+                ))
+                .paginate(page)
+                .load_and_count_pages::<(ValidatorDb, PosRewardAccrualDb)>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_slashes(
+        &self,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<(ValidatorDb, SlashDb)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validators::table
+                .inner_join(slashes::table)
+                .order((
+                    slashes::dsl::processing_epoch.desc(),
+                    slashes::dsl::id.desc(),
+                ))
+                .select((validators::all_columns, slashes::all_columns))
+                .paginate(page)
+                .load_and_count_pages::<(ValidatorDb, SlashDb)>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_slashes_by_validator_id(
+        &self,
+        validator_id: i32,
+    ) -> Result<Vec<SlashDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            slashes::table
+                .filter(slashes::dsl::validator_id.eq(validator_id))
+                .order((
+                    slashes::dsl::processing_epoch.desc(),
+                    slashes::dsl::id.desc(),
+                ))
+                .select(SlashDb::as_select())
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_last_uptime_epoch(&self) -> Result<Option<i32>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validator_uptime::table
+                .select(max(validator_uptime::dsl::epoch))
+                .first(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    /// Returns the blocks signed and missed by each validator between
+    /// `from_epoch` and `to_epoch`, both included
+    async fn find_validators_uptime(
+        &self,
+        validator_ids: Vec<i32>,
+        from_epoch: i32,
+        to_epoch: i32,
+    ) -> Result<Vec<(i32, Option<i64>, Option<i64>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            validator_uptime::table
+                .filter(
+                    validator_uptime::dsl::validator_id.eq_any(validator_ids),
+                )
+                .filter(
+                    validator_uptime::dsl::epoch.between(from_epoch, to_epoch),
+                )
+                .group_by(validator_uptime::dsl::validator_id)
+                .select((
+                    validator_uptime::dsl::validator_id,
+                    sum(validator_uptime::dsl::signed_blocks),
+                    sum(validator_uptime::dsl::missed_blocks),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn get_state(&self) -> Result<EpochCrawlerStateDb, String> {
         let conn = self.app_state.get_db_connection().await;
 
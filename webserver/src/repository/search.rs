@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use diesel::dsl::AsExprOf;
+use diesel::pg::Pg;
+use diesel::sql_types::{Nullable, Text};
+use diesel::{
+    BoolExpressionMethods, Connection, Expression, ExpressionMethods, IntoSql,
+    NullableExpressionMethods, PgConnection, PgSortExpressionMethods,
+    PgTextExpressionMethods, QueryDsl, QueryResult, RunQueryDsl,
+    SelectableHelper, define_sql_function, infix_operator,
+};
+use orm::schema::{
+    governance_proposals, inner_transactions, validators, wrapper_transactions,
+};
+use orm::validators::ValidatorDb;
+
+use super::utils::escape_like;
+use crate::appstate::AppState;
+use crate::constant::SEARCH_SIMILARITY_THRESHOLD;
+
+define_sql_function! {
+    /// Similarity between the query and the closest part of the value, from
+    /// the pg_trgm extension
+    fn word_similarity(query: Text, value: Nullable<Text>) -> Nullable<Float4>;
+}
+
+infix_operator!(WordSimilar, " <% ", backend: Pg);
+
+/// Whether the word similarity between the query and the value is above
+/// `pg_trgm.word_similarity_threshold`. Unlike a comparison of
+/// `word_similarity`, the operator is supported by the trigram indexes.
+fn word_similar<T: Expression>(
+    query: String,
+    value: T,
+) -> WordSimilar<AsExprOf<String, Text>, T> {
+    WordSimilar::new(query.into_sql::<Text>(), value)
+}
+
+/// Run `f` in a transaction where `<%` matches above the search threshold
+fn with_similarity_threshold<T>(
+    conn: &mut PgConnection,
+    f: impl FnOnce(&mut PgConnection) -> QueryResult<T>,
+) -> QueryResult<T> {
+    conn.transaction(|conn| {
+        diesel::sql_query(format!(
+            "SET LOCAL pg_trgm.word_similarity_threshold = {}",
+            SEARCH_SIMILARITY_THRESHOLD
+        ))
+        .execute(conn)?;
+
+        f(conn)
+    })
+}
+
+#[derive(Clone)]
+pub struct SearchRepository {
+    pub(crate) app_state: AppState,
+}
+
+#[async_trait]
+pub trait SearchRepositoryTrait {
+    fn new(app_state: AppState) -> Self;
+
+    /// Validators whose name, email or website match the query, along with
+    /// the similarity of each of these fields
+    async fn find_validators_matching(
+        &self,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(ValidatorDb, Option<f32>, Option<f32>, Option<f32>)>, String>;
+
+    async fn find_proposals_matching(
+        &self,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(i32, String, Option<f32>)>, String>;
+
+    /// Most recent inner transactions whose decoded memo contains the query
+    async fn find_memos_containing(
+        &self,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(String, String)>, String>;
+}
+
+#[async_trait]
+impl SearchRepositoryTrait for SearchRepository {
+    fn new(app_state: AppState) -> Self {
+        Self { app_state }
+    }
+
+    async fn find_validators_matching(
+        &self,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(ValidatorDb, Option<f32>, Option<f32>, Option<f32>)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+        let pattern = format!("%{}%", escape_like(&query));
+
+        conn.interact(move |conn| {
+            let name_similarity =
+                word_similarity(query.clone(), validators::dsl::name);
+            let email_similarity =
+                word_similarity(query.clone(), validators::dsl::email);
+            let website_similarity =
+                word_similarity(query.clone(), validators::dsl::website);
+
+            // Each branch of the OR is supported by a trigram index, so that
+            // the rows are found with a bitmap OR of index scans
+            let query = validators::table
+                .filter(
+                    validators::dsl::name
+                        .ilike(pattern.clone())
+                        .or(validators::dsl::email.ilike(pattern.clone()))
+                        .or(validators::dsl::website.ilike(pattern))
+                        .or(word_similar(query.clone(), validators::dsl::name))
+                        .or(word_similar(query.clone(), validators::dsl::email))
+                        .or(word_similar(query, validators::dsl::website)),
+                )
+                .order(name_similarity.clone().desc().nulls_last())
+                .limit(limit)
+                .select((
+                    ValidatorDb::as_select(),
+                    name_similarity,
+                    email_similarity,
+                    website_similarity,
+                ));
+
+            with_similarity_threshold(conn, |conn| query.load(conn))
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_proposals_matching(
+        &self,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(i32, String, Option<f32>)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+        let pattern = format!("%{}%", escape_like(&query));
+
+        conn.interact(move |conn| {
+            let similarity = word_similarity(
+                query.clone(),
+                governance_proposals::dsl::content.nullable(),
+            );
+
+            let query = governance_proposals::table
+                .filter(governance_proposals::dsl::content.ilike(pattern).or(
+                    word_similar(query, governance_proposals::dsl::content),
+                ))
+                .order(similarity.clone().desc().nulls_last())
+                .limit(limit)
+                .select((
+                    governance_proposals::dsl::id,
+                    governance_proposals::dsl::content,
+                    similarity,
+                ));
+
+            with_similarity_threshold(conn, |conn| query.load(conn))
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_memos_containing(
+        &self,
+        query: String,
+        limit: i64,
+    ) -> Result<Vec<(String, String)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            inner_transactions::table
+                .inner_join(wrapper_transactions::table)
+                .filter(
+                    inner_transactions::dsl::memo_text
+                        .ilike(format!("%{}%", escape_like(&query))),
+                )
+                .order(wrapper_transactions::dsl::block_height.desc())
+                .limit(limit)
+                .select((
+                    inner_transactions::dsl::id,
+                    inner_transactions::dsl::memo_text.assume_not_null(),
+                ))
+                .load(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+}
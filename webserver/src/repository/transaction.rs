@@ -1,19 +1,24 @@
+use std::collections::{HashMap, HashSet};
+
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::dsl::{exists, sql};
+use diesel::expression::BoxableExpression;
+use diesel::pg::Pg;
 use diesel::prelude::*;
-use diesel::sql_types::Text;
+use diesel::sql_types::{Bool, Text};
 use diesel::{
     ExpressionMethods, JoinOnDsl, QueryDsl, RunQueryDsl, SelectableHelper,
 };
 use orm::schema::{
-    inner_transactions, transaction_history, wrapper_transactions,
+    blocks, inner_transactions, transaction_history, wrapper_transactions,
 };
 use orm::transactions::{
-    InnerTransactionDb, TransactionHistoryDb, TransactionKindDb,
-    WrapperTransactionDb,
+    InnerTransactionDb, TransactionHistoryDb, TransactionHistoryKindDb,
+    TransactionKindDb, TransactionResultDb, WrapperTransactionDb,
 };
 
-use super::utils::{Paginate, PaginatedResponseDb};
+use super::utils::{Paginate, PaginatedResponseDb, escape_like};
 use crate::appstate::AppState;
 
 #[derive(Clone)]
@@ -21,6 +26,177 @@ pub struct TransactionRepository {
     pub(crate) app_state: AppState,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct TransactionHistoryFiltersDb {
+    pub kinds: Vec<TransactionKindDb>,
+    pub tokens: Vec<String>,
+    pub from_height: Option<i32>,
+    pub to_height: Option<i32>,
+    pub from_timestamp: Option<NaiveDateTime>,
+    pub to_timestamp: Option<NaiveDateTime>,
+    pub exit_code: Option<TransactionResultDb>,
+    pub direction: Option<TransactionHistoryKindDb>,
+}
+
+#[diesel::dsl::auto_type]
+fn history_join() -> _ {
+    transaction_history::table
+        .inner_join(
+            inner_transactions::table.on(transaction_history::dsl::inner_tx_id
+                .eq(inner_transactions::dsl::id)),
+        )
+        .inner_join(
+            wrapper_transactions::table.on(inner_transactions::dsl::wrapper_id
+                .eq(wrapper_transactions::dsl::id)),
+        )
+}
+
+type BoxedHistoryQuery = diesel::dsl::IntoBoxed<'static, history_join, Pg>;
+
+/// History entries of `addresses`, joined with their inner and wrapper
+/// transactions, matching all the given filters
+fn filtered_history(
+    addresses: Vec<String>,
+    filters: TransactionHistoryFiltersDb,
+) -> BoxedHistoryQuery {
+    let mut query = history_join().into_boxed();
+
+    // The history of a single address is read in order from its index
+    query = match <[String; 1]>::try_from(addresses) {
+        Ok([address]) => {
+            query.filter(transaction_history::dsl::target.eq(address))
+        }
+        Err(addresses) => {
+            query.filter(transaction_history::dsl::target.eq_any(addresses))
+        }
+    };
+
+    if !filters.kinds.is_empty() {
+        query =
+            query.filter(inner_transactions::dsl::kind.eq_any(filters.kinds));
+    }
+    if !filters.tokens.is_empty() {
+        query = query.filter(transfer_token_filter(filters.tokens));
+    }
+    if let Some(from_height) = filters.from_height {
+        query = query
+            .filter(transaction_history::dsl::block_height.ge(from_height));
+    }
+    if let Some(to_height) = filters.to_height {
+        query =
+            query.filter(transaction_history::dsl::block_height.le(to_height));
+    }
+    if filters.from_timestamp.is_some() || filters.to_timestamp.is_some() {
+        let mut heights =
+            blocks::table.select(blocks::dsl::height).into_boxed();
+
+        if let Some(from_timestamp) = filters.from_timestamp {
+            heights = heights.filter(blocks::dsl::timestamp.ge(from_timestamp));
+        }
+        if let Some(to_timestamp) = filters.to_timestamp {
+            heights = heights.filter(blocks::dsl::timestamp.le(to_timestamp));
+        }
+
+        query = query
+            .filter(transaction_history::dsl::block_height.eq_any(heights));
+    }
+    if let Some(exit_code) = filters.exit_code {
+        query = query.filter(inner_transactions::dsl::exit_code.eq(exit_code));
+    }
+    if let Some(direction) = filters.direction {
+        query = query.filter(transaction_history::dsl::kind.eq(direction));
+    }
+
+    query
+}
+
+/// Page of `filtered_history` strictly after the given keyset, ordered by
+/// descending block height, inner transaction id and id
+fn filtered_history_after(
+    addresses: Vec<String>,
+    filters: TransactionHistoryFiltersDb,
+    after: Option<(i32, String, i32)>,
+) -> BoxedHistoryQuery {
+    let mut query = filtered_history(addresses, filters);
+
+    if let Some((height, inner_tx_id, id)) = after {
+        // The redundant bound on the height lets the index scan start at
+        // the cursor
+        query = query
+            .filter(transaction_history::dsl::block_height.le(height))
+            .filter(
+                transaction_history::dsl::block_height.lt(height).or(
+                    transaction_history::dsl::block_height.eq(height).and(
+                        transaction_history::dsl::inner_tx_id
+                            .lt(inner_tx_id.clone())
+                            .or(transaction_history::dsl::inner_tx_id
+                                .eq(inner_tx_id)
+                                .and(transaction_history::dsl::id.lt(id))),
+                    ),
+                ),
+            );
+    }
+
+    query.order((
+        transaction_history::dsl::block_height.desc(),
+        transaction_history::dsl::inner_tx_id.desc(),
+        transaction_history::dsl::id.desc(),
+    ))
+}
+
+/// Inner transactions transferring any of `tokens`, looked up in the transfer
+/// data of regular and IBC transfers
+fn transfer_token_filter<QS>(
+    tokens: Vec<String>,
+) -> Box<dyn BoxableExpression<QS, Pg, SqlType = Bool>>
+where
+    inner_transactions::dsl::kind: SelectableExpression<QS>,
+{
+    // regular transfer kinds (non-IBC)
+    let regular_kinds = [
+        TransactionKindDb::TransparentTransfer,
+        TransactionKindDb::ShieldedTransfer,
+        TransactionKindDb::ShieldingTransfer,
+        TransactionKindDb::UnshieldingTransfer,
+        TransactionKindDb::MixedTransfer,
+    ];
+
+    // IBC transfer kinds
+    let ibc_kinds = [
+        TransactionKindDb::IbcTransparentTransfer,
+        TransactionKindDb::IbcShieldingTransfer,
+        TransactionKindDb::IbcUnshieldingTransfer,
+    ];
+
+    // JSON path extracts coerced to non-null text
+    let sources_token = sql::<Text>(
+        "COALESCE(inner_transactions.data::jsonb #>> '{sources,0,token}', '')",
+    );
+    let targets_token = sql::<Text>(
+        "COALESCE(inner_transactions.data::jsonb #>> '{targets,0,token}', '')",
+    );
+    let ibc_account = sql::<Text>(
+        "COALESCE(inner_transactions.data::jsonb #>> \
+         '{0,Ibc,address,Account}', '')",
+    );
+
+    // Build `(kind in regular) AND ((sources IN tokens) OR (targets IN
+    // tokens))`
+    let regular_filter =
+        inner_transactions::dsl::kind.eq_any(regular_kinds).and(
+            sources_token
+                .eq_any(tokens.clone())
+                .or(targets_token.eq_any(tokens.clone())),
+        );
+
+    // Build `(kind in ibc_kinds) AND (ibc_account IN tokens)`
+    let ibc_filter = inner_transactions::dsl::kind
+        .eq_any(ibc_kinds)
+        .and(ibc_account.eq_any(tokens));
+
+    Box::new(regular_filter.or(ibc_filter))
+}
+
 #[async_trait]
 pub trait TransactionRepositoryTrait {
     fn new(app_state: AppState) -> Self;
@@ -29,6 +205,10 @@ pub trait TransactionRepositoryTrait {
         &self,
         id: String,
     ) -> Result<Option<WrapperTransactionDb>, String>;
+    async fn find_wrapper_txs(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<Vec<WrapperTransactionDb>, String>;
     async fn find_inners_by_wrapper_tx(
         &self,
         wrapper_id: String,
@@ -40,15 +220,54 @@ pub trait TransactionRepositoryTrait {
     async fn find_addresses_history(
         &self,
         addresses: Vec<String>,
+        filters: TransactionHistoryFiltersDb,
         page: i64,
     ) -> Result<
         PaginatedResponseDb<(TransactionHistoryDb, InnerTransactionDb, i32)>,
         String,
     >;
+    async fn find_addresses_history_after(
+        &self,
+        addresses: Vec<String>,
+        filters: TransactionHistoryFiltersDb,
+        after: Option<(i32, String, i32)>,
+        limit: i64,
+    ) -> Result<Vec<(TransactionHistoryDb, InnerTransactionDb, i32)>, String>;
+    #[allow(clippy::type_complexity)]
+    async fn find_addresses_history_export_after(
+        &self,
+        addresses: Vec<String>,
+        filters: TransactionHistoryFiltersDb,
+        after: Option<(i32, String, i32)>,
+        limit: i64,
+    ) -> Result<
+        Vec<(
+            TransactionHistoryDb,
+            InnerTransactionDb,
+            WrapperTransactionDb,
+            Option<NaiveDateTime>,
+        )>,
+        String,
+    >;
     async fn find_txs_by_block_height(
         &self,
         block_height: i32,
     ) -> Result<Vec<WrapperTransactionDb>, String>;
+    /// Wrapper transactions of the blocks between the two heights, both
+    /// included, in block order
+    async fn find_txs_by_block_height_range(
+        &self,
+        from_height: i32,
+        to_height: i32,
+    ) -> Result<Vec<WrapperTransactionDb>, String>;
+    async fn find_inners_by_wrapper_txs(
+        &self,
+        wrapper_ids: Vec<String>,
+    ) -> Result<Vec<InnerTransactionDb>, String>;
+    async fn find_history_targets_by_block_height(
+        &self,
+        block_height: i32,
+    ) -> Result<Vec<(String, String)>, String>;
     async fn find_most_recent_transactions(
         &self,
         offset: i64,
@@ -62,6 +281,16 @@ pub trait TransactionRepositoryTrait {
         kinds: Vec<TransactionKindDb>,
         tokens: Vec<String>,
     ) -> Result<Vec<WrapperTransactionDb>, String>;
+
+    /// Inner transactions whose decoded memo is equal to, or starts with,
+    /// `value`, most recent first
+    async fn find_inner_txs_by_memo(
+        &self,
+        value: String,
+        prefix: bool,
+        address: Option<String>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<InnerTransactionDb>, String>;
 }
 
 #[async_trait]
@@ -87,6 +316,23 @@ impl TransactionRepositoryTrait for TransactionRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_wrapper_txs(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<Vec<WrapperTransactionDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            wrapper_transactions::table
+                .filter(wrapper_transactions::dsl::id.eq_any(ids))
+                .select(WrapperTransactionDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_inners_by_wrapper_tx(
         &self,
         wrapper_id: String,
@@ -96,6 +342,10 @@ impl TransactionRepositoryTrait for TransactionRepository {
         conn.interact(move |conn| {
             inner_transactions::table
                 .filter(inner_transactions::dsl::wrapper_id.eq(wrapper_id))
+                .order((
+                    inner_transactions::dsl::batch_index.asc(),
+                    inner_transactions::dsl::id.asc(),
+                ))
                 .select(InnerTransactionDb::as_select())
                 .get_results(conn)
         })
@@ -124,6 +374,7 @@ impl TransactionRepositoryTrait for TransactionRepository {
     async fn find_addresses_history(
         &self,
         addresses: Vec<String>,
+        filters: TransactionHistoryFiltersDb,
         page: i64,
     ) -> Result<
         PaginatedResponseDb<(TransactionHistoryDb, InnerTransactionDb, i32)>,
@@ -132,11 +383,8 @@ impl TransactionRepositoryTrait for TransactionRepository {
         let conn = self.app_state.get_db_connection().await;
 
         conn.interact(move |conn| {
-            transaction_history::table
-                .filter(transaction_history::dsl::target.eq_any(addresses))
-                .inner_join(inner_transactions::table.on(transaction_history::dsl::inner_tx_id.eq(inner_transactions::dsl::id)))
-                .inner_join(wrapper_transactions::table.on(inner_transactions::dsl::wrapper_id.eq(wrapper_transactions::dsl::id)))
-                .order(wrapper_transactions::dsl::block_height.desc())
+            filtered_history(addresses, filters)
+                .order(transaction_history::dsl::block_height.desc())
                 .select((transaction_history::all_columns, inner_transactions::all_columns, wrapper_transactions::dsl::block_height))
                 .paginate(page)
                 .load_and_count_pages::<(TransactionHistoryDb, InnerTransactionDb, i32)>(conn)
@@ -146,6 +394,91 @@ impl TransactionRepositoryTrait for TransactionRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_addresses_history_after(
+        &self,
+        addresses: Vec<String>,
+        filters: TransactionHistoryFiltersDb,
+        after: Option<(i32, String, i32)>,
+        limit: i64,
+    ) -> Result<Vec<(TransactionHistoryDb, InnerTransactionDb, i32)>, String>
+    {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            filtered_history_after(addresses, filters, after)
+                .limit(limit)
+                .select((
+                    transaction_history::all_columns,
+                    inner_transactions::all_columns,
+                    wrapper_transactions::dsl::block_height,
+                ))
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_addresses_history_export_after(
+        &self,
+        addresses: Vec<String>,
+        filters: TransactionHistoryFiltersDb,
+        after: Option<(i32, String, i32)>,
+        limit: i64,
+    ) -> Result<
+        Vec<(
+            TransactionHistoryDb,
+            InnerTransactionDb,
+            WrapperTransactionDb,
+            Option<NaiveDateTime>,
+        )>,
+        String,
+    > {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let rows: Vec<(
+                TransactionHistoryDb,
+                InnerTransactionDb,
+                WrapperTransactionDb,
+            )> = filtered_history_after(addresses, filters, after)
+                .limit(limit)
+                .select((
+                    transaction_history::all_columns,
+                    inner_transactions::all_columns,
+                    WrapperTransactionDb::as_select(),
+                ))
+                .get_results(conn)?;
+
+            let heights = rows
+                .iter()
+                .map(|(_, _, wrapper)| wrapper.block_height)
+                .collect::<HashSet<_>>();
+
+            let timestamps = blocks::table
+                .filter(blocks::dsl::height.eq_any(heights))
+                .select((blocks::dsl::height, blocks::dsl::timestamp))
+                .get_results::<(i32, Option<NaiveDateTime>)>(conn)?
+                .into_iter()
+                .collect::<HashMap<_, _>>();
+
+            Ok::<_, diesel::result::Error>(
+                rows.into_iter()
+                    .map(|(history, inner, wrapper)| {
+                        let timestamp = timestamps
+                            .get(&wrapper.block_height)
+                            .copied()
+                            .flatten();
+                        (history, inner, wrapper, timestamp)
+                    })
+                    .collect(),
+            )
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_txs_by_block_height(
         &self,
         block_height: i32,
@@ -157,6 +490,34 @@ impl TransactionRepositoryTrait for TransactionRepository {
                 .filter(
                     wrapper_transactions::dsl::block_height.eq(block_height),
                 )
+                .order((
+                    wrapper_transactions::dsl::block_index.asc(),
+                    wrapper_transactions::dsl::id.asc(),
+                ))
+                .select(WrapperTransactionDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_txs_by_block_height_range(
+        &self,
+        from_height: i32,
+        to_height: i32,
+    ) -> Result<Vec<WrapperTransactionDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            wrapper_transactions::table
+                .filter(wrapper_transactions::dsl::block_height.ge(from_height))
+                .filter(wrapper_transactions::dsl::block_height.le(to_height))
+                .order((
+                    wrapper_transactions::dsl::block_height.asc(),
+                    wrapper_transactions::dsl::block_index.asc(),
+                    wrapper_transactions::dsl::id.asc(),
+                ))
                 .select(WrapperTransactionDb::as_select())
                 .get_results(conn)
         })
@@ -165,6 +526,60 @@ impl TransactionRepositoryTrait for TransactionRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_inners_by_wrapper_txs(
+        &self,
+        wrapper_ids: Vec<String>,
+    ) -> Result<Vec<InnerTransactionDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            inner_transactions::table
+                .filter(inner_transactions::dsl::wrapper_id.eq_any(wrapper_ids))
+                .order((
+                    inner_transactions::dsl::batch_index.asc(),
+                    inner_transactions::dsl::id.asc(),
+                ))
+                .select(InnerTransactionDb::as_select())
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_history_targets_by_block_height(
+        &self,
+        block_height: i32,
+    ) -> Result<Vec<(String, String)>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            transaction_history::table
+                .inner_join(
+                    inner_transactions::table
+                        .on(transaction_history::dsl::inner_tx_id
+                            .eq(inner_transactions::dsl::id)),
+                )
+                .inner_join(
+                    wrapper_transactions::table
+                        .on(inner_transactions::dsl::wrapper_id
+                            .eq(wrapper_transactions::dsl::id)),
+                )
+                .filter(
+                    wrapper_transactions::dsl::block_height.eq(block_height),
+                )
+                .select((
+                    wrapper_transactions::dsl::id,
+                    transaction_history::dsl::target,
+                ))
+                .distinct()
+                .get_results(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_most_recent_transactions(
         &self,
         offset: i64,
@@ -213,56 +628,12 @@ impl TransactionRepositoryTrait for TransactionRepository {
             // 2) Token filters via JSON path extraction (apply as its own
             //    EXISTS)
             if !tokens.is_empty() {
-                // regular transfer kinds (non-IBC)
-                let regular_kinds = [
-                    TransactionKindDb::TransparentTransfer,
-                    TransactionKindDb::ShieldedTransfer,
-                    TransactionKindDb::ShieldingTransfer,
-                    TransactionKindDb::UnshieldingTransfer,
-                    TransactionKindDb::MixedTransfer,
-                ];
-
-                // IBC transfer kinds
-                let ibc_kinds = [
-                    TransactionKindDb::IbcTransparentTransfer,
-                    TransactionKindDb::IbcShieldingTransfer,
-                    TransactionKindDb::IbcUnshieldingTransfer,
-                ];
-
-                // JSON path extracts coerced to non-null text
-                let sources_token = sql::<Text>(
-                    "COALESCE(inner_transactions.data::jsonb #>> \
-                     '{sources,0,token}', '')",
-                );
-                let targets_token = sql::<Text>(
-                    "COALESCE(inner_transactions.data::jsonb #>> \
-                     '{targets,0,token}', '')",
-                );
-                let ibc_account = sql::<Text>(
-                    "COALESCE(inner_transactions.data::jsonb #>> \
-                     '{0,Ibc,address,Account}', '')",
-                );
-
-                // Build `(kind in regular) AND ((sources IN tokens) OR (targets
-                // IN tokens))`
-                let regular_filter =
-                    inner_transactions::dsl::kind.eq_any(regular_kinds).and(
-                        sources_token
-                            .eq_any(&tokens)
-                            .or(targets_token.eq_any(&tokens)),
-                    );
-
-                // Build `(kind in ibc_kinds) AND (ibc_account IN tokens)`
-                let ibc_filter = inner_transactions::dsl::kind
-                    .eq_any(ibc_kinds)
-                    .and(ibc_account.eq_any(&tokens));
-
                 let inner_by_token = inner_transactions::table
                     .filter(
                         inner_transactions::dsl::wrapper_id
                             .eq(wrapper_transactions::dsl::id),
                     )
-                    .filter(regular_filter.or(ibc_filter));
+                    .filter(transfer_token_filter(tokens));
 
                 outer = outer.filter(exists(inner_by_token));
             }
@@ -278,4 +649,53 @@ impl TransactionRepositoryTrait for TransactionRepository {
         .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
     }
+
+    async fn find_inner_txs_by_memo(
+        &self,
+        value: String,
+        prefix: bool,
+        address: Option<String>,
+        page: i64,
+    ) -> Result<PaginatedResponseDb<InnerTransactionDb>, String> {
+        let conn = self.app_state.get_db_connection().await;
+
+        conn.interact(move |conn| {
+            let mut query = inner_transactions::table
+                .inner_join(wrapper_transactions::table)
+                .into_boxed();
+
+            query = if prefix {
+                query.filter(
+                    inner_transactions::dsl::memo_text
+                        .like(format!("{}%", escape_like(&value))),
+                )
+            } else {
+                query.filter(inner_transactions::dsl::memo_text.eq(value))
+            };
+
+            if let Some(address) = address {
+                query = query.filter(
+                    inner_transactions::dsl::id.eq_any(
+                        transaction_history::table
+                            .filter(
+                                transaction_history::dsl::target.eq(address),
+                            )
+                            .select(transaction_history::dsl::inner_tx_id),
+                    ),
+                );
+            }
+
+            query
+                .order((
+                    wrapper_transactions::dsl::block_height.desc(),
+                    inner_transactions::dsl::id.asc(),
+                ))
+                .select(InnerTransactionDb::as_select())
+                .paginate(page)
+                .load_and_count_pages::<InnerTransactionDb>(conn)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+    }
 }
@@ -80,3 +80,24 @@ where
         Ok(())
     }
 }
+
+/// Escapes the `LIKE` wildcards, so that they match literally
+pub fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("memo"), "memo");
+        assert_eq!(escape_like("100%"), "100\\%");
+        assert_eq!(escape_like("a_b"), "a\\_b");
+        assert_eq!(escape_like("\\%_"), "\\\\\\%\\_");
+    }
+}
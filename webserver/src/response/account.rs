@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entity::account::{AccountInfo, AccountKind};
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum AccountKindResponse {
+    Implicit,
+    Established,
+    Internal,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfoResponse {
+    pub address: String,
+    pub kind: AccountKindResponse,
+    /// Keys allowed to sign for the account, ordered by their index
+    pub public_keys: Vec<String>,
+    /// Number of signatures required, unset for internal accounts
+    pub threshold: Option<u64>,
+    pub vp_code_hash: Option<String>,
+    pub creation_height: Option<u64>,
+    pub last_update_height: Option<u64>,
+}
+
+impl From<AccountKind> for AccountKindResponse {
+    fn from(value: AccountKind) -> Self {
+        match value {
+            AccountKind::Implicit => Self::Implicit,
+            AccountKind::Established => Self::Established,
+            AccountKind::Internal => Self::Internal,
+        }
+    }
+}
+
+impl From<AccountInfo> for AccountInfoResponse {
+    fn from(value: AccountInfo) -> Self {
+        Self {
+            address: value.address.to_string(),
+            kind: value.kind.into(),
+            public_keys: value.public_keys,
+            threshold: value.threshold,
+            vp_code_hash: value.vp_code_hash,
+            creation_height: value.creation_height,
+            last_update_height: value.last_update_height,
+        }
+    }
+}
@@ -2,19 +2,39 @@ use axum::Json;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoResponses, ToSchema};
 
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
 pub struct ApiSuccessResponse<T: Serialize> {
     data: T,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
 pub struct ApiErrorResponse {
     message: Option<String>,
     #[serde(rename = "code")]
     status: u16,
 }
 
+/// Error responses documented for every endpoint, all of them carrying an
+/// [`ApiErrorResponse`]
+#[derive(IntoResponses)]
+pub enum ApiErrorResponses {
+    #[response(status = 400, description = "Invalid request parameters")]
+    BadRequest(ApiErrorResponse),
+    #[response(status = 401, description = "Unknown api key")]
+    Unauthorized(ApiErrorResponse),
+    #[response(status = 404, description = "Resource not found")]
+    NotFound(ApiErrorResponse),
+    #[response(
+        status = 429,
+        description = "Rate limited, retry after the `Retry-After` header"
+    )]
+    TooManyRequests(ApiErrorResponse),
+    #[response(status = 500, description = "Internal server error")]
+    InternalServerError(ApiErrorResponse),
+}
+
 impl<T: Serialize> ApiSuccessResponse<T>
 where
     T: Serialize,
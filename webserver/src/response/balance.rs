@@ -1,10 +1,34 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use super::chain::TokenResponse;
+use crate::entity::balance::BalanceChange;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AddressBalanceResponse {
     pub token: TokenResponse,
     pub min_denom_amount: String,
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalanceHistoryResponse {
+    pub token: TokenResponse,
+    pub min_denom_amount: String,
+    pub height: u64,
+    pub timestamp: Option<String>,
+}
+
+impl From<BalanceChange> for AddressBalanceHistoryResponse {
+    fn from(value: BalanceChange) -> Self {
+        Self {
+            token: TokenResponse::from(value.token),
+            min_denom_amount: value.amount.to_string(),
+            height: value.height,
+            timestamp: value
+                .timestamp
+                .map(|t| t.and_utc().timestamp().to_string()),
+        }
+    }
+}
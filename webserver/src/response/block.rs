@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::entity::block::Block;
-use crate::entity::transaction::WrapperTransaction;
+use crate::entity::block::BlockDetails;
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockResponse {
     pub height: u64,
@@ -15,14 +15,29 @@ pub struct BlockResponse {
     pub parent_app_hash: Option<String>,
     pub parent_hash: Option<String>,
     pub epoch: Option<String>,
+    pub tx_count: u64,
+    pub gas_used: u64,
+    /// Fees paid by the wrapper transactions of the block, in raw units of
+    /// each fee token
+    pub fees: Vec<BlockFeeResponse>,
 }
 
-impl BlockResponse {
-    pub fn from(
-        block: Block,
-        prev_block: Option<Block>,
-        transactions: Vec<WrapperTransaction>,
-    ) -> Self {
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockFeeResponse {
+    pub token: String,
+    pub amount: String,
+}
+
+impl From<BlockDetails> for BlockResponse {
+    fn from(value: BlockDetails) -> Self {
+        let BlockDetails {
+            block,
+            prev_block,
+            transactions,
+            aggregates,
+        } = value;
+
         Self {
             height: block.height,
             hash: block.hash.map(|hash| hash.to_string()),
@@ -45,6 +60,16 @@ impl BlockResponse {
                 .unwrap_or(None)
                 .map(|hash| hash.to_string()),
             epoch: block.epoch.map(|e| e.to_string()),
+            tx_count: aggregates.tx_count,
+            gas_used: aggregates.gas_used,
+            fees: aggregates
+                .fees
+                .into_iter()
+                .map(|(token, amount)| BlockFeeResponse {
+                    token,
+                    amount: amount.normalized().to_plain_string(),
+                })
+                .collect(),
         }
     }
 }
@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value as SerdeJSONValue;
 use shared::token::Token as SharedToken;
+use utoipa::ToSchema;
 
-use crate::entity::chain::{CirculatingSupply, Parameters, TokenSupply};
+use crate::entity::chain::{
+    CirculatingSupply, EpochEstimate, Parameters, TokenSupply,
+};
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ParametersResponse {
     pub unbonding_length: u64,
@@ -17,6 +20,7 @@ pub struct ParametersResponse {
     pub min_duration: String,
     pub min_num_of_blocks: String,
     pub max_block_time: String,
+    #[schema(value_type = Object)]
     pub checksums: SerdeJSONValue,
     pub epoch_switch_blocks_delay: u64,
     pub cubic_slashing_window_length: u64,
@@ -49,38 +53,38 @@ impl From<Parameters> for ParametersResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RpcUrlResponse {
     pub url: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LastProcessedBlockResponse {
     pub block: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct LastProcessedEpochResponse {
     pub epoch: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NativeToken {
     pub address: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IbcToken {
     pub address: String,
     pub trace: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 #[serde(untagged)]
 pub enum TokenResponse {
@@ -102,7 +106,7 @@ impl From<SharedToken> for TokenResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenSupplyResponse {
     pub address: String,
@@ -120,7 +124,7 @@ impl From<TokenSupply> for TokenSupplyResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CirculatingSupplyResponse {
     pub amount: String,
@@ -133,3 +137,32 @@ impl From<CirculatingSupply> for CirculatingSupplyResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochEstimateResponse {
+    pub epoch: String,
+    /// Unix timestamp at which the epoch starts
+    pub start_time: String,
+    /// False when the start of the epoch was observed
+    pub estimated: bool,
+    pub current_epoch: String,
+    /// Expected duration of an epoch, in seconds
+    pub epoch_duration: String,
+}
+
+impl EpochEstimateResponse {
+    pub fn from(
+        estimate: EpochEstimate,
+        current_epoch: i32,
+        epoch_duration: i64,
+    ) -> Self {
+        Self {
+            epoch: estimate.epoch.to_string(),
+            start_time: estimate.start_time.to_string(),
+            estimated: estimate.estimated,
+            current_epoch: current_epoch.to_string(),
+            epoch_duration: epoch_duration.to_string(),
+        }
+    }
+}
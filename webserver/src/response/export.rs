@@ -0,0 +1,145 @@
+use axum::BoxError;
+use axum::body::{Body, Bytes};
+use axum::http::{HeaderValue, header};
+use axum::response::{IntoResponse, Response};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::dto::export::ExportFormat;
+use crate::entity::export::ExportRow;
+
+const CSV_HEADER: &str =
+    "timestamp,height,txId,kind,token,amount,fee,feeToken,counterparty\n";
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRowResponse {
+    pub timestamp: Option<String>,
+    pub height: Option<u64>,
+    pub tx_id: Option<String>,
+    /// Inner transaction kind, or reward
+    pub kind: String,
+    pub token: Option<String>,
+    /// Amount denominated with the token denomination
+    pub amount: Option<String>,
+    /// Fee of the wrapper transaction, only set when paid by the address
+    pub fee: Option<String>,
+    pub fee_token: Option<String>,
+    /// Other parties of the transaction separated by `;`, or the validator
+    /// of a reward
+    pub counterparty: Option<String>,
+}
+
+impl From<ExportRow> for ExportRowResponse {
+    fn from(row: ExportRow) -> Self {
+        Self {
+            timestamp: row
+                .timestamp
+                .map(|timestamp| timestamp.and_utc().to_rfc3339()),
+            height: row.height,
+            tx_id: row.tx_id,
+            kind: row.kind,
+            token: row.token,
+            amount: row.amount,
+            fee: row.fee,
+            fee_token: row.fee_token,
+            counterparty: row.counterparty,
+        }
+    }
+}
+
+impl ExportRowResponse {
+    fn write(&self, format: ExportFormat, out: &mut String) {
+        match format {
+            ExportFormat::Csv => {
+                let fields = [
+                    self.timestamp.clone(),
+                    self.height.map(|height| height.to_string()),
+                    self.tx_id.clone(),
+                    Some(self.kind.clone()),
+                    self.token.clone(),
+                    self.amount.clone(),
+                    self.fee.clone(),
+                    self.fee_token.clone(),
+                    self.counterparty.clone(),
+                ];
+                let line = fields
+                    .iter()
+                    .map(|field| {
+                        csv_field(field.as_deref().unwrap_or_default())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                out.push_str(&line);
+            }
+            ExportFormat::Ndjson => out.push_str(
+                &serde_json::to_string(self)
+                    .expect("Export row should serialize to json"),
+            ),
+        }
+        out.push('\n');
+    }
+}
+
+/// Quote a csv field if it contains a separator, a quote or a line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Stream batches of rows as a downloadable `{name}.csv` or `{name}.ndjson`
+/// attachment. The response is already sent when a batch fails, so errors
+/// abort the body instead.
+pub fn export_response<S, E>(
+    format: ExportFormat,
+    name: &str,
+    batches: S,
+) -> Response
+where
+    S: Stream<Item = Result<Vec<ExportRow>, E>> + Send + 'static,
+    E: Into<BoxError> + std::fmt::Display + Send + 'static,
+{
+    let header = match format {
+        ExportFormat::Csv => {
+            Some(Ok::<_, E>(Bytes::from_static(CSV_HEADER.as_bytes())))
+        }
+        ExportFormat::Ndjson => None,
+    };
+
+    let body = batches.map(move |batch| {
+        let batch = batch.inspect_err(|e| {
+            tracing::error!("Failed to export rows: {}", e);
+        })?;
+
+        let mut out = String::new();
+        for row in batch {
+            ExportRowResponse::from(row).write(format, &mut out);
+        }
+
+        Ok::<_, E>(Bytes::from(out))
+    });
+
+    let (content_type, extension) = match format {
+        ExportFormat::Csv => ("text/csv", "csv"),
+        ExportFormat::Ndjson => ("application/x-ndjson", "ndjson"),
+    };
+    let disposition = format!("attachment; filename=\"{name}.{extension}\"");
+
+    (
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                header::CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition)
+                    .unwrap_or(HeaderValue::from_static("attachment")),
+            ),
+        ],
+        Body::from_stream(futures::stream::iter(header).chain(body)),
+    )
+        .into_response()
+}
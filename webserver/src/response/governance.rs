@@ -1,13 +1,14 @@
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entity::governance::{
     Proposal, ProposalData, ProposalStatus, ProposalType, ProposalVote,
     TallyType, VoteType,
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ProposalTypeResponse {
     Default,
@@ -29,7 +30,7 @@ impl Display for ProposalTypeResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum TallyTypeResponse {
     TwoFifths,
@@ -51,7 +52,7 @@ impl Display for TallyTypeResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum VoteTypeResponse {
     Yay,
@@ -71,7 +72,7 @@ impl Display for VoteTypeResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ProposalStatusResponse {
     Pending,
@@ -101,7 +102,7 @@ impl Display for ProposalStatusResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProposalResponse {
     pub id: u64,
@@ -172,7 +173,7 @@ impl From<Proposal> for ProposalResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProposalVoteResponse {
     pub proposal_id: u64,
@@ -195,7 +196,7 @@ impl From<ProposalVote> for ProposalVoteResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProposalDataResponse {
     pub data: Option<String>,
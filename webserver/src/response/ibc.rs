@@ -1,10 +1,18 @@
+use bigdecimal::ToPrimitive;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entity::ibc::{
-    IbcAck, IbcAckStatus, IbcRateLimit, IbcTokenFlow, IbcTokenThroughput,
+    IbcAck, IbcAckStatus, IbcChannel, IbcChannelState, IbcChannelStats,
+    IbcChannelTokenVolume, IbcPacket, IbcPacketCounts, IbcPacketDirection,
+    IbcPacketStep, IbcRateLimit, IbcRateLimitUtilization, IbcRefundStatus,
+    IbcTokenFlow, IbcTokenThroughput, IbcTransfer,
+};
+use crate::response::transaction::{
+    TransactionKindResponse, TransactionResultResponse,
 };
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum IbcAckStatusResponse {
     Success,
@@ -24,7 +32,7 @@ impl From<IbcAckStatus> for IbcAckStatusResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IbcAckResponse {
     pub status: IbcAckStatusResponse,
@@ -40,7 +48,7 @@ impl From<IbcAck> for IbcAckResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IbcRateLimitResponse {
     pub token_address: String,
@@ -56,7 +64,7 @@ impl From<IbcRateLimit> for IbcRateLimitResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IbcTokenFlowResponse {
     pub token_address: String,
@@ -74,7 +82,7 @@ impl From<IbcTokenFlow> for IbcTokenFlowResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct IbcTokenThroughputResponse {
     pub throughput: u64,
@@ -89,3 +97,290 @@ impl From<IbcTokenThroughput> for IbcTokenThroughputResponse {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcChannelStateResponse {
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl From<IbcChannelState> for IbcChannelStateResponse {
+    fn from(value: IbcChannelState) -> Self {
+        match value {
+            IbcChannelState::Init => Self::Init,
+            IbcChannelState::TryOpen => Self::TryOpen,
+            IbcChannelState::Open => Self::Open,
+            IbcChannelState::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelResponse {
+    pub channel_id: String,
+    pub port_id: String,
+    pub counterparty_channel_id: Option<String>,
+    pub counterparty_port_id: String,
+    pub counterparty_chain_id: Option<String>,
+    pub connection_id: String,
+    pub client_id: Option<String>,
+    pub state: IbcChannelStateResponse,
+    pub last_update_height: u64,
+    pub client_update_height: Option<u64>,
+    pub client_consensus_height: Option<String>,
+}
+
+impl From<IbcChannel> for IbcChannelResponse {
+    fn from(value: IbcChannel) -> Self {
+        Self {
+            channel_id: value.channel_id,
+            port_id: value.port_id,
+            counterparty_channel_id: value.counterparty_channel_id,
+            counterparty_port_id: value.counterparty_port_id,
+            counterparty_chain_id: value.counterparty_chain_id,
+            connection_id: value.connection_id,
+            client_id: value.client_id,
+            state: IbcChannelStateResponse::from(value.state),
+            last_update_height: value.last_update_height,
+            client_update_height: value.client_update_height,
+            client_consensus_height: value.client_consensus_height,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcPacketCountsResponse {
+    pub total: u64,
+    pub success: u64,
+    pub fail: u64,
+    pub timeout: u64,
+    pub pending: u64,
+}
+
+impl From<IbcPacketCounts> for IbcPacketCountsResponse {
+    fn from(value: IbcPacketCounts) -> Self {
+        Self {
+            total: value.total(),
+            success: value.success,
+            fail: value.fail,
+            timeout: value.timeout,
+            pending: value.pending,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelTokenVolumeResponse {
+    pub token_address: String,
+    pub trace: String,
+    pub deposit: String,
+    pub withdraw: String,
+}
+
+impl From<IbcChannelTokenVolume> for IbcChannelTokenVolumeResponse {
+    fn from(value: IbcChannelTokenVolume) -> Self {
+        Self {
+            token_address: value.token_address.to_string(),
+            trace: value.trace,
+            deposit: value.deposit.normalized().to_plain_string(),
+            withdraw: value.withdraw.normalized().to_plain_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcChannelStatsResponse {
+    pub channel: IbcChannelResponse,
+    /// Packets sent from this chain over the channel
+    pub packets: IbcPacketCountsResponse,
+    /// Deposits and withdrawals of the tokens that entered through the
+    /// channel
+    pub volume: Vec<IbcChannelTokenVolumeResponse>,
+}
+
+impl From<IbcChannelStats> for IbcChannelStatsResponse {
+    fn from(value: IbcChannelStats) -> Self {
+        Self {
+            channel: IbcChannelResponse::from(value.channel),
+            packets: IbcPacketCountsResponse::from(value.packets),
+            volume: value
+                .volume
+                .into_iter()
+                .map(IbcChannelTokenVolumeResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcPacketDirectionResponse {
+    Sent,
+    Received,
+}
+
+impl From<IbcPacketDirection> for IbcPacketDirectionResponse {
+    fn from(value: IbcPacketDirection) -> Self {
+        match value {
+            IbcPacketDirection::Sent => Self::Sent,
+            IbcPacketDirection::Received => Self::Received,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IbcRefundStatusResponse {
+    AwaitingTimeout,
+    Refunded,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcPacketStepResponse {
+    pub tx_hash: Option<String>,
+    pub block_height: u64,
+}
+
+impl From<IbcPacketStep> for IbcPacketStepResponse {
+    fn from(value: IbcPacketStep) -> Self {
+        Self {
+            tx_hash: value.tx_hash,
+            block_height: value.block_height,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcPacketResponse {
+    pub direction: IbcPacketDirectionResponse,
+    pub source_port: String,
+    pub source_channel: String,
+    pub dest_port: String,
+    pub dest_channel: String,
+    pub sequence: u64,
+    pub denom: Option<String>,
+    pub amount: Option<String>,
+    pub sender: Option<String>,
+    pub receiver: Option<String>,
+    pub memo: Option<String>,
+    pub timeout_timestamp: u64,
+    pub timeout_height: String,
+    pub status: IbcAckStatusResponse,
+    /// Only set for sent transfers which failed or timed out
+    pub refund_status: Option<IbcRefundStatusResponse>,
+    pub send: Option<IbcPacketStepResponse>,
+    pub recv: Option<IbcPacketStepResponse>,
+    pub ack: Option<IbcPacketStepResponse>,
+    pub timeout: Option<IbcPacketStepResponse>,
+}
+
+impl From<IbcPacket> for IbcPacketResponse {
+    fn from(value: IbcPacket) -> Self {
+        Self {
+            direction: IbcPacketDirectionResponse::from(value.direction),
+            source_port: value.source_port,
+            source_channel: value.source_channel,
+            dest_port: value.dest_port,
+            dest_channel: value.dest_channel,
+            sequence: value.sequence,
+            denom: value.denom,
+            amount: value
+                .amount
+                .map(|amount| amount.normalized().to_plain_string()),
+            sender: value.sender,
+            receiver: value.receiver,
+            memo: value.memo,
+            timeout_timestamp: value.timeout_timestamp,
+            timeout_height: value.timeout_height,
+            status: IbcAckStatusResponse::from(value.status),
+            refund_status: value.refund_status.map(|status| match status {
+                IbcRefundStatus::AwaitingTimeout => {
+                    IbcRefundStatusResponse::AwaitingTimeout
+                }
+                IbcRefundStatus::Refunded => IbcRefundStatusResponse::Refunded,
+            }),
+            send: value.send.map(IbcPacketStepResponse::from),
+            recv: value.recv.map(IbcPacketStepResponse::from),
+            ack: value.ack.map(IbcPacketStepResponse::from),
+            timeout: value.timeout.map(IbcPacketStepResponse::from),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcTransferResponse {
+    pub tx_id: String,
+    pub wrapper_id: String,
+    pub kind: TransactionKindResponse,
+    pub exit_code: TransactionResultResponse,
+    pub block_height: u64,
+    pub direction: IbcPacketDirectionResponse,
+    pub token: Option<String>,
+    pub base_denom: Option<String>,
+    /// Empty for tokens native to this chain
+    pub trace_path: String,
+    pub amount: Option<String>,
+    pub counterparty: Option<String>,
+    pub status: IbcAckStatusResponse,
+    pub channel: Option<String>,
+    pub sequence: Option<u64>,
+}
+
+impl From<IbcTransfer> for IbcTransferResponse {
+    fn from(value: IbcTransfer) -> Self {
+        Self {
+            tx_id: value.tx_id.to_string(),
+            wrapper_id: value.wrapper_id.to_string(),
+            kind: TransactionKindResponse::from(value.kind),
+            exit_code: TransactionResultResponse::from(value.exit_code),
+            block_height: value.block_height,
+            direction: IbcPacketDirectionResponse::from(value.direction),
+            token: value.token,
+            base_denom: value.base_denom,
+            trace_path: value.trace_path,
+            amount: value.amount,
+            counterparty: value.counterparty,
+            status: IbcAckStatusResponse::from(value.status),
+            channel: value.channel,
+            sequence: value.sequence,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IbcRateLimitUtilizationResponse {
+    pub token_address: String,
+    pub epoch: u64,
+    pub throughput: String,
+    pub throughput_limit: String,
+    /// Percent of the limit consumed, not set for tokens without a limit
+    pub utilization: Option<f64>,
+}
+
+impl From<IbcRateLimitUtilization> for IbcRateLimitUtilizationResponse {
+    fn from(value: IbcRateLimitUtilization) -> Self {
+        Self {
+            token_address: value.token_address.to_string(),
+            epoch: value.epoch,
+            throughput: value.throughput.normalized().to_plain_string(),
+            throughput_limit: value
+                .throughput_limit
+                .normalized()
+                .to_plain_string(),
+            utilization: value
+                .utilization
+                .and_then(|utilization| utilization.to_f64()),
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
 use shared::masp::MaspRewardData;
+use utoipa::ToSchema;
 
 use crate::entity::masp::{
     MaspPoolAggregate, MaspPoolAggregateKind, MaspPoolAggregateWindow,
 };
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum MaspPoolAggregateWindowResponse {
     OneDay,
@@ -14,14 +15,14 @@ pub enum MaspPoolAggregateWindowResponse {
     AllTime,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum MaspPoolAggregateKindResponse {
     Inflows,
     Outflows,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MaspPoolAggregateResponse {
     pub token_address: String,
@@ -61,7 +62,7 @@ impl From<MaspPoolAggregate> for MaspPoolAggregateResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MaspRewardDataResponse {
     pub address: String,
@@ -1,8 +1,10 @@
+pub mod account;
 pub mod api;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod crawler_state;
+pub mod export;
 pub mod gas;
 pub mod governance;
 pub mod headers;
@@ -11,5 +13,7 @@ pub mod masp;
 pub mod pgf;
 pub mod pos;
 pub mod revealed_pk;
+pub mod search;
+pub mod subscription;
 pub mod transaction;
 pub mod utils;
@@ -1,9 +1,10 @@
 use orm::pgf::{PaymentKindDb, PaymentRecurrenceDb};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entity::pgf::{PaymentKind, PaymentRecurrence, PgfPayment};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum PaymentRecurrenceResponse {
     Retro,
@@ -19,7 +20,7 @@ impl From<PaymentRecurrenceDb> for PaymentRecurrenceResponse {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum PaymentKindResponse {
     Native,
@@ -35,7 +36,7 @@ impl From<PaymentKindDb> for PaymentKindResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PgfPaymentResponse {
     pub recurrence: PaymentRecurrenceResponse,
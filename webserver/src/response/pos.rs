@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::entity::pos::{
-    Bond, BondStatus, MergedBond, Reward, Unbond, Validator, ValidatorState,
-    ValidatorWithRank, Withdraw,
+    Bond, BondStatus, DelegatorPosition, DelegatorSummary, MergedBond, Reward,
+    RewardAccrual, Slash, SlashType, Unbond, Validator, ValidatorHistory,
+    ValidatorState, ValidatorUptime, ValidatorWithRank, Withdraw,
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum ValidatorStateResponse {
     Consensus,
@@ -19,7 +21,7 @@ pub enum ValidatorStateResponse {
     Unknown,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidatorResponse {
     pub address: String,
@@ -35,14 +37,14 @@ pub struct ValidatorResponse {
     pub avatar: Option<String>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum BondStatusResponse {
     Active,
     Inactive,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BondResponse {
     pub min_denom_amount: String,
@@ -51,14 +53,14 @@ pub struct BondResponse {
     pub start_epoch: u64,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MergedBondResponse {
     pub min_denom_amount: String,
     pub validator: ValidatorWithRankResponse,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UnbondResponse {
     pub min_denom_amount: String,
@@ -68,33 +70,112 @@ pub struct UnbondResponse {
     pub can_withdraw: bool,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawResponse {
     pub min_denom_amount: String,
     pub validator: ValidatorWithRankResponse,
     pub withdraw_epoch: String,
+    /// Start of the withdraw epoch, estimated when it is not indexed yet
+    pub withdraw_time: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RewardResponse {
     pub min_denom_amount: String,
     pub validator: ValidatorWithRankResponse,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardAccrualResponse {
+    pub validator: ValidatorWithRankResponse,
+    pub epoch: u64,
+    /// Rewards earned by the delegation during the epoch
+    pub min_denom_amount: String,
+    /// Inner transaction that claimed the rewards, if they were claimed
+    pub claimed_in_tx: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TotalVotingPowerResponse {
     pub total_voting_power: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidatorWithRankResponse {
     #[serde(flatten)]
     pub validator: ValidatorResponse,
     pub rank: Option<u64>,
+    /// Share of the blocks signed over the last epochs, only set in the
+    /// validator lists
+    pub uptime: Option<f64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegatorPositionResponse {
+    pub validator: ValidatorWithRankResponse,
+    pub bonded: String,
+    pub unclaimed_rewards: String,
+    /// Chain apr reduced by the validator commission
+    pub estimated_apr: f64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegatorSummaryResponse {
+    pub total_bonded: String,
+    pub total_unbonding: String,
+    /// Unbonded amount that can be withdrawn now
+    pub withdrawable: String,
+    pub unclaimed_rewards: String,
+    pub positions: Vec<DelegatorPositionResponse>,
+    /// Unbonds that can not be withdrawn yet, `withdrawTime` is an estimate
+    pub pending_unbonds: Vec<UnbondResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SlashTypeResponse {
+    DuplicateVote,
+    LightClientAttack,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SlashResponse {
+    pub validator: ValidatorWithRankResponse,
+    pub infraction_epoch: u64,
+    pub processing_epoch: u64,
+    pub block_height: u64,
+    pub rate: String,
+    pub slash_type: SlashTypeResponse,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorHistoryResponse {
+    pub epoch: String,
+    pub voting_power: String,
+    pub max_commission: String,
+    pub commission: String,
+    pub state: ValidatorStateResponse,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorUptimeResponse {
+    pub address: String,
+    pub window: u64,
+    pub from_epoch: u64,
+    pub to_epoch: u64,
+    pub signed_blocks: u64,
+    pub missed_blocks: u64,
+    pub uptime: Option<f64>,
 }
 
 impl From<ValidatorState> for ValidatorStateResponse {
@@ -145,6 +226,87 @@ impl From<ValidatorWithRank> for ValidatorWithRankResponse {
         ValidatorWithRankResponse {
             validator: value.validator.into(),
             rank: value.rank,
+            uptime: value.uptime,
+        }
+    }
+}
+
+impl From<DelegatorPosition> for DelegatorPositionResponse {
+    fn from(value: DelegatorPosition) -> Self {
+        Self {
+            validator: ValidatorWithRankResponse::from(value.validator),
+            bonded: value.bonded.to_string(),
+            unclaimed_rewards: value.unclaimed_rewards.to_string(),
+            estimated_apr: value.estimated_apr,
+        }
+    }
+}
+
+impl From<DelegatorSummary> for DelegatorSummaryResponse {
+    fn from(value: DelegatorSummary) -> Self {
+        Self {
+            total_bonded: value.total_bonded.to_string(),
+            total_unbonding: value.total_unbonding.to_string(),
+            withdrawable: value.withdrawable.to_string(),
+            unclaimed_rewards: value.unclaimed_rewards.to_string(),
+            positions: value
+                .positions
+                .into_iter()
+                .map(DelegatorPositionResponse::from)
+                .collect(),
+            pending_unbonds: value
+                .pending_unbonds
+                .into_iter()
+                .map(UnbondResponse::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<SlashType> for SlashTypeResponse {
+    fn from(value: SlashType) -> Self {
+        match value {
+            SlashType::DuplicateVote => Self::DuplicateVote,
+            SlashType::LightClientAttack => Self::LightClientAttack,
+        }
+    }
+}
+
+impl From<Slash> for SlashResponse {
+    fn from(value: Slash) -> Self {
+        Self {
+            validator: ValidatorWithRankResponse::from(value.validator),
+            infraction_epoch: value.infraction_epoch,
+            processing_epoch: value.processing_epoch,
+            block_height: value.block_height,
+            rate: value.rate.to_string(),
+            slash_type: value.kind.into(),
+        }
+    }
+}
+
+impl From<ValidatorHistory> for ValidatorHistoryResponse {
+    fn from(value: ValidatorHistory) -> Self {
+        Self {
+            epoch: value.epoch.to_string(),
+            voting_power: value.voting_power.to_string(),
+            max_commission: value.max_commission,
+            commission: value.commission,
+            state: value.state.into(),
+        }
+    }
+}
+
+impl ValidatorUptimeResponse {
+    pub fn from(address: String, uptime: ValidatorUptime) -> Self {
+        Self {
+            address,
+            window: uptime.window,
+            from_epoch: uptime.from_epoch,
+            to_epoch: uptime.to_epoch,
+            signed_blocks: uptime.signed_blocks,
+            missed_blocks: uptime.missed_blocks,
+            uptime: uptime.uptime(),
         }
     }
 }
@@ -187,6 +349,18 @@ impl From<Withdraw> for WithdrawResponse {
             min_denom_amount: value.min_denom_amount.to_string(),
             validator: ValidatorWithRankResponse::from(value.validator),
             withdraw_epoch: value.withdraw_epoch.to_string(),
+            withdraw_time: value.withdraw_time.to_string(),
+        }
+    }
+}
+
+impl From<RewardAccrual> for RewardAccrualResponse {
+    fn from(value: RewardAccrual) -> Self {
+        Self {
+            validator: ValidatorWithRankResponse::from(value.validator),
+            epoch: value.epoch,
+            min_denom_amount: value.min_denom_amount.to_string(),
+            claimed_in_tx: value.claimed_in_tx.map(|id| id.to_string()),
         }
     }
 }
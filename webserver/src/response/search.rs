@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entity::search::{SearchResult, SearchResultKind};
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchResultKindResponse {
+    Account,
+    Validator,
+    Block,
+    WrapperTransaction,
+    InnerTransaction,
+    Proposal,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResultResponse {
+    pub kind: SearchResultKindResponse,
+    /// Address, hash, block height or proposal id, depending on the kind
+    pub id: String,
+    /// Validator name, proposal title, block hash or decoded memo
+    pub label: Option<String>,
+    pub score: f32,
+}
+
+impl From<SearchResultKind> for SearchResultKindResponse {
+    fn from(value: SearchResultKind) -> Self {
+        match value {
+            SearchResultKind::Account => Self::Account,
+            SearchResultKind::Validator => Self::Validator,
+            SearchResultKind::Block => Self::Block,
+            SearchResultKind::WrapperTransaction => Self::WrapperTransaction,
+            SearchResultKind::InnerTransaction => Self::InnerTransaction,
+            SearchResultKind::Proposal => Self::Proposal,
+        }
+    }
+}
+
+impl From<SearchResult> for SearchResultResponse {
+    fn from(value: SearchResult) -> Self {
+        Self {
+            kind: value.kind.into(),
+            id: value.id,
+            label: value.label,
+            score: value.score,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use serde::Serialize;
+
+use crate::entity::subscription::{SubscriptionEvent, Topic};
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SubscriptionResponse {
+    Subscribed {
+        topics: Vec<String>,
+    },
+    Unsubscribed {
+        topics: Vec<String>,
+    },
+    Event {
+        topic: String,
+        data: serde_json::Value,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl SubscriptionResponse {
+    pub fn subscribed(topics: &[Topic]) -> Self {
+        Self::Subscribed {
+            topics: topics.iter().map(Topic::to_string).collect(),
+        }
+    }
+
+    pub fn unsubscribed(topics: &[Topic]) -> Self {
+        Self::Unsubscribed {
+            topics: topics.iter().map(Topic::to_string).collect(),
+        }
+    }
+
+    pub fn error(message: impl ToString) -> Self {
+        Self::Error {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<&SubscriptionEvent> for SubscriptionResponse {
+    fn from(event: &SubscriptionEvent) -> Self {
+        Self::Event {
+            topic: event.topic.to_string(),
+            data: event.data.clone(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use super::chain::TokenResponse;
 use crate::entity::transaction::{
@@ -6,7 +7,7 @@ use crate::entity::transaction::{
     TransactionHistoryKind, TransactionKind, WrapperTransaction,
 };
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionResultResponse {
     Applied,
@@ -26,7 +27,9 @@ impl From<TransactionExitStatus> for TransactionResultResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+#[derive(
+    Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash, ToSchema,
+)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionKindResponse {
     TransparentTransfer,
@@ -53,6 +56,7 @@ pub enum TransactionKindResponse {
     ReactivateValidator,
     UnjailValidator,
     InitAccount,
+    UpdateAccount,
     ChangeConsensusKey,
     Unknown,
 }
@@ -90,11 +94,12 @@ impl From<TransactionKind> for TransactionKindResponse {
             TransactionKind::Unknown => Self::Unknown,
             TransactionKind::ChangeConsensusKey => Self::ChangeConsensusKey,
             TransactionKind::InitAccount => Self::InitAccount,
+            TransactionKind::UpdateAccount => Self::UpdateAccount,
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct WrapperTransactionResponse {
     pub id: String,
@@ -131,6 +136,7 @@ impl WrapperTransactionResponse {
                     kind: inner.kind.into(),
                     data: inner.data,
                     memo: inner.memo,
+                    memo_text: inner.memo_text,
                     exit_code: TransactionResultResponse::from(inner.exit_code),
                 })
                 .collect(),
@@ -140,24 +146,30 @@ impl WrapperTransactionResponse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ShortInnerTransactionResponse {
     pub id: String,
     pub kind: TransactionKindResponse,
     pub data: Option<String>,
+    /// Hex encoded memo
     pub memo: Option<String>,
+    /// Memo decoded to text, when it is valid UTF-8
+    pub memo_text: Option<String>,
     pub exit_code: TransactionResultResponse,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InnerTransactionResponse {
     pub id: String,
     pub wrapper_id: String,
     pub kind: TransactionKindResponse,
     pub data: Option<String>,
+    /// Hex encoded memo
     pub memo: Option<String>,
+    /// Memo decoded to text, when it is valid UTF-8
+    pub memo_text: Option<String>,
     pub exit_code: TransactionResultResponse,
 }
 
@@ -169,6 +181,7 @@ impl InnerTransactionResponse {
             kind: TransactionKindResponse::from(inner.kind),
             data: inner.data,
             memo: inner.memo,
+            memo_text: inner.memo_text,
             exit_code: TransactionResultResponse::from(inner.exit_code),
         }
     }
@@ -181,6 +194,7 @@ impl From<InnerTransaction> for ShortInnerTransactionResponse {
             kind: value.kind.into(),
             data: value.data,
             memo: value.memo,
+            memo_text: value.memo_text,
             exit_code: TransactionResultResponse::from(value.exit_code),
         }
     }
@@ -193,19 +207,20 @@ impl InnerTransactionResponse {
             kind: self.kind.clone(),
             data: self.data.clone(),
             memo: self.memo.clone(),
+            memo_text: self.memo_text.clone(),
             exit_code: self.exit_code.clone(),
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum TrasactionHistoryKindResponse {
     Received,
     Sent,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionHistoryResponse {
     pub tx: InnerTransactionResponse,
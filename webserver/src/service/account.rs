@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+use namada_sdk::address::Address as NamadaAddress;
+
+use crate::appstate::AppState;
+use crate::entity::account::AccountInfo;
+use crate::error::account::AccountError;
+use crate::repository::account::{AccountRepo, AccountRepoTrait};
+use crate::repository::revealed_pk::{PkRepoTrait, RevealedPkRepo};
+
+#[derive(Clone)]
+pub struct AccountService {
+    account_repo: AccountRepo,
+    revealed_pk_repo: RevealedPkRepo,
+}
+
+impl AccountService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            account_repo: AccountRepo::new(app_state.clone()),
+            revealed_pk_repo: RevealedPkRepo::new(app_state),
+        }
+    }
+
+    pub async fn get_account_info(
+        &self,
+        address: String,
+    ) -> Result<AccountInfo, AccountError> {
+        let namada_address = NamadaAddress::from_str(&address)
+            .map_err(|_| AccountError::InvalidAddress(address.clone()))?;
+
+        match namada_address {
+            NamadaAddress::Implicit(_) => {
+                let revealed_pk = self
+                    .revealed_pk_repo
+                    .get_revealed_pk_by_address(address.clone())
+                    .await
+                    .map_err(AccountError::Database)?;
+
+                Ok(AccountInfo::implicit(
+                    address,
+                    revealed_pk.map(|revealed_pk| revealed_pk.pk),
+                ))
+            }
+            NamadaAddress::Established(_) => {
+                let db_account = self
+                    .account_repo
+                    .find_account_by_address(address.clone())
+                    .await
+                    .map_err(AccountError::Database)?
+                    .ok_or(AccountError::NotFound(address.clone()))?;
+
+                let db_public_keys = self
+                    .account_repo
+                    .find_account_public_keys(address)
+                    .await
+                    .map_err(AccountError::Database)?;
+
+                Ok(AccountInfo::established(db_account, db_public_keys))
+            }
+            NamadaAddress::Internal(_) => Ok(AccountInfo::internal(address)),
+        }
+    }
+}
@@ -1,9 +1,11 @@
+use orm::balances::BalanceDb;
+use orm::token::{IbcTokenDb, TokenDb};
 use shared::balance::Amount;
 use shared::id::Id;
 use shared::token::{IbcToken, Token};
 
 use crate::appstate::AppState;
-use crate::entity::balance::Balance;
+use crate::entity::balance::{Balance, BalanceAt, BalanceChange};
 use crate::error::balance::BalanceError;
 use crate::repository::balance::{BalanceRepo, BalanceRepoTrait};
 
@@ -22,12 +24,35 @@ impl BalanceService {
     pub async fn get_address_balances(
         &self,
         address: String,
+        height: Option<u64>,
+        timestamp: Option<i64>,
     ) -> Result<Vec<Balance>, BalanceError> {
-        let balances = self
-            .balance_repo
-            .get_address_balances(address.clone())
-            .await
-            .map_err(BalanceError::Database)?;
+        let height = match BalanceAt::new(height, timestamp)? {
+            BalanceAt::Latest => None,
+            BalanceAt::Height(height) => Some(height),
+            BalanceAt::Timestamp(timestamp) => Some(
+                self.balance_repo
+                    .find_last_height_at_timestamp(timestamp)
+                    .await
+                    .map_err(BalanceError::Database)?
+                    .ok_or(BalanceError::BlockNotFound(
+                        timestamp.and_utc().timestamp(),
+                    ))?,
+            ),
+        };
+
+        let balances = match height {
+            Some(height) => self
+                .balance_repo
+                .get_address_balances_at_height(address.clone(), height)
+                .await
+                .map_err(BalanceError::Database)?,
+            None => self
+                .balance_repo
+                .get_address_balances(address.clone())
+                .await
+                .map_err(BalanceError::Database)?,
+        };
 
         let tokens = self
             .balance_repo
@@ -39,22 +64,72 @@ impl BalanceService {
             .into_iter()
             .map(|(token, ibc_token)| Balance {
                 owner: Id::Account(address.clone()),
-                token: match ibc_token {
-                    Some(ibc_token) => Token::Ibc(IbcToken {
-                        address: Id::Account(ibc_token.address),
-                        trace: Some(Id::IbcTrace(ibc_token.ibc_trace)),
-                    }),
-                    None => Token::Native(Id::Account(token.address.clone())),
-                },
-                amount: balances
-                    .iter()
-                    .find(|&balance| balance.token.eq(&token.address))
-                    .cloned()
-                    .map(|balance| Amount::from(balance.raw_amount))
-                    .unwrap_or_else(Amount::zero),
+                amount: Self::find_token_amount(&balances, &token.address),
+                token: Self::to_token(token, ibc_token),
             })
             .collect();
 
         Ok(denominated_balances)
     }
+
+    pub async fn get_address_balance_history(
+        &self,
+        address: String,
+        token: String,
+        page: u64,
+    ) -> Result<(Vec<BalanceChange>, u64, u64), BalanceError> {
+        let (changes, total_pages, total_items) = self
+            .balance_repo
+            .get_address_balance_history(
+                address.clone(),
+                token.clone(),
+                page as i64,
+            )
+            .await
+            .map_err(BalanceError::Database)?;
+
+        let tokens = self
+            .balance_repo
+            .get_all_token()
+            .await
+            .map_err(BalanceError::Database)?;
+
+        let token = tokens
+            .into_iter()
+            .find(|(token_db, _)| token_db.address.eq(&token))
+            .map(|(token, ibc_token)| Self::to_token(token, ibc_token))
+            .unwrap_or(Token::Native(Id::Account(token)));
+
+        let changes = changes
+            .into_iter()
+            .map(|(change, timestamp)| BalanceChange {
+                owner: Id::Account(change.owner),
+                token: token.clone(),
+                amount: Amount::from(change.raw_amount),
+                height: change.height as u64,
+                timestamp,
+            })
+            .collect();
+
+        Ok((changes, total_pages as u64, total_items as u64))
+    }
+
+    fn find_token_amount(balances: &[BalanceDb], token: &str) -> Amount {
+        balances
+            .iter()
+            .find(|&balance| balance.token.eq(token))
+            .cloned()
+            .map(|balance| Amount::from(balance.raw_amount))
+            .unwrap_or_else(Amount::zero)
+    }
+
+    fn to_token(token: TokenDb, ibc_token: Option<IbcTokenDb>) -> Token {
+        match ibc_token {
+            Some(ibc_token) => Token::Ibc(IbcToken {
+                address: Id::Account(ibc_token.address),
+                trace: Some(Id::IbcTrace(ibc_token.ibc_trace)),
+            }),
+            None => Token::Native(Id::Account(token.address)),
+        }
+    }
 }
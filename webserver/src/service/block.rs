@@ -1,6 +1,13 @@
+use std::collections::BTreeMap;
+
+use orm::blocks::BlockDb;
+use orm::token::{IbcTokenDb, TokenDb};
+use orm::transactions::WrapperTransactionDb;
+
 use crate::appstate::AppState;
-use crate::entity::block::Block;
-use crate::entity::transaction::WrapperTransaction;
+use crate::constant::{DEFAULT_LATEST_BLOCKS, MAX_BLOCK_RANGE};
+use crate::entity::block::{Block, BlockAggregates, BlockDetails};
+use crate::entity::transaction::{InnerTransaction, WrapperTransaction};
 use crate::error::block::BlockError;
 use crate::repository::block::{BlockRepository, BlockRepositoryTrait};
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
@@ -27,79 +34,143 @@ impl BlockService {
     pub async fn get_block_by_hash(
         &self,
         hash: String,
-    ) -> Result<(Block, Option<Block>, Vec<WrapperTransaction>), BlockError>
-    {
+    ) -> Result<BlockDetails, BlockError> {
         let block = self
             .block_repo
             .find_block_by_hash(hash.clone())
             .await
-            .map_err(BlockError::Database)?
-            .map(Block::from_db);
+            .map_err(BlockError::Database)?;
 
         let block = block.ok_or(BlockError::NotFound(
             "hash".to_string(),
             hash.to_string(),
         ))?;
 
-        let block_height = block.height as i32;
-
-        let prev_block = if let Some(block_height) = block.height.checked_sub(1)
-        {
-            self.block_repo
-                .find_block_by_height(block_height as i32)
-                .await
-                .map_err(BlockError::Database)?
-                .map(Block::from_db)
-        } else {
-            None
-        };
-
-        let tokens = self
-            .chain_repo
-            .find_tokens()
-            .await
-            .map_err(BlockError::Database)?;
-
-        let transactions = self
-            .transaction_repo
-            .find_txs_by_block_height(block_height)
-            .await
-            .map_err(BlockError::Database)?;
-
-        let transactions = transactions
-            .into_iter()
-            .map(|tx| WrapperTransaction::from_db(tx, tokens.clone()))
-            .collect();
-
-        Ok((block, prev_block, transactions))
+        self.get_block_details(block).await
     }
 
     pub async fn get_block_by_height(
         &self,
         height: i32,
-    ) -> Result<(Block, Option<Block>, Vec<WrapperTransaction>), BlockError>
-    {
+    ) -> Result<BlockDetails, BlockError> {
         let block = self
             .block_repo
             .find_block_by_height(height)
             .await
-            .map_err(BlockError::Database)?
-            .map(Block::from_db);
+            .map_err(BlockError::Database)?;
 
         let block = block.ok_or(BlockError::NotFound(
             "height".to_string(),
             height.to_string(),
         ))?;
 
-        let prev_block = if let Some(block_height) = height.checked_sub(1) {
-            self.block_repo
-                .find_block_by_height(block_height)
-                .await
-                .map_err(BlockError::Database)?
-                .map(Block::from_db)
+        self.get_block_details(block).await
+    }
+
+    pub async fn get_block_by_timestamp(
+        &self,
+        timestamp: i64,
+    ) -> Result<BlockDetails, BlockError> {
+        let block = self
+            .block_repo
+            .find_block_by_timestamp(timestamp)
+            .await
+            .map_err(BlockError::Database)?;
+
+        let block = block.ok_or(BlockError::NotFound(
+            "timestamp".to_string(),
+            timestamp.to_string(),
+        ))?;
+
+        self.get_block_details(block).await
+    }
+
+    /// Blocks between the two heights, both included, by ascending height
+    pub async fn get_blocks_by_height_range(
+        &self,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<BlockDetails>, BlockError> {
+        if from_height > to_height {
+            return Err(BlockError::InvalidRange(
+                "fromHeight must be lower than toHeight".to_string(),
+            ));
+        }
+        if to_height - from_height >= MAX_BLOCK_RANGE {
+            return Err(BlockError::InvalidRange(format!(
+                "at most {} blocks can be requested at once",
+                MAX_BLOCK_RANGE
+            )));
+        }
+        let from_height = i32::try_from(from_height)
+            .map_err(|e| BlockError::InvalidRange(e.to_string()))?;
+        let to_height = i32::try_from(to_height)
+            .map_err(|e| BlockError::InvalidRange(e.to_string()))?;
+
+        // The block before the range is loaded for the parent hashes
+        let blocks = self
+            .block_repo
+            .find_blocks_by_height_range(
+                from_height.saturating_sub(1),
+                to_height,
+            )
+            .await
+            .map_err(BlockError::Database)?;
+
+        self.get_blocks_details(blocks, from_height, to_height)
+            .await
+    }
+
+    /// Most recent blocks, by descending height
+    pub async fn get_latest_blocks(
+        &self,
+        limit: Option<u64>,
+    ) -> Result<Vec<BlockDetails>, BlockError> {
+        let limit = limit
+            .unwrap_or(DEFAULT_LATEST_BLOCKS)
+            .clamp(1, MAX_BLOCK_RANGE) as i64;
+
+        // One more block is loaded for the parent hashes
+        let mut blocks = self
+            .block_repo
+            .find_latest_blocks(limit + 1)
+            .await
+            .map_err(BlockError::Database)?;
+        blocks.reverse();
+
+        let (Some(first), Some(last)) = (blocks.first(), blocks.last()) else {
+            return Ok(vec![]);
+        };
+        let from_height = if blocks.len() as i64 > limit {
+            first.height + 1
         } else {
-            None
+            first.height
         };
+        let to_height = last.height;
+
+        let mut blocks = self
+            .get_blocks_details(blocks, from_height, to_height)
+            .await?;
+        blocks.reverse();
+
+        Ok(blocks)
+    }
+
+    /// Wrapper transactions of a block, in block order, each with its inner
+    /// transactions in batch order
+    pub async fn get_block_transactions(
+        &self,
+        height: i32,
+    ) -> Result<Vec<(WrapperTransaction, Vec<InnerTransaction>)>, BlockError>
+    {
+        self.block_repo
+            .find_block_by_height(height)
+            .await
+            .map_err(BlockError::Database)?
+            .ok_or(BlockError::NotFound(
+                "height".to_string(),
+                height.to_string(),
+            ))?;
 
         let tokens = self
             .chain_repo
@@ -107,46 +178,50 @@ impl BlockService {
             .await
             .map_err(BlockError::Database)?;
 
-        let transactions = self
+        let wrappers = self
             .transaction_repo
             .find_txs_by_block_height(height)
             .await
             .map_err(BlockError::Database)?;
 
-        let transactions = transactions
-            .into_iter()
-            .map(|tx| WrapperTransaction::from_db(tx, tokens.clone()))
-            .collect();
-
-        Ok((block, prev_block, transactions))
-    }
-
-    pub async fn get_block_by_timestamp(
-        &self,
-        timestamp: i64,
-    ) -> Result<(Block, Option<Block>, Vec<WrapperTransaction>), BlockError>
-    {
-        let block = self
-            .block_repo
-            .find_block_by_timestamp(timestamp)
+        let inners = self
+            .transaction_repo
+            .find_inners_by_wrapper_txs(
+                wrappers.iter().map(|wrapper| wrapper.id.clone()).collect(),
+            )
             .await
-            .map_err(BlockError::Database)?
-            .map(Block::from_db);
+            .map_err(BlockError::Database)?;
 
-        let block = block.ok_or(BlockError::NotFound(
-            "timestamp".to_string(),
-            timestamp.to_string(),
-        ))?;
+        let mut inners_by_wrapper = inners.into_iter().fold(
+            BTreeMap::<String, Vec<InnerTransaction>>::new(),
+            |mut acc, inner| {
+                acc.entry(inner.wrapper_id.clone())
+                    .or_default()
+                    .push(InnerTransaction::from(inner));
+                acc
+            },
+        );
 
-        let block_height = block.height as i32;
+        Ok(wrappers
+            .into_iter()
+            .map(|wrapper| {
+                let inners =
+                    inners_by_wrapper.remove(&wrapper.id).unwrap_or_default();
+                (WrapperTransaction::from_db(wrapper, tokens.clone()), inners)
+            })
+            .collect())
+    }
 
-        let prev_block = if let Some(block_height) = block_height.checked_sub(1)
+    async fn get_block_details(
+        &self,
+        block: BlockDb,
+    ) -> Result<BlockDetails, BlockError> {
+        let prev_block = if let Some(block_height) = block.height.checked_sub(1)
         {
             self.block_repo
                 .find_block_by_height(block_height)
                 .await
                 .map_err(BlockError::Database)?
-                .map(Block::from_db)
         } else {
             None
         };
@@ -159,15 +234,81 @@ impl BlockService {
 
         let transactions = self
             .transaction_repo
-            .find_txs_by_block_height(block_height)
+            .find_txs_by_block_height(block.height)
             .await
             .map_err(BlockError::Database)?;
 
-        let transactions = transactions
-            .into_iter()
-            .map(|tx| WrapperTransaction::from_db(tx, tokens.clone()))
-            .collect();
+        Ok(block_details(block, prev_block, transactions, &tokens))
+    }
+
+    /// Details of the blocks from `from_height` to `to_height`, `blocks`
+    /// being sorted by ascending height and possibly starting with the
+    /// parent of the first one
+    async fn get_blocks_details(
+        &self,
+        blocks: Vec<BlockDb>,
+        from_height: i32,
+        to_height: i32,
+    ) -> Result<Vec<BlockDetails>, BlockError> {
+        let tokens = self
+            .chain_repo
+            .find_tokens()
+            .await
+            .map_err(BlockError::Database)?;
+
+        let transactions = self
+            .transaction_repo
+            .find_txs_by_block_height_range(from_height, to_height)
+            .await
+            .map_err(BlockError::Database)?;
+
+        let mut transactions_by_height = transactions.into_iter().fold(
+            BTreeMap::<i32, Vec<WrapperTransactionDb>>::new(),
+            |mut acc, wrapper| {
+                acc.entry(wrapper.block_height).or_default().push(wrapper);
+                acc
+            },
+        );
 
-        Ok((block, prev_block, transactions))
+        let mut prev_block: Option<BlockDb> = None;
+        let mut details = vec![];
+        for block in blocks {
+            let parent = prev_block
+                .replace(block.clone())
+                .filter(|prev| prev.height + 1 == block.height);
+
+            if block.height >= from_height {
+                let transactions = transactions_by_height
+                    .remove(&block.height)
+                    .unwrap_or_default();
+                details.push(block_details(
+                    block,
+                    parent,
+                    transactions,
+                    &tokens,
+                ));
+            }
+        }
+
+        Ok(details)
+    }
+}
+
+fn block_details(
+    block: BlockDb,
+    prev_block: Option<BlockDb>,
+    transactions: Vec<WrapperTransactionDb>,
+    tokens: &[(TokenDb, Option<IbcTokenDb>)],
+) -> BlockDetails {
+    let aggregates = BlockAggregates::from_db(&transactions);
+
+    BlockDetails {
+        block: Block::from_db(block),
+        prev_block: prev_block.map(Block::from_db),
+        transactions: transactions
+            .into_iter()
+            .map(|tx| WrapperTransaction::from_db(tx, tokens.to_vec()))
+            .collect(),
+        aggregates,
     }
 }
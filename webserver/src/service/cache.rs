@@ -0,0 +1,246 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use deadpool_redis::redis::AsyncCommands;
+
+use crate::appstate::AppState;
+use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
+
+const KEY_PREFIX: &str = "namada-indexer";
+/// How often the chain crawler progress embedded in the keys is refreshed
+const VERSION_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// What makes a cached value stale, besides its ttl
+#[derive(Clone, Copy, Debug)]
+pub enum Invalidation {
+    /// The value changes whenever the chain crawler processes a new block
+    Block,
+    /// The value only changes once the chain crawler reaches a new epoch
+    Epoch,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CachePolicy {
+    pub name: &'static str,
+    /// Time to live of the cached value, in seconds
+    pub ttl: u64,
+    pub invalidation: Invalidation,
+}
+
+pub const VALIDATORS: CachePolicy = CachePolicy {
+    name: "validators",
+    ttl: 60,
+    invalidation: Invalidation::Block,
+};
+pub const ALL_VALIDATORS: CachePolicy = CachePolicy {
+    name: "all-validators",
+    ttl: 60,
+    invalidation: Invalidation::Block,
+};
+pub const CHAIN_PARAMETERS: CachePolicy = CachePolicy {
+    name: "chain-parameters",
+    ttl: 600,
+    invalidation: Invalidation::Epoch,
+};
+pub const TOKEN_SUPPLY: CachePolicy = CachePolicy {
+    name: "token-supply",
+    ttl: 600,
+    invalidation: Invalidation::Epoch,
+};
+pub const GAS_PRICES: CachePolicy = CachePolicy {
+    name: "gas-prices",
+    ttl: 300,
+    invalidation: Invalidation::Epoch,
+};
+pub const MASP_AGGREGATES: CachePolicy = CachePolicy {
+    name: "masp-aggregates",
+    ttl: 30,
+    invalidation: Invalidation::Block,
+};
+pub const GOVERNANCE_PROPOSALS: CachePolicy = CachePolicy {
+    name: "governance-proposals",
+    ttl: 30,
+    invalidation: Invalidation::Block,
+};
+
+/// Last block and epoch processed by the chain crawler
+#[derive(Clone, Copy, Debug)]
+pub struct ChainVersion {
+    pub block: i32,
+    pub epoch: i32,
+}
+
+impl ChainVersion {
+    /// Key of the value cached under `policy` for a request. Keys embed the
+    /// last block or epoch processed by the chain crawler, so that values are
+    /// invalidated as soon as the crawler moves forward.
+    pub fn key(&self, policy: CachePolicy, request: &str) -> String {
+        let version = match policy.invalidation {
+            Invalidation::Block => format!("block:{}", self.block),
+            Invalidation::Epoch => format!("epoch:{}", self.epoch),
+        };
+
+        format!("{KEY_PREFIX}:{}:{version}:{request}", policy.name)
+    }
+}
+
+/// Where the responses of cached endpoints are stored
+#[async_trait]
+pub trait ResponseCache: Clone + Send + Sync + 'static {
+    /// Key of the value cached under `policy` for a request, if it can be
+    /// cached right now
+    fn key(&self, policy: CachePolicy, request: &str) -> Option<String>;
+
+    /// Cache failures are logged and treated as a miss
+    async fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    async fn set(&self, key: &str, value: &[u8], ttl: u64);
+}
+
+#[derive(Clone)]
+pub struct CacheService {
+    app_state: AppState,
+    version: Arc<RwLock<Option<ChainVersion>>>,
+}
+
+impl CacheService {
+    /// Create the service and spawn the task tracking the chain crawler
+    /// progress, so that building a key does not cost a db query
+    pub fn new(app_state: AppState) -> Self {
+        let service = Self {
+            app_state: app_state.clone(),
+            version: Arc::new(RwLock::new(None)),
+        };
+
+        tokio::spawn(
+            service
+                .clone()
+                .refresh_version(ChainRepository::new(app_state)),
+        );
+
+        service
+    }
+
+    async fn refresh_version(self, chain_repo: ChainRepository) {
+        let mut interval = tokio::time::interval(VERSION_REFRESH_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match chain_repo.get_state().await {
+                Ok(state) => {
+                    *self.version.write().unwrap() = Some(ChainVersion {
+                        block: state.last_processed_block,
+                        epoch: state.last_processed_epoch,
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to get chain crawler state: {}", e)
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ResponseCache for CacheService {
+    /// There is no key until the crawler progress is known
+    fn key(&self, policy: CachePolicy, request: &str) -> Option<String> {
+        self.version
+            .read()
+            .unwrap()
+            .map(|version| version.key(policy, request))
+    }
+
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut conn = self.app_state.get_cache_connection().await?;
+
+        conn.get::<_, Option<Vec<u8>>>(key)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!("Failed to read cache {}: {}", key, e)
+            })
+            .ok()
+            .flatten()
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: u64) {
+        let Some(mut conn) = self.app_state.get_cache_connection().await else {
+            return;
+        };
+
+        if let Err(e) = conn.set_ex::<_, _, ()>(key, value, ttl).await {
+            tracing::warn!("Failed to write cache {}: {}", key, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUEST: &str = "/api/v1/chain/parameters?page=1";
+
+    #[test]
+    fn keys_embed_the_policy_and_request() {
+        let version = ChainVersion {
+            block: 120,
+            epoch: 4,
+        };
+
+        assert_eq!(
+            version.key(VALIDATORS, REQUEST),
+            format!("namada-indexer:validators:block:120:{REQUEST}")
+        );
+        assert_eq!(
+            version.key(CHAIN_PARAMETERS, REQUEST),
+            format!("namada-indexer:chain-parameters:epoch:4:{REQUEST}")
+        );
+        assert_ne!(
+            version.key(VALIDATORS, REQUEST),
+            version.key(ALL_VALIDATORS, REQUEST)
+        );
+        assert_ne!(
+            version.key(VALIDATORS, REQUEST),
+            version.key(VALIDATORS, "/api/v1/pos/validator?page=2")
+        );
+    }
+
+    #[test]
+    fn new_blocks_invalidate_block_keys_only() {
+        let before = ChainVersion {
+            block: 120,
+            epoch: 4,
+        };
+        let after = ChainVersion {
+            block: 121,
+            ..before
+        };
+
+        assert_ne!(
+            before.key(VALIDATORS, REQUEST),
+            after.key(VALIDATORS, REQUEST)
+        );
+        assert_eq!(
+            before.key(CHAIN_PARAMETERS, REQUEST),
+            after.key(CHAIN_PARAMETERS, REQUEST)
+        );
+    }
+
+    #[test]
+    fn new_epochs_invalidate_every_key() {
+        let before = ChainVersion {
+            block: 120,
+            epoch: 4,
+        };
+        let after = ChainVersion {
+            block: 121,
+            epoch: 5,
+        };
+
+        for policy in [VALIDATORS, CHAIN_PARAMETERS, GAS_PRICES] {
+            assert_ne!(before.key(policy, REQUEST), after.key(policy, REQUEST));
+        }
+    }
+}
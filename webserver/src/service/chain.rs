@@ -3,10 +3,13 @@ use shared::id::Id;
 use shared::token::{IbcToken, Token};
 
 use crate::appstate::AppState;
-use crate::entity::chain::{CirculatingSupply, Parameters, TokenSupply};
+use crate::entity::chain::{
+    CirculatingSupply, EpochTimeEstimator, Parameters, TokenSupply,
+};
 use crate::error::chain::ChainError;
 use crate::repository::balance::{BalanceRepo, BalanceRepoTrait};
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
+use crate::service::utils::epoch_time_estimator;
 
 #[derive(Clone)]
 pub struct ChainService {
@@ -33,6 +36,14 @@ impl ChainService {
         Ok(parameters)
     }
 
+    pub async fn get_epoch_time_estimator(
+        &self,
+    ) -> Result<EpochTimeEstimator, ChainError> {
+        epoch_time_estimator(&self.chain_repo)
+            .await
+            .map_err(ChainError::Database)
+    }
+
     pub async fn find_last_processed_block(&self) -> Result<i32, ChainError> {
         self.chain_repo
             .find_latest_height()
@@ -6,12 +6,14 @@ use subtle_encoding::hex;
 
 use crate::appstate::AppState;
 use crate::dto::governance::{ProposalKind, ProposalStatus};
+use crate::entity::cursor::{Cursor, IdCursor};
 use crate::entity::governance::{
     Proposal, ProposalData, ProposalType, ProposalVote,
 };
 use crate::error::governance::GovernanceError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::governance::{GovernanceRepo, GovernanceRepoTrait};
+use crate::service::utils::{epoch_time_estimator, split_cursor_page};
 
 #[derive(Clone)]
 pub struct GovernanceService {
@@ -43,29 +45,14 @@ impl GovernanceService {
             .await
             .map_err(GovernanceError::Database)?;
 
-        let chain_state = self
-            .chain_repo
-            .get_state()
-            .await
-            .map_err(GovernanceError::Database)?;
-
-        let parameters = self
-            .chain_repo
-            .find_chain_parameters()
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
             .await
             .map_err(GovernanceError::Database)?;
 
         Ok((
             db_proposals
                 .into_iter()
-                .map(|p| {
-                    Proposal::from_db(
-                        p,
-                        &chain_state,
-                        parameters.max_block_time,
-                        parameters.min_duration,
-                    )
-                })
+                .map(|p| Proposal::from_db(p, &epoch_estimator))
                 .collect(),
             total_pages as u64,
             total_items as u64,
@@ -117,26 +104,33 @@ impl GovernanceService {
             .await
             .map_err(GovernanceError::Database)?;
 
-        let chain_state = self
-            .chain_repo
-            .get_state()
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        Ok(db_proposal.map(|p| Proposal::from_db(p, &epoch_estimator)))
+    }
+
+    pub async fn find_governance_proposals_by_ids(
+        &self,
+        proposal_ids: Vec<u64>,
+    ) -> Result<Vec<Proposal>, GovernanceError> {
+        let db_proposals = self
+            .governance_repo
+            .find_governance_proposals_by_ids(
+                proposal_ids.into_iter().map(|id| id as i32).collect(),
+            )
             .await
             .map_err(GovernanceError::Database)?;
 
-        let parameters = self
-            .chain_repo
-            .find_chain_parameters()
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
             .await
             .map_err(GovernanceError::Database)?;
 
-        Ok(db_proposal.map(|p| {
-            Proposal::from_db(
-                p,
-                &chain_state,
-                parameters.max_block_time,
-                parameters.min_duration,
-            )
-        }))
+        Ok(db_proposals
+            .into_iter()
+            .map(|p| Proposal::from_db(p, &epoch_estimator))
+            .collect())
     }
 
     pub async fn find_governance_proposal_votes(
@@ -170,6 +164,50 @@ impl GovernanceService {
         ))
     }
 
+    pub async fn find_governance_proposal_votes_after(
+        &self,
+        proposal_id: u64,
+        cursor: &str,
+        limit: u64,
+    ) -> Result<(Vec<ProposalVote>, Option<String>), GovernanceError> {
+        let after = IdCursor::decode(cursor)
+            .map_err(GovernanceError::InvalidCursor)?
+            .map(|cursor| cursor.id);
+
+        let db_proposal = self
+            .governance_repo
+            .find_governance_proposals_by_id(proposal_id as i32)
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        if db_proposal.is_none() {
+            return Err(GovernanceError::NotFound(proposal_id));
+        }
+
+        let db_proposal_votes = self
+            .governance_repo
+            .find_governance_proposal_votes_after(
+                proposal_id as i32,
+                after,
+                limit as i64 + 1,
+            )
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let (db_proposal_votes, next_cursor) =
+            split_cursor_page(db_proposal_votes, limit, |vote| IdCursor {
+                id: vote.id,
+            });
+
+        Ok((
+            db_proposal_votes
+                .into_iter()
+                .map(ProposalVote::from)
+                .collect(),
+            next_cursor,
+        ))
+    }
+
     pub async fn find_governance_proposal_votes_by_address(
         &self,
         proposal_id: u64,
@@ -216,6 +254,40 @@ impl GovernanceService {
             .collect())
     }
 
+    pub async fn find_governance_proposal_votes_by_voter_after(
+        &self,
+        voter_address: String,
+        cursor: &str,
+        limit: u64,
+    ) -> Result<(Vec<ProposalVote>, Option<String>), GovernanceError> {
+        let after = IdCursor::decode(cursor)
+            .map_err(GovernanceError::InvalidCursor)?
+            .map(|cursor| cursor.id);
+
+        let db_proposal_votes = self
+            .governance_repo
+            .find_governance_proposal_votes_by_voter_after(
+                voter_address,
+                after,
+                limit as i64 + 1,
+            )
+            .await
+            .map_err(GovernanceError::Database)?;
+
+        let (db_proposal_votes, next_cursor) =
+            split_cursor_page(db_proposal_votes, limit, |vote| IdCursor {
+                id: vote.id,
+            });
+
+        Ok((
+            db_proposal_votes
+                .into_iter()
+                .map(ProposalVote::from)
+                .collect(),
+            next_cursor,
+        ))
+    }
+
     fn map_status(
         &self,
         status: Option<ProposalStatus>,
@@ -1,10 +1,13 @@
 use bigdecimal::BigDecimal;
+use chrono::Utc;
 use orm::ibc::IbcAckStatusDb;
 use shared::id::Id;
 
 use crate::appstate::AppState;
 use crate::entity::ibc::{
-    IbcAck, IbcAckStatus, IbcRateLimit, IbcTokenFlow, IbcTokenThroughput,
+    IbcAck, IbcAckStatus, IbcChannel, IbcChannelStats, IbcChannelTokenVolume,
+    IbcPacket, IbcPacketCounts, IbcRateLimit, IbcRateLimitUtilization,
+    IbcTokenFlow, IbcTokenThroughput, IbcTransfer,
 };
 use crate::error::ibc::IbcError;
 use crate::repository::ibc::{IbcRepository, IbcRepositoryTrait};
@@ -107,4 +110,196 @@ impl IbcService {
                 limit: limit.parse().expect("Should be a valid number"),
             })
     }
+
+    pub async fn get_channels(&self) -> Result<Vec<IbcChannel>, IbcError> {
+        self.ibc_repo
+            .find_channels()
+            .await
+            .map_err(IbcError::Database)
+            .map(|channels| {
+                channels
+                    .into_iter()
+                    .map(|(channel, connection, client)| {
+                        IbcChannel::from_db(channel, connection, client)
+                    })
+                    .collect()
+            })
+    }
+
+    pub async fn get_channel_stats(
+        &self,
+        channel_id: String,
+    ) -> Result<IbcChannelStats, IbcError> {
+        let (channel, connection, client) = self
+            .ibc_repo
+            .find_channel(channel_id.clone())
+            .await
+            .map_err(IbcError::Database)?
+            .ok_or(IbcError::ChannelNotFound(channel_id))?;
+        let channel = IbcChannel::from_db(channel, connection, client);
+
+        let packets = self
+            .ibc_repo
+            .get_channel_packet_counts(
+                channel.port_id.clone(),
+                channel.channel_id.clone(),
+            )
+            .await
+            .map_err(IbcError::Database)
+            .map(IbcPacketCounts::from_db)?;
+
+        let volume = self
+            .ibc_repo
+            .get_channel_token_flows(
+                channel.port_id.clone(),
+                channel.channel_id.clone(),
+            )
+            .await
+            .map_err(IbcError::Database)?
+            .into_iter()
+            .map(
+                |(address, trace, deposit, withdraw)| IbcChannelTokenVolume {
+                    token_address: Id::Account(address),
+                    trace,
+                    deposit: deposit.unwrap_or_default(),
+                    withdraw: withdraw.unwrap_or_default(),
+                },
+            )
+            .collect();
+
+        Ok(IbcChannelStats {
+            channel,
+            packets,
+            volume,
+        })
+    }
+
+    pub async fn get_packets(
+        &self,
+        channel_id: String,
+        sequence: u64,
+    ) -> Result<Vec<IbcPacket>, IbcError> {
+        let Ok(sequence) = i64::try_from(sequence) else {
+            return Ok(vec![]);
+        };
+        let now = now_nanos();
+
+        self.ibc_repo
+            .find_packets_by_channel_and_sequence(channel_id, sequence)
+            .await
+            .map_err(IbcError::Database)
+            .map(|packets| {
+                packets
+                    .into_iter()
+                    .map(|packet| IbcPacket::from_db(packet, now))
+                    .collect()
+            })
+    }
+
+    pub async fn get_packets_by_address(
+        &self,
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<IbcPacket>, u64, u64), IbcError> {
+        let now = now_nanos();
+
+        let (packets, total_pages, total_items) = self
+            .ibc_repo
+            .find_packets_by_address(address, page as i64)
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            packets
+                .into_iter()
+                .map(|packet| IbcPacket::from_db(packet, now))
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn get_transfers_by_address(
+        &self,
+        address: String,
+        page: u64,
+    ) -> Result<(Vec<IbcTransfer>, u64, u64), IbcError> {
+        let (transfers, total_pages, total_items) = self
+            .ibc_repo
+            .find_transfers_by_address(address, page as i64)
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            transfers
+                .into_iter()
+                .map(
+                    |(
+                        history,
+                        inner,
+                        block_height,
+                        sent,
+                        received,
+                        ibc_trace,
+                    )| {
+                        IbcTransfer::from_db(
+                            history,
+                            inner,
+                            block_height,
+                            sent.or(received),
+                            ibc_trace,
+                        )
+                    },
+                )
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
+    pub async fn get_rate_limit_utilization(
+        &self,
+        token_address: Option<String>,
+        from_epoch: Option<u64>,
+        to_epoch: Option<u64>,
+        page: u64,
+    ) -> Result<(Vec<IbcRateLimitUtilization>, u64, u64), IbcError> {
+        let epoch = |epoch: u64| {
+            i32::try_from(epoch).map_err(|_| IbcError::InvalidEpoch(epoch))
+        };
+        let from_epoch = from_epoch.map(epoch).transpose()?;
+        let to_epoch = to_epoch.map(epoch).transpose()?;
+
+        let (usages, total_pages, total_items) = self
+            .ibc_repo
+            .find_rate_limit_usage(
+                token_address,
+                from_epoch,
+                to_epoch,
+                page as i64,
+            )
+            .await
+            .map_err(IbcError::Database)?;
+
+        Ok((
+            usages
+                .into_iter()
+                .map(|(token_address, epoch, deposit, withdraw, limit)| {
+                    IbcRateLimitUtilization::from_db(
+                        token_address,
+                        epoch,
+                        deposit,
+                        withdraw,
+                        limit,
+                    )
+                })
+                .collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+}
+
+fn now_nanos() -> u64 {
+    Utc::now().timestamp_nanos_opt().unwrap_or(i64::MAX) as u64
 }
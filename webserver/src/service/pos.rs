@@ -1,18 +1,26 @@
+use std::collections::HashMap;
+
 use bigdecimal::{BigDecimal, Zero};
+use futures::{Stream, stream};
 use orm::helpers::OrderByDb;
-use orm::validators::{ValidatorSortByDb, ValidatorStateDb};
+use orm::validators::{ValidatorDb, ValidatorSortByDb, ValidatorStateDb};
 use shared::crawler_state::ChainCrawlerState;
 use shared::parameters::Parameters;
 
 use crate::appstate::AppState;
+use crate::constant::{DEFAULT_UPTIME_WINDOW, EXPORT_BATCH_SIZE};
 use crate::dto::pos::{OrderByDto, ValidatorSortFieldDto, ValidatorStateDto};
+use crate::entity::cursor::{Cursor, IdCursor, RewardCursor};
+use crate::entity::export::ExportRow;
 use crate::entity::pos::{
-    Bond, BondStatus, MergedBond, MergedBondRedelegation, Reward, Unbond,
-    ValidatorWithRank, Withdraw,
+    Bond, BondStatus, DelegatorPosition, DelegatorSummary, MergedBond,
+    MergedBondRedelegation, Reward, RewardAccrual, Slash, Unbond,
+    ValidatorHistory, ValidatorUptime, ValidatorWithRank, Withdraw,
 };
 use crate::error::pos::PoSError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::pos::{PosRepository, PosRepositoryTrait};
+use crate::service::utils::{epoch_time_estimator, split_cursor_page};
 
 #[derive(Clone)]
 pub struct PosService {
@@ -54,6 +62,12 @@ impl PosService {
             .get_validators_rank()
             .await
             .map_err(PoSError::Database)?;
+        let validators_uptime = self
+            .get_validators_uptime(
+                db_validators.iter().map(|v| v.id).collect(),
+                DEFAULT_UPTIME_WINDOW,
+            )
+            .await?;
 
         let validators = db_validators
             .into_iter()
@@ -62,7 +76,13 @@ impl PosService {
                     .iter()
                     .position(|v_id| v_id == &v.id)
                     .map(|r| (r + 1) as i32);
-                ValidatorWithRank::from(v, rank)
+                let uptime = validators_uptime
+                    .get(&v.id)
+                    .and_then(ValidatorUptime::uptime);
+                ValidatorWithRank {
+                    uptime,
+                    ..ValidatorWithRank::from(v, rank)
+                }
             })
             .collect();
 
@@ -87,6 +107,12 @@ impl PosService {
             .get_validators_rank()
             .await
             .map_err(PoSError::Database)?;
+        let validators_uptime = self
+            .get_validators_uptime(
+                db_validators.iter().map(|v| v.id).collect(),
+                DEFAULT_UPTIME_WINDOW,
+            )
+            .await?;
         let validators = db_validators
             .into_iter()
             .map(|v| {
@@ -94,7 +120,13 @@ impl PosService {
                     .iter()
                     .position(|v_id| v_id == &v.id)
                     .map(|r| (r + 1) as i32);
-                ValidatorWithRank::from(v, rank)
+                let uptime = validators_uptime
+                    .get(&v.id)
+                    .and_then(ValidatorUptime::uptime);
+                ValidatorWithRank {
+                    uptime,
+                    ..ValidatorWithRank::from(v, rank)
+                }
             })
             .collect();
 
@@ -130,6 +162,50 @@ impl PosService {
         Ok((bonds, total_pages as u64, total_items as u64))
     }
 
+    pub async fn get_bonds_by_address_after(
+        &self,
+        address: String,
+        cursor: &str,
+        limit: u64,
+        active_at: Option<i32>,
+    ) -> Result<(Vec<Bond>, Option<String>), PoSError> {
+        let after = IdCursor::decode(cursor)
+            .map_err(PoSError::InvalidCursor)?
+            .map(|cursor| cursor.id);
+
+        let pos_state = self
+            .pos_repo
+            .get_state()
+            .await
+            .map_err(PoSError::Database)?;
+
+        let db_bonds = self
+            .pos_repo
+            .find_bonds_by_address_after(
+                address,
+                active_at,
+                after,
+                limit as i64 + 1,
+            )
+            .await
+            .map_err(PoSError::Database)?;
+
+        let (db_bonds, next_cursor) =
+            split_cursor_page(db_bonds, limit, |(_, bond)| IdCursor {
+                id: bond.id,
+            });
+
+        let bonds = db_bonds
+            .into_iter()
+            .map(|(validator, bond)| {
+                let bond_status = BondStatus::from((&bond, &pos_state));
+                Bond::from(bond, bond_status, validator)
+            })
+            .collect();
+
+        Ok((bonds, next_cursor))
+    }
+
     pub async fn get_merged_bonds_by_address(
         &self,
         address: String,
@@ -199,33 +275,69 @@ impl PosService {
             .await
             .map_err(PoSError::Database)?;
 
-        let chain_state = self
-            .chain_repo
-            .get_state()
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
             .await
             .map_err(PoSError::Database)?;
 
-        let parameters = self
-            .chain_repo
-            .find_chain_parameters()
+        let unbonds: Vec<Unbond> = db_unbonds
+            .into_iter()
+            .map(|(validator, unbond)| {
+                Unbond::from(
+                    unbond.raw_amount,
+                    unbond.withdraw_epoch,
+                    validator,
+                    &epoch_estimator,
+                )
+            })
+            .collect();
+
+        Ok((unbonds, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_unbonds_by_address_after(
+        &self,
+        address: String,
+        cursor: &str,
+        limit: u64,
+        active_at: Option<i32>,
+    ) -> Result<(Vec<Unbond>, Option<String>), PoSError> {
+        let after = IdCursor::decode(cursor)
+            .map_err(PoSError::InvalidCursor)?
+            .map(|cursor| cursor.id);
+
+        let db_unbonds = self
+            .pos_repo
+            .find_unbonds_by_address_after(
+                address,
+                active_at,
+                after,
+                limit as i64 + 1,
+            )
             .await
             .map_err(PoSError::Database)?;
 
-        let unbonds: Vec<Unbond> = db_unbonds
+        let (db_unbonds, next_cursor) =
+            split_cursor_page(db_unbonds, limit, |(_, unbond)| IdCursor {
+                id: unbond.id,
+            });
+
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let unbonds = db_unbonds
             .into_iter()
             .map(|(validator, unbond)| {
                 Unbond::from(
                     unbond.raw_amount,
                     unbond.withdraw_epoch,
                     validator,
-                    &chain_state,
-                    parameters.max_block_time,
-                    parameters.min_duration,
+                    &epoch_estimator,
                 )
             })
             .collect();
 
-        Ok((unbonds, total_pages as u64, total_items as u64))
+        Ok((unbonds, next_cursor))
     }
 
     pub async fn get_merged_unbonds_by_address(
@@ -233,12 +345,6 @@ impl PosService {
         address: String,
         page: u64,
     ) -> Result<(Vec<Unbond>, u64, u64), PoSError> {
-        let chain_state = self
-            .chain_repo
-            .get_state()
-            .await
-            .map_err(PoSError::Database)?;
-
         let pos_state = self
             .pos_repo
             .get_state()
@@ -255,9 +361,7 @@ impl PosService {
             .await
             .map_err(PoSError::Database)?;
 
-        let parameters = self
-            .chain_repo
-            .find_chain_parameters()
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
             .await
             .map_err(PoSError::Database)?;
 
@@ -268,9 +372,7 @@ impl PosService {
                     raw_amount.unwrap_or(BigDecimal::zero()),
                     withdraw_epoch,
                     validator,
-                    &chain_state,
-                    parameters.max_block_time,
-                    parameters.min_duration,
+                    &epoch_estimator,
                 )
             })
             .collect();
@@ -284,15 +386,13 @@ impl PosService {
         epoch: Option<u64>,
         page: u64,
     ) -> Result<(Vec<Withdraw>, u64, u64), PoSError> {
-        let epoch = if let Some(epoch) = epoch {
-            epoch as i32
-        } else {
-            self.chain_repo
-                .get_state()
-                .await
-                .map_err(PoSError::Database)?
-                .last_processed_epoch
-        };
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let epoch = epoch
+            .map(|epoch| epoch as i32)
+            .unwrap_or(epoch_estimator.current_epoch);
 
         let (db_withdraws, total_pages, total_items) = self
             .pos_repo
@@ -302,7 +402,9 @@ impl PosService {
 
         let withdraws: Vec<Withdraw> = db_withdraws
             .into_iter()
-            .map(|(validator, withdraw)| Withdraw::from(withdraw, validator))
+            .map(|(validator, withdraw)| {
+                Withdraw::from(withdraw, validator, &epoch_estimator)
+            })
             .collect();
 
         Ok((withdraws, total_pages as u64, total_items as u64))
@@ -336,6 +438,103 @@ impl PosService {
         Ok(rewards)
     }
 
+    pub async fn get_rewards_by_address_after(
+        &self,
+        address: String,
+        cursor: &str,
+        limit: u64,
+        epoch: Option<u64>,
+    ) -> Result<(Vec<Reward>, Option<String>), PoSError> {
+        let after = RewardCursor::decode(cursor)
+            .map_err(PoSError::InvalidCursor)?
+            .map(|cursor| (cursor.epoch, cursor.id));
+
+        let db_rewards = self
+            .pos_repo
+            .find_rewards_by_address_after(
+                address,
+                epoch,
+                after,
+                limit as i64 + 1,
+            )
+            .await
+            .map_err(PoSError::Database)?;
+
+        let (db_rewards, next_cursor) =
+            split_cursor_page(db_rewards, limit, |(id, reward, _)| {
+                RewardCursor {
+                    epoch: reward.epoch,
+                    id: *id,
+                }
+            });
+
+        let rewards = db_rewards
+            .into_iter()
+            .map(|(_, db_reward, db_validator)| {
+                Reward::from(db_reward, db_validator)
+            })
+            .collect();
+
+        Ok((rewards, next_cursor))
+    }
+
+    /// Stream, batch by batch, the rewards `address` earned in every epoch
+    pub async fn export_rewards(
+        &self,
+        address: String,
+    ) -> Result<
+        impl Stream<Item = Result<Vec<ExportRow>, PoSError>> + use<>,
+        PoSError,
+    > {
+        let native_token = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(PoSError::Database)?
+            .native_token_address;
+        let pos_repo = self.pos_repo.clone();
+
+        Ok(stream::try_unfold(Some(None), move |after| {
+            let address = address.clone();
+            let native_token = native_token.clone();
+            let pos_repo = pos_repo.clone();
+
+            async move {
+                let Some(after) = after else {
+                    return Ok(None);
+                };
+
+                let accruals = pos_repo
+                    .find_all_reward_accruals_by_address_after(
+                        address,
+                        after,
+                        EXPORT_BATCH_SIZE as i64,
+                    )
+                    .await
+                    .map_err(PoSError::Database)?;
+
+                let next = (accruals.len() as u64 == EXPORT_BATCH_SIZE)
+                    .then(|| accruals.last())
+                    .flatten()
+                    .map(|(accrual, _, _)| Some(accrual.id));
+
+                let rows = accruals
+                    .into_iter()
+                    .map(|(accrual, validator, epoch_start)| {
+                        ExportRow::from_accrual(
+                            accrual,
+                            validator,
+                            epoch_start,
+                            &native_token,
+                        )
+                    })
+                    .collect();
+
+                Ok(Some((rows, next)))
+            }
+        }))
+    }
+
     pub async fn get_rewards_by_delegator_and_validator_and_epoch(
         &self,
         delegator: String,
@@ -381,6 +580,290 @@ impl PosService {
         Ok(rewards)
     }
 
+    pub async fn get_validator_by_address(
+        &self,
+        address: String,
+    ) -> Result<Option<ValidatorWithRank>, PoSError> {
+        let db_validator = self
+            .pos_repo
+            .find_validator_by_address(address)
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok(db_validator
+            .map(|db_validator| ValidatorWithRank::from(db_validator, None)))
+    }
+
+    pub async fn get_delegator_summary(
+        &self,
+        address: String,
+    ) -> Result<DelegatorSummary, PoSError> {
+        let parameters = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(PoSError::Database)?;
+        let chain_apr = parameters.apr.parse::<f64>().unwrap_or_default();
+
+        let epoch_estimator = epoch_time_estimator(&self.chain_repo)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let db_bonded = self
+            .pos_repo
+            .find_bonded_amounts_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?;
+
+        let db_unbonds = self
+            .pos_repo
+            .find_all_unbonds_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?;
+
+        let db_rewards = self
+            .pos_repo
+            .find_rewards_by_address(address, None)
+            .await
+            .map_err(PoSError::Database)?;
+
+        // Validators the delegator has a bond or unclaimed rewards with, in
+        // the order they are first seen
+        let mut positions: Vec<(ValidatorDb, BigDecimal, BigDecimal)> = vec![];
+        for (db_validator, raw_amount) in db_bonded {
+            positions.push((
+                db_validator,
+                raw_amount.unwrap_or(BigDecimal::zero()),
+                BigDecimal::zero(),
+            ));
+        }
+        for (db_reward, db_validator) in db_rewards {
+            match positions
+                .iter_mut()
+                .find(|(validator, _, _)| validator.id == db_validator.id)
+            {
+                Some((_, _, rewards)) => *rewards += db_reward.raw_amount,
+                None => positions.push((
+                    db_validator,
+                    BigDecimal::zero(),
+                    db_reward.raw_amount,
+                )),
+            }
+        }
+
+        let total_bonded = positions
+            .iter()
+            .map(|(_, bonded, _)| bonded)
+            .sum::<BigDecimal>();
+        let unclaimed_rewards = positions
+            .iter()
+            .map(|(_, _, rewards)| rewards)
+            .sum::<BigDecimal>();
+
+        let (pending_unbonds, withdrawable_unbonds): (Vec<_>, Vec<_>) =
+            db_unbonds.into_iter().partition(|(_, unbond)| {
+                unbond.withdraw_epoch > epoch_estimator.current_epoch
+            });
+
+        let total_unbonding = pending_unbonds
+            .iter()
+            .map(|(_, unbond)| &unbond.raw_amount)
+            .sum::<BigDecimal>();
+        let withdrawable = withdrawable_unbonds
+            .iter()
+            .map(|(_, unbond)| &unbond.raw_amount)
+            .sum::<BigDecimal>();
+
+        let pending_unbonds = pending_unbonds
+            .into_iter()
+            .map(|(validator, unbond)| {
+                Unbond::from(
+                    unbond.raw_amount,
+                    unbond.withdraw_epoch,
+                    validator,
+                    &epoch_estimator,
+                )
+            })
+            .collect();
+
+        let positions = positions
+            .into_iter()
+            .map(|(validator, bonded, rewards)| DelegatorPosition {
+                estimated_apr: DelegatorPosition::estimated_apr(
+                    chain_apr,
+                    &validator.commission,
+                ),
+                validator: ValidatorWithRank::from(validator, None),
+                bonded: bonded.into(),
+                unclaimed_rewards: rewards.into(),
+            })
+            .collect();
+
+        Ok(DelegatorSummary {
+            total_bonded: total_bonded.into(),
+            total_unbonding: total_unbonding.into(),
+            withdrawable: withdrawable.into(),
+            unclaimed_rewards: unclaimed_rewards.into(),
+            positions,
+            pending_unbonds,
+        })
+    }
+
+    pub async fn get_reward_history(
+        &self,
+        address: String,
+        page: u64,
+        from_epoch: Option<u64>,
+        to_epoch: Option<u64>,
+    ) -> Result<(Vec<RewardAccrual>, u64, u64), PoSError> {
+        let (db_accruals, total_pages, total_items) = self
+            .pos_repo
+            .find_reward_accruals_by_address(
+                address,
+                page as i64,
+                from_epoch.map(|epoch| epoch as i32),
+                to_epoch.map(|epoch| epoch as i32),
+            )
+            .await
+            .map_err(PoSError::Database)?;
+
+        let accruals = db_accruals
+            .into_iter()
+            .map(|(validator, accrual)| RewardAccrual::from(accrual, validator))
+            .collect();
+
+        Ok((accruals, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_slashes(
+        &self,
+        page: u64,
+    ) -> Result<(Vec<Slash>, u64, u64), PoSError> {
+        let (db_slashes, total_pages, total_items) = self
+            .pos_repo
+            .find_slashes(page as i64)
+            .await
+            .map_err(PoSError::Database)?;
+
+        let slashes = db_slashes
+            .into_iter()
+            .map(|(validator, slash)| Slash::from(slash, validator))
+            .collect();
+
+        Ok((slashes, total_pages as u64, total_items as u64))
+    }
+
+    pub async fn get_validator_slashes(
+        &self,
+        address: String,
+    ) -> Result<Vec<Slash>, PoSError> {
+        let db_validator = self
+            .pos_repo
+            .find_validator_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(address))?;
+
+        let db_slashes = self
+            .pos_repo
+            .find_slashes_by_validator_id(db_validator.id)
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok(db_slashes
+            .into_iter()
+            .map(|slash| Slash::from(slash, db_validator.clone()))
+            .collect())
+    }
+
+    pub async fn get_validator_history(
+        &self,
+        address: String,
+        from_epoch: Option<u64>,
+        to_epoch: Option<u64>,
+    ) -> Result<Vec<ValidatorHistory>, PoSError> {
+        let db_validator = self
+            .pos_repo
+            .find_validator_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(address))?;
+
+        let history = self
+            .pos_repo
+            .find_validator_history(
+                db_validator.id,
+                from_epoch.map(|epoch| epoch as i32),
+                to_epoch.map(|epoch| epoch as i32),
+            )
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok(history.into_iter().map(ValidatorHistory::from).collect())
+    }
+
+    pub async fn get_validator_uptime(
+        &self,
+        address: String,
+        window: u64,
+    ) -> Result<ValidatorUptime, PoSError> {
+        let db_validator = self
+            .pos_repo
+            .find_validator_by_address(address.clone())
+            .await
+            .map_err(PoSError::Database)?
+            .ok_or(PoSError::ValidatorNotFound(address))?;
+
+        let mut validators_uptime = self
+            .get_validators_uptime(vec![db_validator.id], window)
+            .await?;
+
+        Ok(validators_uptime
+            .remove(&db_validator.id)
+            .unwrap_or_else(|| ValidatorUptime {
+                window,
+                ..ValidatorUptime::default()
+            }))
+    }
+
+    /// Uptime of the validators over the last `window` epochs with uptime
+    /// data, validators without data are left out
+    async fn get_validators_uptime(
+        &self,
+        validator_ids: Vec<i32>,
+        window: u64,
+    ) -> Result<HashMap<i32, ValidatorUptime>, PoSError> {
+        let Some(to_epoch) = self
+            .pos_repo
+            .find_last_uptime_epoch()
+            .await
+            .map_err(PoSError::Database)?
+        else {
+            return Ok(HashMap::new());
+        };
+        let from_epoch = (to_epoch - window as i32 + 1).max(0);
+
+        let uptime = self
+            .pos_repo
+            .find_validators_uptime(validator_ids, from_epoch, to_epoch)
+            .await
+            .map_err(PoSError::Database)?;
+
+        Ok(uptime
+            .into_iter()
+            .map(|(validator_id, signed_blocks, missed_blocks)| {
+                let uptime = ValidatorUptime {
+                    window,
+                    from_epoch: from_epoch as u64,
+                    to_epoch: to_epoch as u64,
+                    signed_blocks: signed_blocks.unwrap_or_default() as u64,
+                    missed_blocks: missed_blocks.unwrap_or_default() as u64,
+                };
+                (validator_id, uptime)
+            })
+            .collect())
+    }
+
     // TODO: maybe return object(struct) instead
     pub async fn get_total_voting_power(&self) -> Result<u64, PoSError> {
         let total_voting_power_db = self
@@ -0,0 +1,242 @@
+use crate::appstate::AppState;
+use crate::constant::{DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT};
+use crate::entity::search::{SearchQuery, SearchResult, SearchResultKind};
+use crate::error::search::SearchError;
+use crate::repository::block::{BlockRepository, BlockRepositoryTrait};
+use crate::repository::governance::{GovernanceRepo, GovernanceRepoTrait};
+use crate::repository::pos::{PosRepository, PosRepositoryTrait};
+use crate::repository::search::{SearchRepository, SearchRepositoryTrait};
+use crate::repository::transaction::{
+    TransactionRepository, TransactionRepositoryTrait,
+};
+
+/// Shorter free text queries match too much to be worth a fuzzy search
+const MIN_FUZZY_QUERY_LENGTH: usize = 3;
+const MAX_QUERY_LENGTH: usize = 256;
+
+#[derive(Clone)]
+pub struct SearchService {
+    search_repo: SearchRepository,
+    block_repo: BlockRepository,
+    transaction_repo: TransactionRepository,
+    gov_repo: GovernanceRepo,
+    pos_repo: PosRepository,
+}
+
+impl SearchService {
+    pub fn new(app_state: AppState) -> Self {
+        Self {
+            search_repo: SearchRepository::new(app_state.clone()),
+            block_repo: BlockRepository::new(app_state.clone()),
+            transaction_repo: TransactionRepository::new(app_state.clone()),
+            gov_repo: GovernanceRepo::new(app_state.clone()),
+            pos_repo: PosRepository::new(app_state),
+        }
+    }
+
+    /// Exact matches on the classified input come first, followed by fuzzy
+    /// matches ranked by similarity
+    pub async fn search(
+        &self,
+        query: String,
+        limit: Option<u64>,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        let query = query.trim().to_string();
+        if query.is_empty() || query.len() > MAX_QUERY_LENGTH {
+            return Err(SearchError::InvalidQuery(format!(
+                "query must be between 1 and {} characters",
+                MAX_QUERY_LENGTH
+            )));
+        }
+        let limit = limit
+            .unwrap_or(DEFAULT_SEARCH_LIMIT)
+            .clamp(1, MAX_SEARCH_LIMIT) as usize;
+
+        let mut results = match SearchQuery::classify(&query) {
+            SearchQuery::Address(address) => {
+                self.search_address(address).await?
+            }
+            SearchQuery::Hash(hash) => self.search_hash(hash).await?,
+            SearchQuery::Number(number) => {
+                let mut results = self.search_number(number).await?;
+                results.extend(self.search_text(&query, limit).await?);
+                results
+            }
+            SearchQuery::Text(text) => self.search_text(&text, limit).await?,
+        };
+
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        let mut seen = Vec::<(SearchResultKind, String)>::new();
+        results.retain(|result| {
+            let key = (result.kind.clone(), result.id.clone());
+            let is_new = !seen.contains(&key);
+            seen.push(key);
+            is_new
+        });
+        results.truncate(limit);
+
+        Ok(results)
+    }
+
+    async fn search_address(
+        &self,
+        address: String,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        let validator = self
+            .pos_repo
+            .find_validator_by_address(address.clone())
+            .await
+            .map_err(SearchError::Database)?;
+
+        let mut results = vec![SearchResult::exact(
+            SearchResultKind::Account,
+            address,
+            None,
+        )];
+        if let Some(validator) = validator {
+            results.push(SearchResult::exact(
+                SearchResultKind::Validator,
+                validator.namada_address,
+                validator.name,
+            ));
+        }
+
+        Ok(results)
+    }
+
+    async fn search_hash(
+        &self,
+        hash: String,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        let mut results = vec![];
+
+        let wrapper_tx = self
+            .transaction_repo
+            .find_wrapper_tx(hash.clone())
+            .await
+            .map_err(SearchError::Database)?;
+        if let Some(wrapper_tx) = wrapper_tx {
+            results.push(SearchResult::exact(
+                SearchResultKind::WrapperTransaction,
+                wrapper_tx.id,
+                None,
+            ));
+        }
+
+        let inner_tx = self
+            .transaction_repo
+            .find_inner_tx(hash.clone())
+            .await
+            .map_err(SearchError::Database)?;
+        if let Some(inner_tx) = inner_tx {
+            results.push(SearchResult::exact(
+                SearchResultKind::InnerTransaction,
+                inner_tx.id,
+                None,
+            ));
+        }
+
+        let block = self
+            .block_repo
+            .find_block_by_hash(hash)
+            .await
+            .map_err(SearchError::Database)?;
+        if let Some(block) = block {
+            results.push(SearchResult::exact(
+                SearchResultKind::Block,
+                block.height.to_string(),
+                block.hash,
+            ));
+        }
+
+        Ok(results)
+    }
+
+    async fn search_number(
+        &self,
+        number: u64,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        let Ok(number) = i32::try_from(number) else {
+            return Ok(vec![]);
+        };
+        let mut results = vec![];
+
+        let block = self
+            .block_repo
+            .find_block_by_height(number)
+            .await
+            .map_err(SearchError::Database)?;
+        if let Some(block) = block {
+            results.push(SearchResult::exact(
+                SearchResultKind::Block,
+                block.height.to_string(),
+                block.hash,
+            ));
+        }
+
+        let proposal = self
+            .gov_repo
+            .find_governance_proposals_by_id(number)
+            .await
+            .map_err(SearchError::Database)?;
+        if let Some(proposal) = proposal {
+            let mut result = SearchResult::from_proposal(
+                proposal.id,
+                &proposal.content,
+                None,
+            );
+            result.score = 1.0;
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    async fn search_text(
+        &self,
+        text: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        if text.len() < MIN_FUZZY_QUERY_LENGTH {
+            return Ok(vec![]);
+        }
+        let limit = limit as i64;
+
+        let validators = self
+            .search_repo
+            .find_validators_matching(text.to_string(), limit)
+            .await
+            .map_err(SearchError::Database)?;
+
+        let proposals = self
+            .search_repo
+            .find_proposals_matching(text.to_string(), limit)
+            .await
+            .map_err(SearchError::Database)?;
+
+        let memos = self
+            .search_repo
+            .find_memos_containing(text.to_string(), limit)
+            .await
+            .map_err(SearchError::Database)?;
+
+        let validators =
+            validators
+                .into_iter()
+                .map(|(validator, name, email, website)| {
+                    SearchResult::from_validator(
+                        validator,
+                        [name, email, website],
+                    )
+                });
+        let proposals =
+            proposals.into_iter().map(|(id, content, similarity)| {
+                SearchResult::from_proposal(id, &content, similarity)
+            });
+        let memos = memos
+            .into_iter()
+            .map(|(id, memo)| SearchResult::from_memo(id, memo, text));
+
+        Ok(validators.chain(proposals).chain(memos).collect())
+    }
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use native_tls::TlsConnector;
+use orm::notifications::{INDEXER_NOTIFICATION_CHANNEL, IndexerNotification};
+use postgres_native_tls::MakeTlsConnector;
+use tokio::sync::{broadcast, mpsc};
+use tokio_postgres::AsyncMessage;
+
+use crate::appstate::AppState;
+use crate::entity::subscription::{SubscriptionEvent, Topic};
+use crate::error::subscription::SubscriptionError;
+use crate::response::block::BlockResponse;
+use crate::response::governance::ProposalResponse;
+use crate::response::transaction::{
+    InnerTransactionResponse, WrapperTransactionResponse,
+};
+use crate::service::block::BlockService;
+use crate::service::governance::GovernanceService;
+use crate::service::transaction::TransactionService;
+
+/// How many notifications can be buffered for slow websocket clients before
+/// they start missing events
+const EVENTS_CAPACITY: usize = 256;
+const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+pub type SubscriptionEvents = Arc<Vec<SubscriptionEvent>>;
+
+#[derive(Clone)]
+pub struct SubscriptionService {
+    block_service: BlockService,
+    transaction_service: TransactionService,
+    gov_service: GovernanceService,
+    events: broadcast::Sender<SubscriptionEvents>,
+}
+
+impl SubscriptionService {
+    /// Create the service and spawn the task listening for the notifications
+    /// emitted by the crawlers on `database_url`
+    pub fn new(
+        app_state: AppState,
+        database_url: &str,
+    ) -> Result<Self, SubscriptionError> {
+        // libpq also accepts `sslmode=verify-ca` and `verify-full`, which the
+        // listener can't honor, so those urls are rejected upfront instead of
+        // failing on every reconnection
+        let database_config = database_url
+            .parse::<tokio_postgres::Config>()
+            .map_err(|e| {
+                SubscriptionError::Listener(format!(
+                    "Unsupported database url: {}",
+                    e
+                ))
+            })?;
+        let tls = tls_connector()?;
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+
+        let service = Self {
+            block_service: BlockService::new(app_state.clone()),
+            transaction_service: TransactionService::new(app_state.clone()),
+            gov_service: GovernanceService::new(app_state),
+            events,
+        };
+
+        tokio::spawn(service.clone().listen(database_config, tls));
+
+        Ok(service)
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<SubscriptionEvents> {
+        self.events.subscribe()
+    }
+
+    async fn listen(
+        self,
+        database_config: tokio_postgres::Config,
+        tls: MakeTlsConnector,
+    ) {
+        let mut backoff = INITIAL_RECONNECT_BACKOFF;
+
+        loop {
+            match self.connect(&database_config, tls.clone()).await {
+                Ok((_client, notifications)) => {
+                    // Only consecutive failures to connect are backed off
+                    backoff = INITIAL_RECONNECT_BACKOFF;
+                    self.forward(notifications).await;
+                    tracing::error!(
+                        "Subscription listener error: connection closed"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!("Subscription listener error: {}", e)
+                }
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+        }
+    }
+
+    /// Connect and listen for the notifications, which are received until the
+    /// returned client is dropped or the connection is lost
+    async fn connect(
+        &self,
+        database_config: &tokio_postgres::Config,
+        tls: MakeTlsConnector,
+    ) -> Result<
+        (tokio_postgres::Client, mpsc::UnboundedReceiver<String>),
+        SubscriptionError,
+    > {
+        let (client, mut connection) = database_config
+            .connect(tls)
+            .await
+            .map_err(|e| SubscriptionError::Listener(e.to_string()))?;
+
+        // The connection has to be polled for the client to make progress, so
+        // it is driven in its own task which forwards the notifications
+        let (notifications_tx, notifications_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut messages =
+                futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+
+            while let Some(message) = messages.next().await {
+                match message {
+                    Ok(AsyncMessage::Notification(notification)) => {
+                        if notifications_tx
+                            .send(notification.payload().to_string())
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Ok(_) => continue,
+                    Err(e) => {
+                        tracing::error!("Subscription connection error: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        client
+            .batch_execute(&format!("LISTEN {}", INDEXER_NOTIFICATION_CHANNEL))
+            .await
+            .map_err(|e| SubscriptionError::Listener(e.to_string()))?;
+
+        tracing::info!(
+            "Listening for {} notifications",
+            INDEXER_NOTIFICATION_CHANNEL
+        );
+
+        Ok((client, notifications_rx))
+    }
+
+    async fn forward(
+        &self,
+        mut notifications_rx: mpsc::UnboundedReceiver<String>,
+    ) {
+        while let Some(payload) = notifications_rx.recv().await {
+            // Nobody to publish to, no need to hit the database
+            if self.events.receiver_count() == 0 {
+                continue;
+            }
+
+            let notification =
+                match serde_json::from_str::<IndexerNotification>(&payload) {
+                    Ok(notification) => notification,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Invalid notification {}: {}",
+                            payload,
+                            e
+                        );
+                        continue;
+                    }
+                };
+
+            match self.get_events(notification).await {
+                // Sending only fails when there are no receivers left
+                Ok(events) if !events.is_empty() => {
+                    let _ = self.events.send(Arc::new(events));
+                }
+                Ok(_) => (),
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to build subscription events: {}",
+                        e
+                    )
+                }
+            }
+        }
+    }
+
+    async fn get_events(
+        &self,
+        notification: IndexerNotification,
+    ) -> Result<Vec<SubscriptionEvent>, SubscriptionError> {
+        match notification {
+            IndexerNotification::Block { height } => {
+                self.get_block_events(height).await
+            }
+            IndexerNotification::Proposal { id } => {
+                self.get_proposal_events(id as u64).await
+            }
+        }
+    }
+
+    async fn get_block_events(
+        &self,
+        height: i32,
+    ) -> Result<Vec<SubscriptionEvent>, SubscriptionError> {
+        let block = self
+            .block_service
+            .get_block_by_height(height)
+            .await
+            .map_err(|e| SubscriptionError::Database(e.to_string()))?;
+
+        let history_targets = self
+            .transaction_service
+            .get_history_targets_by_block_height(height)
+            .await
+            .map_err(|e| SubscriptionError::Database(e.to_string()))?;
+
+        let wrappers = block.transactions.clone();
+        let mut events = vec![SubscriptionEvent {
+            topic: Topic::Block,
+            data: to_json(BlockResponse::from(block)),
+        }];
+
+        for wrapper in wrappers {
+            let wrapper_id = wrapper.id.to_string();
+
+            let inners = self
+                .transaction_service
+                .get_inner_tx_by_wrapper_id(wrapper_id.clone())
+                .await
+                .map_err(|e| SubscriptionError::Database(e.to_string()))?;
+
+            events.extend(inners.iter().map(|inner| SubscriptionEvent {
+                topic: Topic::Tx(inner.kind.clone()),
+                data: to_json(InnerTransactionResponse::new(inner.clone())),
+            }));
+
+            let mut addresses = history_targets
+                .get(&wrapper_id)
+                .cloned()
+                .unwrap_or_default();
+            addresses.insert(wrapper.fee_payer.to_string());
+
+            let data =
+                to_json(WrapperTransactionResponse::new(wrapper, inners));
+            events.extend(addresses.into_iter().map(|address| {
+                SubscriptionEvent {
+                    topic: Topic::Address(address),
+                    data: data.clone(),
+                }
+            }));
+        }
+
+        Ok(events)
+    }
+
+    async fn get_proposal_events(
+        &self,
+        id: u64,
+    ) -> Result<Vec<SubscriptionEvent>, SubscriptionError> {
+        let proposal = self
+            .gov_service
+            .find_governance_proposal_by_id(id)
+            .await
+            .map_err(|e| SubscriptionError::Database(e.to_string()))?;
+
+        Ok(proposal
+            .map(|proposal| SubscriptionEvent {
+                topic: Topic::Proposal(id),
+                data: to_json(ProposalResponse::from(proposal)),
+            })
+            .into_iter()
+            .collect())
+    }
+}
+
+fn to_json<T: serde::Serialize>(response: T) -> serde_json::Value {
+    serde_json::to_value(response).expect("Response should serialize to json")
+}
+
+/// Connects like libpq does for the diesel pool: the connection is encrypted
+/// when `sslmode` is `prefer` (the default) or `require`, without verifying
+/// the server certificate
+fn tls_connector() -> Result<MakeTlsConnector, SubscriptionError> {
+    let connector = TlsConnector::builder()
+        .danger_accept_invalid_certs(true)
+        .danger_accept_invalid_hostnames(true)
+        .build()
+        .map_err(|e| SubscriptionError::Listener(e.to_string()))?;
+
+    Ok(MakeTlsConnector::new(connector))
+}
@@ -1,14 +1,27 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use futures::{Stream, stream};
+use namada_sdk::tendermint_rpc::HttpClient;
+use namada_sdk::token::NATIVE_MAX_DECIMAL_PLACES;
 use orm::transactions::TransactionKindDb;
+use shared::utils;
 
 use crate::appstate::AppState;
+use crate::constant::EXPORT_BATCH_SIZE;
+use crate::entity::cursor::{Cursor, HistoryCursor};
+use crate::entity::export::ExportRow;
 use crate::entity::transaction::{
-    InnerTransaction, TransactionHistory, TransactionKind, WrapperTransaction,
+    InnerTransaction, MemoMatch, TransactionHistory, TransactionHistoryFilter,
+    TransactionKind, WrapperTransaction,
 };
 use crate::error::transaction::TransactionError;
 use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 use crate::repository::transaction::{
-    TransactionRepository, TransactionRepositoryTrait,
+    TransactionHistoryFiltersDb, TransactionRepository,
+    TransactionRepositoryTrait,
 };
+use crate::service::utils::{query_missing_denominations, split_cursor_page};
 
 #[derive(Clone)]
 pub struct TransactionService {
@@ -44,6 +57,28 @@ impl TransactionService {
             .map(|wrapper| WrapperTransaction::from_db(wrapper, tokens)))
     }
 
+    pub async fn get_wrapper_txs(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<Vec<WrapperTransaction>, TransactionError> {
+        let tokens = self
+            .chain_repo
+            .find_tokens()
+            .await
+            .map_err(TransactionError::Database)?;
+
+        let wrapper_txs = self
+            .transaction_repo
+            .find_wrapper_txs(ids)
+            .await
+            .map_err(TransactionError::Database)?;
+
+        Ok(wrapper_txs
+            .into_iter()
+            .map(|wrapper| WrapperTransaction::from_db(wrapper, tokens.clone()))
+            .collect())
+    }
+
     pub async fn get_inner_tx(
         &self,
         id: String,
@@ -70,14 +105,85 @@ impl TransactionService {
         Ok(inner_txs.into_iter().map(InnerTransaction::from).collect())
     }
 
+    pub async fn get_inner_txs_by_wrapper_ids(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<Vec<InnerTransaction>, TransactionError> {
+        let inner_txs = self
+            .transaction_repo
+            .find_inners_by_wrapper_txs(ids)
+            .await
+            .map_err(TransactionError::Database)?;
+
+        Ok(inner_txs.into_iter().map(InnerTransaction::from).collect())
+    }
+
+    /// Returns, for each wrapper transaction included at `block_height`, the
+    /// addresses its inner transactions are recorded against in the history
+    pub async fn get_history_targets_by_block_height(
+        &self,
+        block_height: i32,
+    ) -> Result<HashMap<String, HashSet<String>>, TransactionError> {
+        let targets = self
+            .transaction_repo
+            .find_history_targets_by_block_height(block_height)
+            .await
+            .map_err(TransactionError::Database)?;
+
+        Ok(targets.into_iter().fold(
+            HashMap::new(),
+            |mut acc, (wrapper_id, target)| {
+                acc.entry(wrapper_id).or_default().insert(target);
+                acc
+            },
+        ))
+    }
+
+    pub async fn get_inner_txs_by_memo(
+        &self,
+        value: String,
+        matching: MemoMatch,
+        address: Option<String>,
+        page: u64,
+    ) -> Result<(Vec<InnerTransaction>, u64, u64), TransactionError> {
+        if let Some(address) = &address {
+            if !utils::is_valid_bech32_address(address, "tnam") {
+                return Err(TransactionError::InvalidFilter(format!(
+                    "{} is not a valid address",
+                    address
+                )));
+            }
+        }
+
+        let (txs, total_pages, total_items) = self
+            .transaction_repo
+            .find_inner_txs_by_memo(
+                value,
+                matches!(matching, MemoMatch::Prefix),
+                address,
+                page as i64,
+            )
+            .await
+            .map_err(TransactionError::Database)?;
+
+        Ok((
+            txs.into_iter().map(InnerTransaction::from).collect(),
+            total_pages as u64,
+            total_items as u64,
+        ))
+    }
+
     pub async fn get_addresses_history(
         &self,
         addresses: Vec<String>,
+        filter: TransactionHistoryFilter,
         page: u64,
     ) -> Result<(Vec<TransactionHistory>, u64, u64), TransactionError> {
+        let filters = history_filters_db(filter)?;
+
         let (txs, total_pages, total_items) = self
             .transaction_repo
-            .find_addresses_history(addresses, page as i64)
+            .find_addresses_history(addresses, filters, page as i64)
             .await
             .map_err(TransactionError::Database)?;
 
@@ -90,6 +196,134 @@ impl TransactionService {
         ))
     }
 
+    pub async fn get_addresses_history_after(
+        &self,
+        addresses: Vec<String>,
+        filter: TransactionHistoryFilter,
+        cursor: &str,
+        limit: u64,
+    ) -> Result<(Vec<TransactionHistory>, Option<String>), TransactionError>
+    {
+        let filters = history_filters_db(filter)?;
+        let after = HistoryCursor::decode(cursor)
+            .map_err(TransactionError::InvalidCursor)?
+            .map(|cursor| (cursor.height, cursor.inner_tx_id, cursor.id));
+
+        let txs = self
+            .transaction_repo
+            .find_addresses_history_after(
+                addresses,
+                filters,
+                after,
+                limit as i64 + 1,
+            )
+            .await
+            .map_err(TransactionError::Database)?;
+
+        let (txs, next_cursor) =
+            split_cursor_page(txs, limit, |(h, _, bh)| HistoryCursor {
+                height: *bh,
+                inner_tx_id: h.inner_tx_id.clone(),
+                id: h.id,
+            });
+
+        Ok((
+            txs.into_iter()
+                .map(|(h, t, bh)| TransactionHistory::from_db(h, t, bh))
+                .collect(),
+            next_cursor,
+        ))
+    }
+
+    /// Stream, batch by batch, the statement of `addresses` matching
+    /// `filter`, in the history order, with amounts denominated by querying
+    /// the token denominations from the node
+    pub async fn export_addresses_history(
+        &self,
+        client: Arc<HttpClient>,
+        addresses: Vec<String>,
+        filter: TransactionHistoryFilter,
+    ) -> Result<
+        impl Stream<Item = Result<Vec<ExportRow>, TransactionError>> + use<>,
+        TransactionError,
+    > {
+        let filters = history_filters_db(filter)?;
+        let native_token = self
+            .chain_repo
+            .find_chain_parameters()
+            .await
+            .map_err(TransactionError::Database)?
+            .native_token_address;
+        let transaction_repo = self.transaction_repo.clone();
+        let denominations = HashMap::from([(
+            native_token.clone(),
+            Some(NATIVE_MAX_DECIMAL_PLACES),
+        )]);
+
+        Ok(stream::try_unfold(
+            (Some(None), denominations),
+            move |(after, mut denominations)| {
+                let addresses = addresses.clone();
+                let filters = filters.clone();
+                let native_token = native_token.clone();
+                let transaction_repo = transaction_repo.clone();
+                let client = client.clone();
+
+                async move {
+                    let Some(after) = after else {
+                        return Ok(None);
+                    };
+
+                    let txs = transaction_repo
+                        .find_addresses_history_export_after(
+                            addresses,
+                            filters,
+                            after,
+                            EXPORT_BATCH_SIZE as i64,
+                        )
+                        .await
+                        .map_err(TransactionError::Database)?;
+
+                    let next = (txs.len() as u64 == EXPORT_BATCH_SIZE)
+                        .then(|| txs.last())
+                        .flatten()
+                        .map(|(h, _, w, _)| {
+                            Some((w.block_height, h.inner_tx_id.clone(), h.id))
+                        });
+
+                    let rows = txs
+                        .into_iter()
+                        .map(|(h, t, w, timestamp)| {
+                            ExportRow::from_history(
+                                h,
+                                t,
+                                w,
+                                timestamp,
+                                &native_token,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    let tokens = ExportRow::tokens(&rows);
+                    query_missing_denominations(
+                        &client,
+                        &mut denominations,
+                        tokens,
+                    )
+                    .await
+                    .map_err(TransactionError::Rpc)?;
+
+                    let rows = rows
+                        .into_iter()
+                        .map(|row| row.denominate(&denominations))
+                        .collect();
+
+                    Ok(Some((rows, (next, denominations))))
+                }
+            },
+        ))
+    }
+
     pub async fn get_most_recent_transactions(
         &self,
         offset: u64,
@@ -148,3 +382,42 @@ impl TransactionService {
             .collect())
     }
 }
+
+fn history_filters_db(
+    filter: TransactionHistoryFilter,
+) -> Result<TransactionHistoryFiltersDb, TransactionError> {
+    let height = |height: Option<u64>, name: &str| {
+        height.map(i32::try_from).transpose().map_err(|_| {
+            TransactionError::InvalidFilter(format!("{} is too large", name))
+        })
+    };
+    let timestamp = |timestamp: Option<i64>, name: &str| {
+        timestamp
+            .map(|timestamp| {
+                chrono::DateTime::from_timestamp(timestamp, 0)
+                    .map(|datetime| datetime.naive_utc())
+                    .ok_or_else(|| {
+                        TransactionError::InvalidFilter(format!(
+                            "{} is out of range",
+                            name
+                        ))
+                    })
+            })
+            .transpose()
+    };
+
+    Ok(TransactionHistoryFiltersDb {
+        kinds: filter
+            .kinds
+            .into_iter()
+            .map(TransactionKindDb::from)
+            .collect(),
+        tokens: filter.tokens,
+        from_height: height(filter.from_height, "fromHeight")?,
+        to_height: height(filter.to_height, "toHeight")?,
+        from_timestamp: timestamp(filter.from_timestamp, "fromTimestamp")?,
+        to_timestamp: timestamp(filter.to_timestamp, "toTimestamp")?,
+        exit_code: filter.exit_code.map(Into::into),
+        direction: filter.direction.map(Into::into),
+    })
+}
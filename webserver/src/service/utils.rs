@@ -1,7 +1,83 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
 use namada_core::token::Amount;
+use namada_sdk::address::Address as NamadaAddress;
+use namada_sdk::queries::RPC;
+use namada_sdk::tendermint_rpc::HttpClient;
+use shared::balance::Denomination;
+
+use crate::constant::EPOCH_ESTIMATE_WINDOW;
+use crate::entity::chain::EpochTimeEstimator;
+use crate::entity::cursor::Cursor;
+use crate::repository::chain::{ChainRepository, ChainRepositoryTrait};
 
 pub fn raw_amount_to_nam(raw_amount: String) -> String {
     Amount::from_str(raw_amount, 0)
         .expect("raw_amount is not a valid string")
         .to_string_native()
 }
+
+/// Split rows fetched with `limit + 1` into a page of at most `limit` rows and
+/// the cursor of its last row, when more rows follow it
+pub fn split_cursor_page<T, C: Cursor>(
+    mut rows: Vec<T>,
+    limit: u64,
+    cursor: impl Fn(&T) -> C,
+) -> (Vec<T>, Option<String>) {
+    if rows.len() as u64 > limit {
+        rows.truncate(limit as usize);
+        let next_cursor = rows.last().map(|row| cursor(row).encode());
+        (rows, next_cursor)
+    } else {
+        (rows, None)
+    }
+}
+
+/// Build the estimator of epoch start times from the chain state, the chain
+/// parameters and the start of the most recently indexed epochs
+pub async fn epoch_time_estimator(
+    chain_repo: &ChainRepository,
+) -> Result<EpochTimeEstimator, String> {
+    let chain_state = chain_repo.get_state().await?;
+    let parameters = chain_repo.find_chain_parameters().await?;
+    let epoch_starts = chain_repo
+        .find_epoch_start_times(
+            chain_state.last_processed_epoch - EPOCH_ESTIMATE_WINDOW - 1,
+        )
+        .await?;
+
+    Ok(EpochTimeEstimator::new(
+        &chain_state,
+        &parameters,
+        epoch_starts,
+    ))
+}
+
+/// Query the denomination of the `tokens` missing from `denominations`,
+/// tokens without a denomination are remembered as `None`
+pub async fn query_missing_denominations(
+    client: &HttpClient,
+    denominations: &mut HashMap<String, Option<Denomination>>,
+    tokens: impl IntoIterator<Item = String>,
+) -> Result<(), String> {
+    for token in tokens {
+        if denominations.contains_key(&token) {
+            continue;
+        }
+
+        let denomination = match NamadaAddress::from_str(&token) {
+            Ok(address) => RPC
+                .vp()
+                .token()
+                .denomination(client, &address)
+                .await
+                .map_err(|e| e.to_string())?
+                .map(|denomination| denomination.0),
+            Err(_) => None,
+        };
+        denominations.insert(token, denomination);
+    }
+
+    Ok(())
+}
@@ -4,8 +4,11 @@ use namada_sdk::tendermint_rpc::HttpClient;
 
 use crate::appstate::AppState;
 use crate::config::AppConfig;
+use crate::graphql::schema::{IndexerSchema, build_schema};
+use crate::service::account::AccountService;
 use crate::service::balance::BalanceService;
 use crate::service::block::BlockService;
+use crate::service::cache::CacheService;
 use crate::service::chain::ChainService;
 use crate::service::crawler_state::CrawlerStateService;
 use crate::service::gas::GasService;
@@ -15,6 +18,8 @@ use crate::service::masp::MaspService;
 use crate::service::pgf::PgfService;
 use crate::service::pos::PosService;
 use crate::service::revealed_pk::RevealedPkService;
+use crate::service::search::SearchService;
+use crate::service::subscription::SubscriptionService;
 use crate::service::transaction::TransactionService;
 
 #[derive(Clone)]
@@ -25,33 +30,50 @@ pub struct CommonState {
     pub balance_service: BalanceService,
     pub chain_service: ChainService,
     pub revealed_pk_service: RevealedPkService,
+    pub account_service: AccountService,
+    pub search_service: SearchService,
     pub gas_service: GasService,
     pub transaction_service: TransactionService,
     pub pgf_service: PgfService,
     pub crawler_state_service: CrawlerStateService,
     pub ibc_service: IbcService,
     pub masp_service: MaspService,
+    pub subscription_service: SubscriptionService,
+    pub cache_service: CacheService,
+    pub graphql_schema: IndexerSchema,
     pub client: Arc<HttpClient>,
     pub config: AppConfig,
 }
 
 impl CommonState {
-    pub fn new(client: HttpClient, config: AppConfig, data: AppState) -> Self {
-        Self {
+    pub fn new(
+        client: HttpClient,
+        config: AppConfig,
+        data: AppState,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
             block_service: BlockService::new(data.clone()),
             pos_service: PosService::new(data.clone()),
             gov_service: GovernanceService::new(data.clone()),
             balance_service: BalanceService::new(data.clone()),
             chain_service: ChainService::new(data.clone()),
             revealed_pk_service: RevealedPkService::new(data.clone()),
+            account_service: AccountService::new(data.clone()),
+            search_service: SearchService::new(data.clone()),
             gas_service: GasService::new(data.clone()),
             pgf_service: PgfService::new(data.clone()),
             transaction_service: TransactionService::new(data.clone()),
             crawler_state_service: CrawlerStateService::new(data.clone()),
             ibc_service: IbcService::new(data.clone()),
-            masp_service: MaspService::new(data),
+            masp_service: MaspService::new(data.clone()),
+            cache_service: CacheService::new(data.clone()),
+            graphql_schema: build_schema(&config),
+            subscription_service: SubscriptionService::new(
+                data,
+                &config.database_url,
+            )?,
             client: Arc::new(client),
             config,
-        }
+        })
     }
 }